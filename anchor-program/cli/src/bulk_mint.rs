@@ -0,0 +1,461 @@
+// bulk-mint - mints every nft listed in a CSV or JSON manifest
+//
+// The whole manifest is validated before anything is sent. Each row is then minted in its own transaction,
+// so a row either has all three of its accounts or none. Rows whose name is already registered are skipped,
+// which makes it safe to re-run the same manifest after a partial failure.
+// The outcome of every row is written to the results file as soon as it is known
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use animal_kingdom_client::animal_kingdom::{EcologicalData, NFT_PRICE};
+use animal_kingdom_client::state::AccountFetcher;
+use animal_kingdom_client::{pda, state};
+use serde::{Deserialize, Serialize};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::native_token::{lamports_to_sol, sol_to_lamports};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+
+use crate::{
+    fetcher, mint_nft_instructions, parse_asset_kind, parse_iucn_status, send, CliResult, NftSpec,
+};
+
+// Limits of the token metadata program
+const MAX_NAME_LENGTH: usize = 32;
+const MAX_SYMBOL_LENGTH: usize = 10;
+const MAX_URI_LENGTH: usize = 200;
+const MAX_SELLER_FEE_BASIS_POINTS: u16 = 10_000;
+
+#[derive(Deserialize)]
+struct ManifestRow {
+    name: String,
+    uri: String,
+    // conservation project that receives the proceeds of the nft
+    project: String,
+    // animal, animal-family, habitat or reef
+    category: String,
+    symbol: Option<String>,
+    seller_fee_basis_points: Option<u16>,
+    // in SOL - one-of-ones always sell for 1 SOL, for master editions this is the price of a print
+    price: Option<f64>,
+    max_supply: Option<u64>,
+    species_id: Option<u64>,
+    iucn_status: Option<String>,
+    region_code: Option<String>,
+    geohash: Option<String>,
+    area_m2: Option<u64>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+enum Status {
+    Minted,
+    Simulated,
+    Skipped,
+    Failed,
+}
+
+#[derive(Serialize)]
+struct RowResult {
+    row: usize,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mint: Option<String>,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+pub fn run(
+    rpc: &RpcClient,
+    admin: &Keypair,
+    manifest: &Path,
+    results: Option<PathBuf>,
+    dry_run: bool,
+) -> CliResult<()> {
+    let results_path = results.unwrap_or_else(|| {
+        let mut path = manifest.as_os_str().to_owned();
+        path.push(".results.json");
+        PathBuf::from(path)
+    });
+
+    let nfts = validate(read_manifest(manifest)?)?;
+    println!("{} nfts in {}", nfts.len(), manifest.display());
+
+    let mut results = Vec::with_capacity(nfts.len());
+    for (index, nft) in nfts.into_iter().enumerate() {
+        let row = index + 1;
+        let name = nft.name.clone();
+        println!("[{row}] {name}");
+
+        results.push(match mint_row(rpc, admin, nft, dry_run) {
+            Ok((status, mint, signature)) => RowResult {
+                row,
+                name,
+                mint: Some(mint.to_string()),
+                status,
+                signature: signature.map(|signature| signature.to_string()),
+                error: None,
+            },
+            Err(error) => {
+                println!("[{row}] failed: {error}");
+                RowResult {
+                    row,
+                    name,
+                    mint: None,
+                    status: Status::Failed,
+                    signature: None,
+                    error: Some(error.to_string()),
+                }
+            }
+        });
+        serde_json::to_writer_pretty(File::create(&results_path)?, &results)?;
+    }
+
+    let count = |wanted: fn(&Status) -> bool| results.iter().filter(|r| wanted(&r.status)).count();
+    let failed = count(|status| matches!(status, Status::Failed));
+    println!(
+        "Minted {}, simulated {}, skipped {} already minted, failed {}",
+        count(|status| matches!(status, Status::Minted)),
+        count(|status| matches!(status, Status::Simulated)),
+        count(|status| matches!(status, Status::Skipped)),
+        failed
+    );
+    println!("Results written to {}", results_path.display());
+
+    if failed > 0 {
+        return Err(format!("{failed} rows failed, re-run the manifest to retry them").into());
+    }
+    Ok(())
+}
+
+// Mints one row, or skips it if an nft is already registered with its name
+// or was minted with it before the registry
+fn mint_row(
+    rpc: &RpcClient,
+    admin: &Keypair,
+    nft: NftSpec,
+    dry_run: bool,
+) -> CliResult<(Status, Pubkey, Option<Signature>)> {
+    let fetcher = fetcher(rpc);
+    if let Some(record) = state::fetch_name_record(&fetcher, &nft.name)? {
+        println!("Already minted as {}, skipping", record.mint);
+        return Ok((Status::Skipped, record.mint, None));
+    }
+    let legacy_mint = pda::find_legacy_nft_mint(&nft.name).0;
+    if fetcher.account_data(&legacy_mint)?.is_some() {
+        println!("Already minted as {legacy_mint} before the name registry, skipping - run migrate-nft-name to register it");
+        return Ok((Status::Skipped, legacy_mint, None));
+    }
+
+    let nft_id = state::next_nft_id(&fetcher)?;
+    let mint = pda::find_nft_mint(nft_id).0;
+    println!("Minting nft {nft_id} ({mint})");
+    let instructions = mint_nft_instructions(admin.pubkey(), nft_id, nft);
+    Ok(match send(rpc, admin, &instructions, dry_run)? {
+        Some(signature) => (Status::Minted, mint, Some(signature)),
+        None => (Status::Simulated, mint, None),
+    })
+}
+
+fn read_manifest(manifest: &Path) -> CliResult<Vec<ManifestRow>> {
+    let file = File::open(manifest)
+        .map_err(|error| format!("failed to open {}: {error}", manifest.display()))?;
+
+    let is_json = manifest
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"));
+    if is_json {
+        return Ok(serde_json::from_reader(file)?);
+    }
+
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(file);
+    let rows = reader.deserialize().collect::<Result<_, _>>()?;
+    Ok(rows)
+}
+
+// Checks every row and reports all the problems at once, so nothing is minted from a manifest with mistakes in it
+fn validate(rows: Vec<ManifestRow>) -> CliResult<Vec<NftSpec>> {
+    let mut names = HashSet::new();
+    let mut nfts = Vec::with_capacity(rows.len());
+    let mut problems = Vec::new();
+
+    for (index, row) in rows.into_iter().enumerate() {
+        let label = format!("row {} ({})", index + 1, row.name);
+        // The name registry ignores case and extra whitespace
+        if !names.insert(pda::normalise_name(&row.name)) {
+            problems.push(format!("{label}: an earlier row has the same name"));
+        }
+        match validate_row(row) {
+            Ok(nft) => nfts.push(nft),
+            Err(error) => problems.push(format!("{label}: {error}")),
+        }
+    }
+
+    if !problems.is_empty() {
+        return Err(format!(
+            "invalid manifest, nothing was minted\n{}",
+            problems.join("\n")
+        )
+        .into());
+    }
+    Ok(nfts)
+}
+
+fn validate_row(row: ManifestRow) -> CliResult<NftSpec> {
+    if pda::normalise_name(&row.name).is_empty() || row.name.len() > MAX_NAME_LENGTH {
+        return Err(format!("the name must be 1 to {MAX_NAME_LENGTH} bytes").into());
+    }
+    if row.uri.is_empty() || row.uri.len() > MAX_URI_LENGTH {
+        return Err(format!("the uri must be 1 to {MAX_URI_LENGTH} bytes").into());
+    }
+    let symbol = row.symbol.unwrap_or_else(|| "$ALKM".to_string());
+    if symbol.len() > MAX_SYMBOL_LENGTH {
+        return Err(format!("the symbol must be at most {MAX_SYMBOL_LENGTH} bytes").into());
+    }
+    let seller_fee_basis_points = row.seller_fee_basis_points.unwrap_or(100);
+    if seller_fee_basis_points > MAX_SELLER_FEE_BASIS_POINTS {
+        return Err("the seller fee must be at most 10000 basis points".into());
+    }
+    let organisation = row
+        .project
+        .parse::<Pubkey>()
+        .map_err(|_| format!("{} is not a valid project address", row.project))?;
+
+    let max_supply = row.max_supply.unwrap_or(0);
+    let price = row.price.map(sol_to_lamports);
+    let print_price = if max_supply == 0 {
+        if price.is_some_and(|price| price != NFT_PRICE) {
+            return Err(format!(
+                "one-of-ones sell for {} SOL, leave the price empty or set max_supply",
+                lamports_to_sol(NFT_PRICE)
+            )
+            .into());
+        }
+        0
+    } else {
+        match price {
+            Some(price) if price > 0 => price,
+            _ => return Err("master editions need the price of a print".into()),
+        }
+    };
+
+    let ecology = EcologicalData {
+        asset_kind: parse_asset_kind(&row.category)?,
+        species_id: row.species_id.unwrap_or(0),
+        iucn_status: parse_iucn_status(row.iucn_status.as_deref().unwrap_or("not-evaluated"))?,
+        region_code: row.region_code.unwrap_or_default(),
+        geohash: row.geohash.unwrap_or_default(),
+        area_m2: row.area_m2.unwrap_or(0),
+    };
+    ecology.validate().map_err(|error| error.to_string())?;
+
+    Ok(NftSpec {
+        name: row.name,
+        uri: row.uri,
+        symbol,
+        seller_fee_basis_points,
+        organisation,
+        max_supply,
+        print_price,
+        ecology,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROJECT: &str = "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH";
+
+    fn row(name: &str) -> ManifestRow {
+        ManifestRow {
+            name: name.to_string(),
+            uri: "https://example.com/pink-pigeon.json".to_string(),
+            project: PROJECT.to_string(),
+            category: "habitat".to_string(),
+            symbol: None,
+            seller_fee_basis_points: None,
+            price: None,
+            max_supply: None,
+            species_id: None,
+            iucn_status: None,
+            region_code: None,
+            geohash: None,
+            area_m2: None,
+        }
+    }
+
+    // Writes a manifest to a file of its own and reads it back
+    fn read(file_name: &str, contents: &str) -> CliResult<Vec<ManifestRow>> {
+        let path = std::env::temp_dir().join(format!("{}-{file_name}", std::process::id()));
+        std::fs::write(&path, contents)?;
+        let rows = read_manifest(&path);
+        std::fs::remove_file(&path)?;
+        rows
+    }
+
+    #[test]
+    fn one_of_ones_default_to_the_nft_price() {
+        let nft = validate_row(row("Pink Pigeon")).unwrap();
+        assert_eq!(nft.symbol, "$ALKM");
+        assert_eq!(nft.seller_fee_basis_points, 100);
+        assert_eq!(nft.organisation, PROJECT.parse().unwrap());
+        assert_eq!(nft.max_supply, 0);
+        assert_eq!(nft.print_price, 0);
+
+        let mut one_of_one = row("Pink Pigeon");
+        one_of_one.price = Some(lamports_to_sol(NFT_PRICE));
+        assert!(validate_row(one_of_one).is_ok());
+    }
+
+    #[test]
+    fn one_of_ones_reject_any_other_price() {
+        let mut one_of_one = row("Pink Pigeon");
+        one_of_one.price = Some(2.0);
+        assert!(validate_row(one_of_one).is_err());
+    }
+
+    #[test]
+    fn master_editions_need_a_print_price() {
+        let mut edition = row("Pink Pigeon");
+        edition.max_supply = Some(100);
+        assert!(validate_row(edition).is_err());
+
+        let mut edition = row("Pink Pigeon");
+        edition.max_supply = Some(100);
+        edition.price = Some(0.0);
+        assert!(validate_row(edition).is_err());
+
+        let mut edition = row("Pink Pigeon");
+        edition.max_supply = Some(100);
+        edition.price = Some(0.25);
+        let nft = validate_row(edition).unwrap();
+        assert_eq!(nft.max_supply, 100);
+        assert_eq!(nft.print_price, sol_to_lamports(0.25));
+    }
+
+    #[test]
+    fn names_are_limited_to_32_bytes() {
+        assert!(validate_row(row(&"a".repeat(MAX_NAME_LENGTH))).is_ok());
+        assert!(validate_row(row(&"a".repeat(MAX_NAME_LENGTH + 1))).is_err());
+        assert!(validate_row(row("   ")).is_err());
+    }
+
+    #[test]
+    fn metadata_limits_are_checked() {
+        let mut long_uri = row("Pink Pigeon");
+        long_uri.uri = "a".repeat(MAX_URI_LENGTH + 1);
+        assert!(validate_row(long_uri).is_err());
+
+        let mut long_symbol = row("Pink Pigeon");
+        long_symbol.symbol = Some("a".repeat(MAX_SYMBOL_LENGTH + 1));
+        assert!(validate_row(long_symbol).is_err());
+
+        let mut high_fee = row("Pink Pigeon");
+        high_fee.seller_fee_basis_points = Some(MAX_SELLER_FEE_BASIS_POINTS + 1);
+        assert!(validate_row(high_fee).is_err());
+
+        let mut bad_project = row("Pink Pigeon");
+        bad_project.project = "not a pubkey".to_string();
+        assert!(validate_row(bad_project).is_err());
+    }
+
+    #[test]
+    fn ecology_is_checked() {
+        let mut animal = row("Pink Pigeon");
+        animal.category = "animal".to_string();
+        assert!(validate_row(animal).is_err());
+
+        let mut animal = row("Pink Pigeon");
+        animal.category = "animal".to_string();
+        animal.species_id = Some(22690);
+        animal.iucn_status = Some("vulnerable".to_string());
+        assert!(validate_row(animal).is_ok());
+
+        let mut unknown = row("Pink Pigeon");
+        unknown.category = "plant".to_string();
+        assert!(validate_row(unknown).is_err());
+    }
+
+    #[test]
+    fn names_that_normalise_the_same_are_duplicates() {
+        let error = validate(vec![row("Pink Pigeon"), row("  pink   PIGEON ")])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("row 2"));
+        assert!(error.contains("an earlier row has the same name"));
+
+        assert_eq!(
+            validate(vec![row("Pink Pigeon"), row("Echo Parakeet")])
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn every_problem_is_reported() {
+        let mut bad_uri = row("Echo Parakeet");
+        bad_uri.uri = String::new();
+        let error = validate(vec![row("Pink Pigeon"), bad_uri, row("pink pigeon")])
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("row 2 (Echo Parakeet)"));
+        assert!(error.contains("row 3 (pink pigeon)"));
+        assert!(!error.contains("row 1"));
+    }
+
+    #[test]
+    fn csv_manifests_are_parsed() {
+        let rows = read(
+            "manifest.csv",
+            &format!(
+                "name,uri,project,category,price,max_supply,species_id\n\
+                 Pink Pigeon , https://example.com/1.json,{PROJECT},animal,,,22690\n\
+                 Ebony Forest,https://example.com/2.json,{PROJECT},habitat,0.5,10,\n"
+            ),
+        )
+        .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].name, "Pink Pigeon");
+        assert_eq!(rows[0].species_id, Some(22690));
+        assert_eq!(rows[0].price, None);
+        assert_eq!(rows[1].max_supply, Some(10));
+        assert_eq!(rows[1].price, Some(0.5));
+        assert_eq!(validate(rows).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn json_manifests_are_parsed() {
+        let rows = read(
+            "manifest.JSON",
+            &format!(
+                r#"[{{"name": "Pink Pigeon", "uri": "https://example.com/1.json",
+                    "project": "{PROJECT}", "category": "reef", "symbol": "PIGEON"}}]"#
+            ),
+        )
+        .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].symbol.as_deref(), Some("PIGEON"));
+        assert_eq!(validate(rows).unwrap()[0].symbol, "PIGEON");
+    }
+
+    #[test]
+    fn malformed_manifests_are_rejected() {
+        assert!(read(
+            "missing-column.csv",
+            "name,uri\nPink Pigeon,https://example.com\n"
+        )
+        .is_err());
+        assert!(read("broken.json", "[{\"name\": ").is_err());
+    }
+}
@@ -0,0 +1,602 @@
+// ak-admin - runs the admin operations of the animal-kingdom program that used to live in nfts-script/
+//
+// Every command that changes state builds its instructions with animal-kingdom-client and signs them with
+// the admin keypair. With --dry-run the transaction is simulated instead and its logs are printed
+
+use std::error::Error;
+use std::fmt::Debug;
+use std::path::PathBuf;
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use animal_kingdom_client::animal_kingdom::{AssetKind, EcologicalData, IucnStatus};
+use animal_kingdom_client::state::{self, *};
+use animal_kingdom_client::{args, instructions, pda, ClientError, ID};
+use clap::{Parser, Subcommand};
+use solana_client::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+
+mod bulk_mint;
+
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+#[derive(Parser)]
+#[clap(
+    name = "ak-admin",
+    version,
+    about = "Admin CLI for the animal-kingdom program"
+)]
+struct Cli {
+    /// Cluster to use: mainnet-beta, devnet, testnet, localnet or an RPC URL
+    #[clap(short = 'u', long, global = true, default_value = "devnet")]
+    url: String,
+    /// Keypair of the admin wallet
+    #[clap(short, long, global = true, default_value = "~/.config/solana/id.json")]
+    keypair: String,
+    /// Simulate the transaction and print its logs instead of sending it
+    #[clap(long, global = true)]
+    dry_run: bool,
+    #[clap(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the PDA that signs for every nft mint
+    InitMintAuthority,
+    /// Create the mint, metadata and master edition of an nft in one transaction
+    MintNft {
+        #[clap(long)]
+        name: String,
+        #[clap(long)]
+        uri: String,
+        /// Conservation project that receives the proceeds of the nft
+        #[clap(long)]
+        organisation: Pubkey,
+        #[clap(long, default_value = "$ALKM")]
+        symbol: String,
+        #[clap(long, default_value_t = 100)]
+        seller_fee_basis_points: u16,
+        /// Number of prints that can be minted from the master edition, 0 for none
+        #[clap(long, default_value_t = 0)]
+        max_supply: u64,
+        /// Price of a print in lamports
+        #[clap(long, default_value_t = 0)]
+        print_price: u64,
+        /// animal, animal-family, habitat or reef
+        #[clap(long, default_value = "animal")]
+        asset_kind: String,
+        /// GBIF taxon key of the species, 0 for habitats and reefs
+        #[clap(long, default_value_t = 0)]
+        species_id: u64,
+        /// IUCN Red List category, e.g. endangered or critically-endangered
+        #[clap(long, default_value = "not-evaluated")]
+        iucn_status: String,
+        /// ISO 3166-2 code of the region, e.g. MU-PL
+        #[clap(long, default_value = "")]
+        region_code: String,
+        #[clap(long, default_value = "")]
+        geohash: String,
+        /// Area covered in square metres, 0 for single animals
+        #[clap(long, default_value_t = 0)]
+        area_m2: u64,
+    },
+    /// Mint every nft in a CSV or JSON manifest that hasn't been minted yet
+    BulkMint {
+        /// Manifest with the columns name, uri, project, category and optionally symbol,
+        /// seller_fee_basis_points, price (in SOL), max_supply, species_id, iucn_status, region_code,
+        /// geohash and area_m2
+        manifest: PathBuf,
+        /// Where to write the outcome of each row, defaults to <manifest>.results.json
+        #[clap(long)]
+        results: Option<PathBuf>,
+    },
+    /// Change the name of an nft in its metadata and the name registry
+    RenameNft {
+        /// The name the nft is registered with now
+        #[clap(long)]
+        name: String,
+        #[clap(long)]
+        new_name: String,
+    },
+    /// Register the name of an nft whose mint is seeded by its name, so no new nft can take it
+    MigrateNftName {
+        #[clap(long)]
+        name: String,
+    },
+    /// Create an election that closes at the given unix timestamp
+    CreateElection {
+        #[clap(long)]
+        date: i64,
+        /// The three organisations on the ballot
+        #[clap(long, number_of_values = 3, required = true)]
+        organisations: Vec<Pubkey>,
+    },
+    /// Set the "I voted" nft that voters of an election can claim
+    SetVoteReceipt {
+        #[clap(long)]
+        date: i64,
+        #[clap(long)]
+        name: String,
+        #[clap(long, default_value = "$ALKM")]
+        symbol: String,
+        /// Metadata of the receipt, which should describe the election
+        #[clap(long)]
+        uri: String,
+    },
+    /// Archive the results of a closed election
+    FinalizeElection {
+        #[clap(long)]
+        date: i64,
+    },
+    /// Stream a grant from the treasury to the winner of a finalized election, vesting over a month
+    OpenGrantStream {
+        #[clap(long)]
+        date: i64,
+        /// Lamports to grant
+        #[clap(long)]
+        amount: u64,
+    },
+    /// Put the cancellation of a grant stream to the vote in a later election
+    ProposeStreamCancellation {
+        /// Date of the election the grant was opened for
+        #[clap(long)]
+        date: i64,
+        /// Date of the later election whose voters decide
+        #[clap(long)]
+        later_date: i64,
+    },
+    /// Cancel a grant stream, as decided by the vote of a later election
+    CancelGrantStream {
+        /// Date of the election the grant was opened for
+        #[clap(long)]
+        date: i64,
+        /// Date of the later election that decided to cancel it
+        #[clap(long)]
+        later_date: i64,
+    },
+    /// Print a decoded account of the program
+    Inspect {
+        #[clap(subcommand)]
+        target: Inspect,
+    },
+}
+
+#[derive(Subcommand)]
+enum Inspect {
+    /// Any account owned by the program, decoded by its discriminator
+    Address {
+        address: Pubkey,
+    },
+    /// The nft PDA of the nft with this name
+    Nft {
+        name: String,
+    },
+    /// The name record of this name
+    Name {
+        name: String,
+    },
+    Election {
+        date: i64,
+    },
+    Archive {
+        date: i64,
+    },
+    /// The "I voted" receipt config of an election
+    Receipt {
+        date: i64,
+    },
+    Vote {
+        mint: Pubkey,
+        date: i64,
+    },
+    Treasury,
+    Config,
+    Leaderboard,
+    Project {
+        wallet: Pubkey,
+    },
+    Donor {
+        wallet: Pubkey,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+    if let Err(error) = run(cli) {
+        eprintln!("Error: {error}");
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> CliResult<()> {
+    let rpc = RpcClient::new_with_commitment(cluster_url(&cli.url), CommitmentConfig::confirmed());
+
+    let command = match cli.command {
+        Command::Inspect { target } => return inspect(&rpc, target),
+        command => command,
+    };
+
+    let keypair_path = match cli.keypair.strip_prefix("~/") {
+        Some(path) => format!("{}/{path}", std::env::var("HOME")?),
+        None => cli.keypair,
+    };
+    let admin = read_keypair_file(&keypair_path)
+        .map_err(|error| format!("failed to read keypair {keypair_path}: {error}"))?;
+    let payer = admin.pubkey();
+
+    let instructions = match command {
+        Command::InitMintAuthority => vec![instructions::initialize_mint_authority(payer)],
+        Command::MintNft {
+            name,
+            uri,
+            organisation,
+            symbol,
+            seller_fee_basis_points,
+            max_supply,
+            print_price,
+            asset_kind,
+            species_id,
+            iucn_status,
+            region_code,
+            geohash,
+            area_m2,
+        } => {
+            let nft = NftSpec {
+                name,
+                uri,
+                symbol,
+                seller_fee_basis_points,
+                organisation,
+                max_supply,
+                print_price,
+                ecology: EcologicalData {
+                    asset_kind: parse_asset_kind(&asset_kind)?,
+                    species_id,
+                    iucn_status: parse_iucn_status(&iucn_status)?,
+                    region_code,
+                    geohash,
+                    area_m2,
+                },
+            };
+            let nft_id = state::next_nft_id(&fetcher(&rpc))?;
+            println!("Nft id: {nft_id}");
+            println!("Mint: {}", pda::find_nft_mint(nft_id).0);
+            mint_nft_instructions(payer, nft_id, nft)
+        }
+        Command::RenameNft { name, new_name } => {
+            let record = fetch_name_record(&fetcher(&rpc), &name)?
+                .ok_or_else(|| format!("no nft is registered with the name {name}"))?;
+            vec![instructions::rename_nft(
+                payer,
+                record.mint,
+                &record.name,
+                args::RenameNft { new_name },
+            )]
+        }
+        Command::MigrateNftName { name } => {
+            println!("Mint: {}", pda::find_legacy_nft_mint(&name).0);
+            vec![instructions::migrate_nft_name(
+                payer,
+                args::MigrateNftName { name },
+            )]
+        }
+        Command::CreateElection {
+            date,
+            organisations,
+        } => {
+            println!("Election: {}", pda::find_election(date).0);
+            vec![instructions::create_election(
+                payer,
+                [organisations[0], organisations[1], organisations[2]],
+                args::CreateElection {
+                    date_of_election: date,
+                },
+            )]
+        }
+        Command::SetVoteReceipt {
+            date,
+            name,
+            symbol,
+            uri,
+        } => {
+            println!(
+                "Receipt config: {}",
+                pda::find_vote_receipt_config(&pda::find_election(date).0).0
+            );
+            vec![instructions::set_vote_receipt(
+                payer,
+                args::SetVoteReceipt {
+                    _date_of_election: date,
+                    name,
+                    symbol,
+                    uri,
+                },
+            )]
+        }
+        Command::FinalizeElection { date } => vec![instructions::finalize_election(
+            payer,
+            args::FinalizeElection {
+                _date_of_election: date,
+            },
+        )],
+        Command::OpenGrantStream { date, amount } => {
+            let archive = fetch_election_archive(&fetcher(&rpc), date)?
+                .ok_or("the election has not been finalized")?;
+            println!(
+                "Streaming {} SOL to {} (already paid {} SOL)",
+                lamports_to_sol(amount),
+                archive.winner,
+                lamports_to_sol(archive.paid_out)
+            );
+            vec![instructions::open_grant_stream(
+                payer,
+                date,
+                args::OpenGrantStream { amount },
+            )]
+        }
+        Command::ProposeStreamCancellation { date, later_date } => {
+            vec![instructions::propose_stream_cancellation(
+                payer,
+                date,
+                args::ProposeStreamCancellation {
+                    _date_of_election: later_date,
+                },
+            )]
+        }
+        Command::CancelGrantStream { date, later_date } => {
+            let cancellation = fetch_stream_cancellation(&fetcher(&rpc), date, later_date)?
+                .ok_or("the cancellation was never put to the vote")?;
+            println!(
+                "{} ballots to cancel, {} to keep",
+                cancellation.cancel_votes, cancellation.keep_votes
+            );
+            vec![instructions::cancel_grant_stream(payer, date, later_date)]
+        }
+        Command::BulkMint { manifest, results } => {
+            return bulk_mint::run(&rpc, &admin, &manifest, results, cli.dry_run);
+        }
+        Command::Inspect { .. } => unreachable!(),
+    };
+
+    send(&rpc, &admin, &instructions, cli.dry_run)?;
+    Ok(())
+}
+
+// Everything needed to mint one nft
+struct NftSpec {
+    name: String,
+    uri: String,
+    symbol: String,
+    seller_fee_basis_points: u16,
+    organisation: Pubkey,
+    max_supply: u64,
+    print_price: u64,
+    ecology: EcologicalData,
+}
+
+// The create_mint, create_metadata and create_master_edition instructions, which must be sent in one transaction.
+// `nft_id` is the next id of the nft counter
+fn mint_nft_instructions(payer: Pubkey, nft_id: u64, nft: NftSpec) -> Vec<Instruction> {
+    let mint = pda::find_nft_mint(nft_id).0;
+    vec![
+        instructions::create_mint(
+            payer,
+            nft.organisation,
+            nft_id,
+            args::CreateMint {
+                name: nft.name.clone(),
+                ecology: nft.ecology,
+            },
+        ),
+        instructions::create_metadata(
+            payer,
+            mint,
+            args::CreateMetadata {
+                name: nft.name.clone(),
+                uri: nft.uri,
+                seller_fee_basis_points: nft.seller_fee_basis_points,
+                symbol: nft.symbol,
+            },
+        ),
+        instructions::create_master_edition(
+            payer,
+            mint,
+            args::CreateMasterEdition {
+                max_supply: nft.max_supply,
+                print_price: nft.print_price,
+            },
+        ),
+    ]
+}
+
+fn cluster_url(url: &str) -> String {
+    match url {
+        "mainnet-beta" | "m" => "https://api.mainnet-beta.solana.com".to_string(),
+        "devnet" | "d" => "https://api.devnet.solana.com".to_string(),
+        "testnet" | "t" => "https://api.testnet.solana.com".to_string(),
+        "localnet" | "localhost" | "l" => "http://localhost:8899".to_string(),
+        url => url.to_string(),
+    }
+}
+
+fn send(
+    rpc: &RpcClient,
+    admin: &Keypair,
+    instructions: &[Instruction],
+    dry_run: bool,
+) -> CliResult<Option<Signature>> {
+    let blockhash = rpc.get_latest_blockhash()?;
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&admin.pubkey()),
+        &[admin],
+        blockhash,
+    );
+
+    if dry_run {
+        let simulation = rpc.simulate_transaction(&transaction)?.value;
+        for log in simulation.logs.unwrap_or_default() {
+            println!("{log}");
+        }
+        if let Some(units) = simulation.units_consumed {
+            println!("Compute units consumed: {units}");
+        }
+        return match simulation.err {
+            Some(error) => Err(format!("simulation failed: {error}").into()),
+            None => {
+                println!("Simulation succeeded");
+                Ok(None)
+            }
+        };
+    }
+
+    let signature = rpc.send_and_confirm_transaction_with_spinner(&transaction)?;
+    println!("Signature: {signature}");
+    Ok(Some(signature))
+}
+
+fn fetcher(rpc: &RpcClient) -> impl AccountFetcher + '_ {
+    move |address: &Pubkey| -> Result<Option<Vec<u8>>, ClientError> {
+        let account = rpc
+            .get_account_with_commitment(address, rpc.commitment())
+            .map_err(|error| ClientError::Fetch(Box::new(error)))?;
+        Ok(account.value.map(|account| account.data))
+    }
+}
+
+fn inspect(rpc: &RpcClient, target: Inspect) -> CliResult<()> {
+    let address = match target {
+        Inspect::Address { address } => address,
+        Inspect::Nft { name } => pda::find_nft_pda(&nft_mint(rpc, &name)?).0,
+        Inspect::Name { name } => pda::find_name_record(&name).0,
+        Inspect::Election { date } => pda::find_election(date).0,
+        Inspect::Receipt { date } => pda::find_vote_receipt_config(&pda::find_election(date).0).0,
+        Inspect::Archive { date } => pda::find_election_archive(&pda::find_election(date).0).0,
+        Inspect::Vote { mint, date } => pda::find_vote(&mint, &pda::find_election(date).0).0,
+        Inspect::Treasury => pda::find_treasury().0,
+        Inspect::Config => pda::find_config().0,
+        Inspect::Leaderboard => pda::find_leaderboard().0,
+        Inspect::Project { wallet } => pda::find_project(&wallet).0,
+        Inspect::Donor { wallet } => pda::find_donor(&wallet).0,
+    };
+
+    let account = rpc
+        .get_account_with_commitment(&address, rpc.commitment())?
+        .value
+        .ok_or_else(|| format!("account {address} does not exist"))?;
+    if account.owner != ID {
+        return Err(format!("account {address} is not owned by the program").into());
+    }
+
+    println!("Address: {address}");
+    println!("Balance: {} SOL", lamports_to_sol(account.lamports));
+    println!("{}", describe(&account.data)?);
+    Ok(())
+}
+
+// Mint of the nft registered with this name, or its name seeded mint if it was minted before the registry
+fn nft_mint(rpc: &RpcClient, name: &str) -> CliResult<Pubkey> {
+    Ok(match fetch_name_record(&fetcher(rpc), name)? {
+        Some(record) => record.mint,
+        None => pda::find_legacy_nft_mint(name).0,
+    })
+}
+
+type Describe = fn(&[u8]) -> CliResult<String>;
+
+// Decodes an account of type T and pretty prints it
+fn show<T: AccountDeserialize + Debug>(data: &[u8]) -> CliResult<String> {
+    Ok(format!("{:#?}", state::decode::<T>(data)?))
+}
+
+// Finds the account type from the first 8 bytes of its data
+fn describe(data: &[u8]) -> CliResult<String> {
+    let accounts: [([u8; 8], Describe); 31] = [
+        (NftPDA::discriminator(), show::<NftPDA>),
+        (MintAuthority::discriminator(), show::<MintAuthority>),
+        (Treasury::discriminator(), show::<Treasury>),
+        (Election::discriminator(), show::<Election>),
+        (Vote::discriminator(), show::<Vote>),
+        (Auction::discriminator(), show::<Auction>),
+        (PaymentMint::discriminator(), show::<PaymentMint>),
+        (
+            ConservationProject::discriminator(),
+            show::<ConservationProject>,
+        ),
+        (Donor::discriminator(), show::<Donor>),
+        (TokenDonor::discriminator(), show::<TokenDonor>),
+        (Leaderboard::discriminator(), show::<Leaderboard>),
+        (BadgeTier::discriminator(), show::<BadgeTier>),
+        (SalePhase::discriminator(), show::<SalePhase>),
+        (PresalePurchases::discriminator(), show::<PresalePurchases>),
+        (ProgramConfig::discriminator(), show::<ProgramConfig>),
+        (PurchaseCounter::discriminator(), show::<PurchaseCounter>),
+        (Reservation::discriminator(), show::<Reservation>),
+        (
+            ReservationCooldown::discriminator(),
+            show::<ReservationCooldown>,
+        ),
+        (ElectionArchive::discriminator(), show::<ElectionArchive>),
+        (FieldReporter::discriminator(), show::<FieldReporter>),
+        (StatusLog::discriminator(), show::<StatusLog>),
+        (Escrow::discriminator(), show::<Escrow>),
+        (
+            TreasuryRecipient::discriminator(),
+            show::<TreasuryRecipient>,
+        ),
+        (GrantStream::discriminator(), show::<GrantStream>),
+        (
+            StreamCancellation::discriminator(),
+            show::<StreamCancellation>,
+        ),
+        (
+            CancellationBallot::discriminator(),
+            show::<CancellationBallot>,
+        ),
+        (PrintRun::discriminator(), show::<PrintRun>),
+        (CompressedTree::discriminator(), show::<CompressedTree>),
+        (NftCounter::discriminator(), show::<NftCounter>),
+        (NameRecord::discriminator(), show::<NameRecord>),
+        (
+            VoteReceiptConfig::discriminator(),
+            show::<VoteReceiptConfig>,
+        ),
+    ];
+
+    let discriminator = data.get(..8).ok_or("account data is too short")?;
+    let (_, describe) = accounts
+        .iter()
+        .find(|(known, _)| known == discriminator)
+        .ok_or("unknown account type")?;
+    describe(data)
+}
+
+fn parse_asset_kind(value: &str) -> CliResult<AssetKind> {
+    Ok(match value {
+        "animal" => AssetKind::Animal,
+        "animal-family" => AssetKind::AnimalFamily,
+        "habitat" => AssetKind::Habitat,
+        "reef" => AssetKind::Reef,
+        _ => return Err(format!("unknown asset kind {value}").into()),
+    })
+}
+
+fn parse_iucn_status(value: &str) -> CliResult<IucnStatus> {
+    Ok(match value {
+        "not-evaluated" => IucnStatus::NotEvaluated,
+        "data-deficient" => IucnStatus::DataDeficient,
+        "least-concern" => IucnStatus::LeastConcern,
+        "near-threatened" => IucnStatus::NearThreatened,
+        "vulnerable" => IucnStatus::Vulnerable,
+        "endangered" => IucnStatus::Endangered,
+        "critically-endangered" => IucnStatus::CriticallyEndangered,
+        "extinct-in-the-wild" => IucnStatus::ExtinctInTheWild,
+        "extinct" => IucnStatus::Extinct,
+        _ => return Err(format!("unknown IUCN status {value}").into()),
+    })
+}
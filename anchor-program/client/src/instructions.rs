@@ -0,0 +1,1122 @@
+// Instruction builders for every instruction of the program.
+// Each builder takes the program's typed instruction arguments (animal_kingdom::instruction::*) plus the
+// few addresses that cannot be derived, and fills in every PDA, associated token account and program id
+
+use anchor_lang::prelude::{AccountMeta, Pubkey};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use animal_kingdom::{accounts, instruction as args, BUBBLEGUM_ID, COMPRESSION_ID, ID, NOOP_ID};
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+
+use crate::pda::*;
+
+// Organisations of an election, in the order mauritius_wildlife_protection,
+// mauritius_nature_protection_society, mauritius_marine_life_protection (see state::election_organisations)
+pub type ElectionOrganisations = [Pubkey; 3];
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn ata(wallet: &Pubkey, mint: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(wallet, mint, &anchor_spl::token::ID)
+}
+
+// Proof nodes of a compressed nft are passed as read only remaining accounts
+fn proof_accounts(proof: &[[u8; 32]]) -> Vec<AccountMeta> {
+    proof
+        .iter()
+        .map(|node| AccountMeta::new_readonly(Pubkey::new_from_array(*node), false))
+        .collect()
+}
+
+pub fn initialize_mint_authority(payer: Pubkey) -> Instruction {
+    build(
+        accounts::InitializeMintAuthority {
+            mint_authority: find_mint_authority().0,
+            system_program: system_program::ID,
+            token_program: anchor_spl::token::ID,
+            payer,
+        },
+        args::InitializeMintAuthority {},
+    )
+}
+
+// `nft_id` is the next id of the nft counter (see state::next_nft_id), which seeds the mint
+pub fn create_mint(
+    payer: Pubkey,
+    organisation_wallet: Pubkey,
+    nft_id: u64,
+    args: args::CreateMint,
+) -> Instruction {
+    let mint = find_nft_mint(nft_id).0;
+    let nft_pda = find_nft_pda(&mint).0;
+    build(
+        accounts::CreateMint {
+            nft_counter: find_nft_counter().0,
+            mint,
+            mint_authority: find_mint_authority().0,
+            token_program: anchor_spl::token::ID,
+            nft_pda,
+            token_account: ata(&nft_pda, &mint),
+            token_metadata_program: mpl_token_metadata::ID,
+            payer,
+            system_program: system_program::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            current_program: ID,
+            organisation_wallet,
+            config: find_config().0,
+            name_record: find_name_record(&args.name).0,
+        },
+        args,
+    )
+}
+
+pub fn create_metadata(payer: Pubkey, mint: Pubkey, args: args::CreateMetadata) -> Instruction {
+    build(
+        accounts::CreateMetadata {
+            mint,
+            mint_authority: find_mint_authority().0,
+            token_program: anchor_spl::token::ID,
+            metadata: find_metadata_account(&mint).0,
+            token_metadata_program: mpl_token_metadata::ID,
+            payer,
+            system_program: system_program::ID,
+            current_program: ID,
+            config: find_config().0,
+            name_record: find_name_record(&args.name).0,
+        },
+        args,
+    )
+}
+
+pub fn create_master_edition(
+    payer: Pubkey,
+    mint: Pubkey,
+    args: args::CreateMasterEdition,
+) -> Instruction {
+    build(
+        accounts::CreateMasterEdition {
+            mint,
+            mint_authority: find_mint_authority().0,
+            nft_pda: find_nft_pda(&mint).0,
+            token_program: anchor_spl::token::ID,
+            metadata: find_metadata_account(&mint).0,
+            token_metadata_program: mpl_token_metadata::ID,
+            payer,
+            system_program: system_program::ID,
+            master_edition: find_master_edition_account(&mint).0,
+            current_program: ID,
+            config: find_config().0,
+            print_run: find_print_run(&mint).0,
+        },
+        args,
+    )
+}
+
+// `reservation_buyer` is the buyer of an open reservation on the nft, or any account if there is none
+// `name` is the name the nft was registered with, so its name record is closed along with it
+pub fn delete_nft(
+    payer: Pubkey,
+    mint: Pubkey,
+    name: &str,
+    token_program: Pubkey,
+    reservation_buyer: Pubkey,
+    args: args::DeleteNft,
+) -> Instruction {
+    let nft_pda = find_nft_pda(&mint).0;
+    build(
+        accounts::DeleteNft {
+            metadata: find_metadata_account(&mint).0,
+            nft_pda,
+            mint,
+            token_account: get_associated_token_address_with_program_id(
+                &nft_pda,
+                &mint,
+                &token_program,
+            ),
+            master_edition: find_master_edition_account(&mint).0,
+            token_program,
+            token_metadata_program: mpl_token_metadata::ID,
+            mint_authority: find_mint_authority().0,
+            current_program: ID,
+            treasury_account: find_treasury().0,
+            auction_pda: find_auction(&mint).0,
+            reservation: find_reservation(&mint).0,
+            reservation_buyer,
+            escrow: find_escrow(&mint).0,
+            print_run: find_print_run(&mint).0,
+            status_log: find_status_log(&mint).0,
+            name_record: find_name_record(name).0,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `extra_accounts` are the accounts a Token-2022 transfer hook needs, if the mint has one
+pub fn transfer_nft(
+    payer: Pubkey,
+    mint: Pubkey,
+    project_account: Pubkey,
+    token_program: Pubkey,
+    extra_accounts: Vec<AccountMeta>,
+    args: args::TransferNft,
+) -> Instruction {
+    let nft_pda = find_nft_pda(&mint).0;
+    let mut instruction = build(
+        accounts::TransferNft {
+            project_account,
+            treasury_account: find_treasury().0,
+            from_account: get_associated_token_address_with_program_id(
+                &nft_pda,
+                &mint,
+                &token_program,
+            ),
+            to_account: get_associated_token_address_with_program_id(&payer, &mint, &token_program),
+            authority_account: nft_pda,
+            mint,
+            mint_authority: find_mint_authority().0,
+            current_program: ID,
+            token_program,
+            associated_token_program: anchor_spl::associated_token::ID,
+            donor_pda: find_donor(&payer).0,
+            leaderboard: find_leaderboard().0,
+            sale_phase: find_sale_phase().0,
+            config: find_config().0,
+            purchase_counter: find_purchase_counter(&payer).0,
+            reservation: find_reservation(&mint).0,
+            escrow: find_escrow(&mint).0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    );
+    instruction.accounts.extend(extra_accounts);
+    instruction
+}
+
+pub fn create_election(
+    payer: Pubkey,
+    organisations: ElectionOrganisations,
+    args: args::CreateElection,
+) -> Instruction {
+    build(
+        accounts::CreateElection {
+            election_pda: find_election(args.date_of_election).0,
+            mauritius_wildlife_protection: organisations[0],
+            mauritius_nature_protection_society: organisations[1],
+            mauritius_marine_life_protection: organisations[2],
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn create_vote(
+    payer: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+    organisations: ElectionOrganisations,
+    args: args::CreateVote,
+) -> Instruction {
+    let election_pda = find_election(args._date_of_election).0;
+    build(
+        accounts::CreateVote {
+            vote_pda: find_vote(&mint, &election_pda).0,
+            election_pda,
+            mint,
+            nft_pda: find_nft_pda(&mint).0,
+            mint_authority: find_mint_authority().0,
+            ata_account: get_associated_token_address_with_program_id(
+                &payer,
+                &mint,
+                &token_program,
+            ),
+            token_program,
+            mauritius_wildlife_protection: organisations[0],
+            mauritius_nature_protection_society: organisations[1],
+            mauritius_marine_life_protection: organisations[2],
+            donor_pda: find_donor(&payer).0,
+            config: find_config().0,
+            system_program: system_program::ID,
+            current_program: ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn set_vote_receipt(payer: Pubkey, args: args::SetVoteReceipt) -> Instruction {
+    let election_pda = find_election(args._date_of_election).0;
+    build(
+        accounts::SetVoteReceipt {
+            receipt_config: find_vote_receipt_config(&election_pda).0,
+            election_pda,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `nft` is the mint the vote was cast with, or the asset id of a compressed nft
+pub fn claim_vote_receipt(payer: Pubkey, nft: Pubkey, args: args::ClaimVoteReceipt) -> Instruction {
+    let election_pda = find_election(args._date_of_election).0;
+    let vote_pda = find_vote(&nft, &election_pda).0;
+    let receipt_mint = find_vote_receipt_mint(&vote_pda).0;
+    build(
+        accounts::ClaimVoteReceipt {
+            receipt_config: find_vote_receipt_config(&election_pda).0,
+            vote_pda,
+            election_pda,
+            receipt_mint,
+            token_account: ata(&payer, &receipt_mint),
+            mint_authority: find_mint_authority().0,
+            metadata: find_metadata_account(&receipt_mint).0,
+            master_edition: find_master_edition_account(&receipt_mint).0,
+            token_metadata_program: mpl_token_metadata::ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn create_auction(payer: Pubkey, mint: Pubkey, args: args::CreateAuction) -> Instruction {
+    let nft_pda = find_nft_pda(&mint).0;
+    build(
+        accounts::CreateAuction {
+            auction_pda: find_auction(&mint).0,
+            nft_pda,
+            token_account: ata(&nft_pda, &mint),
+            mint,
+            reservation: find_reservation(&mint).0,
+            current_program: ID,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn buy_auction_nft(
+    payer: Pubkey,
+    mint: Pubkey,
+    project_account: Pubkey,
+    args: args::BuyAuctionNft,
+) -> Instruction {
+    let nft_pda = find_nft_pda(&mint).0;
+    build(
+        accounts::BuyAuctionNft {
+            auction_pda: find_auction(&mint).0,
+            sale_phase: find_sale_phase().0,
+            reservation: find_reservation(&mint).0,
+            project_account,
+            treasury_account: find_treasury().0,
+            from_account: ata(&nft_pda, &mint),
+            to_account: ata(&payer, &mint),
+            authority_account: nft_pda,
+            mint,
+            current_program: ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            donor_pda: find_donor(&payer).0,
+            leaderboard: find_leaderboard().0,
+            config: find_config().0,
+            purchase_counter: find_purchase_counter(&payer).0,
+            escrow: find_escrow(&mint).0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn set_payment_mint(
+    payer: Pubkey,
+    payment_mint: Pubkey,
+    args: args::SetPaymentMint,
+) -> Instruction {
+    let treasury_account = find_treasury().0;
+    build(
+        accounts::SetPaymentMint {
+            payment_mint_pda: find_payment_mint(&payment_mint).0,
+            payment_mint,
+            treasury_account,
+            treasury_vault: ata(&treasury_account, &payment_mint),
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// Pays with the payer's associated token account of `payment_mint`
+pub fn transfer_nft_with_token(
+    payer: Pubkey,
+    mint: Pubkey,
+    project_account: Pubkey,
+    payment_mint: Pubkey,
+    args: args::TransferNftWithToken,
+) -> Instruction {
+    let nft_pda = find_nft_pda(&mint).0;
+    let treasury_account = find_treasury().0;
+    build(
+        accounts::TransferNftWithToken {
+            payment_mint_pda: find_payment_mint(&payment_mint).0,
+            payment_mint,
+            payer_token_account: ata(&payer, &payment_mint),
+            project_account,
+            project_token_account: ata(&project_account, &payment_mint),
+            treasury_account,
+            treasury_vault: ata(&treasury_account, &payment_mint),
+            from_account: ata(&nft_pda, &mint),
+            to_account: ata(&payer, &mint),
+            authority_account: nft_pda,
+            mint,
+            current_program: ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            donor_pda: find_donor(&payer).0,
+            leaderboard: find_leaderboard().0,
+            sale_phase: find_sale_phase().0,
+            config: find_config().0,
+            purchase_counter: find_purchase_counter(&payer).0,
+            reservation: find_reservation(&mint).0,
+            escrow: find_escrow(&mint).0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn register_project(
+    payer: Pubkey,
+    project_account: Pubkey,
+    args: args::RegisterProject,
+) -> Instruction {
+    build(
+        accounts::RegisterProject {
+            project_pda: find_project(&project_account).0,
+            project_account,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `project_account` can be None for donations that only go to the treasury
+pub fn donate(payer: Pubkey, project_account: Option<Pubkey>, args: args::Donate) -> Instruction {
+    let project_account = project_account.unwrap_or(payer);
+    build(
+        accounts::Donate {
+            donor_pda: find_donor(&payer).0,
+            project_pda: find_project(&project_account).0,
+            project_account,
+            treasury_account: find_treasury().0,
+            leaderboard: find_leaderboard().0,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// Donates from the payer's associated token account of `payment_mint`.
+// `project_account` can be None for donations that only go to the treasury
+pub fn donate_token(
+    payer: Pubkey,
+    project_account: Option<Pubkey>,
+    payment_mint: Pubkey,
+    args: args::DonateToken,
+) -> Instruction {
+    let project_account = project_account.unwrap_or(payer);
+    let treasury_account = find_treasury().0;
+    build(
+        accounts::DonateToken {
+            donor_pda: find_donor(&payer).0,
+            token_donor_pda: find_token_donor(&payer, &payment_mint).0,
+            project_pda: find_project(&project_account).0,
+            project_account,
+            payment_mint_pda: find_payment_mint(&payment_mint).0,
+            payment_mint,
+            payer_token_account: ata(&payer, &payment_mint),
+            project_token_account: ata(&project_account, &payment_mint),
+            treasury_account,
+            treasury_vault: ata(&treasury_account, &payment_mint),
+            leaderboard: find_leaderboard().0,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn set_badge_tier(payer: Pubkey, args: args::SetBadgeTier) -> Instruction {
+    build(
+        accounts::SetBadgeTier {
+            badge_tier_pda: find_badge_tier(args.tier).0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn claim_badge(payer: Pubkey, args: args::ClaimBadge) -> Instruction {
+    let badge_mint = find_badge_mint(args._tier, &payer).0;
+    build(
+        accounts::ClaimBadge {
+            badge_tier_pda: find_badge_tier(args._tier).0,
+            donor_pda: find_donor(&payer).0,
+            badge_mint,
+            token_account: ata(&payer, &badge_mint),
+            mint_authority: find_mint_authority().0,
+            metadata: find_metadata_account(&badge_mint).0,
+            token_metadata_program: mpl_token_metadata::ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn set_sale_phase(payer: Pubkey, args: args::SetSalePhase) -> Instruction {
+    build(
+        accounts::SetSalePhase {
+            sale_phase: find_sale_phase().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `presale_start` is the start of the current presale (see state::fetch_sale_phase)
+pub fn presale_transfer_nft(
+    payer: Pubkey,
+    mint: Pubkey,
+    project_account: Pubkey,
+    presale_start: i64,
+    args: args::PresaleTransferNft,
+) -> Instruction {
+    let nft_pda = find_nft_pda(&mint).0;
+    build(
+        accounts::PresaleTransferNft {
+            sale_phase: find_sale_phase().0,
+            presale_purchases: find_presale_purchases(&payer, presale_start).0,
+            project_account,
+            treasury_account: find_treasury().0,
+            from_account: ata(&nft_pda, &mint),
+            to_account: ata(&payer, &mint),
+            authority_account: nft_pda,
+            mint,
+            current_program: ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            donor_pda: find_donor(&payer).0,
+            leaderboard: find_leaderboard().0,
+            config: find_config().0,
+            purchase_counter: find_purchase_counter(&payer).0,
+            reservation: find_reservation(&mint).0,
+            escrow: find_escrow(&mint).0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn set_purchase_limits(payer: Pubkey, args: args::SetPurchaseLimits) -> Instruction {
+    build(
+        accounts::SetPurchaseLimits {
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn set_reservation_terms(payer: Pubkey, args: args::SetReservationTerms) -> Instruction {
+    build(
+        accounts::SetReservationTerms {
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn reserve_nft(payer: Pubkey, mint: Pubkey, args: args::ReserveNft) -> Instruction {
+    let nft_pda = find_nft_pda(&mint).0;
+    build(
+        accounts::ReserveNft {
+            reservation: find_reservation(&mint).0,
+            cooldown: find_reservation_cooldown(&mint, &payer).0,
+            config: find_config().0,
+            nft_pda,
+            token_account: ata(&nft_pda, &mint),
+            mint,
+            current_program: ID,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn release_reservation(payer: Pubkey, mint: Pubkey, buyer: Pubkey) -> Instruction {
+    build(
+        accounts::ReleaseReservation {
+            reservation: find_reservation(&mint).0,
+            buyer,
+            payer,
+        },
+        args::ReleaseReservation {},
+    )
+}
+
+pub fn finalize_election(payer: Pubkey, args: args::FinalizeElection) -> Instruction {
+    let election_pda = find_election(args._date_of_election).0;
+    build(
+        accounts::FinalizeElection {
+            election_pda,
+            archive: find_election_archive(&election_pda).0,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `nft` is the mint the vote was cast with, or the asset id of a compressed nft
+pub fn close_vote(voter: Pubkey, nft: Pubkey, args: args::CloseVote) -> Instruction {
+    let election_pda = find_election(args._date_of_election).0;
+    build(
+        accounts::CloseVote {
+            vote_pda: find_vote(&nft, &election_pda).0,
+            election_pda,
+            archive: find_election_archive(&election_pda).0,
+            voter,
+        },
+        args,
+    )
+}
+
+pub fn close_election(payer: Pubkey, args: args::CloseElection) -> Instruction {
+    build(
+        accounts::CloseElection {
+            election_pda: find_election(args._date_of_election).0,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn set_guardian(payer: Pubkey, args: args::SetGuardian) -> Instruction {
+    build(
+        accounts::SetGuardian {
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn set_paused(payer: Pubkey, args: args::SetPaused) -> Instruction {
+    build(
+        accounts::SetPaused {
+            config: find_config().0,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn migrate_nft_pda(payer: Pubkey, mint: Pubkey, args: args::MigrateNftPda) -> Instruction {
+    build(
+        accounts::MigrateNftPda {
+            nft_pda: find_nft_pda(&mint).0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn migrate_election(payer: Pubkey, args: args::MigrateElection) -> Instruction {
+    build(
+        accounts::MigrateElection {
+            election_pda: find_election(args._date_of_election).0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn migrate_nft_name(payer: Pubkey, args: args::MigrateNftName) -> Instruction {
+    let mint = find_legacy_nft_mint(&args.name).0;
+    build(
+        accounts::MigrateNftName {
+            name_record: find_name_record(&args.name).0,
+            mint,
+            nft_pda: find_nft_pda(&mint).0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `current_name` is the name the nft is registered with now
+pub fn rename_nft(
+    payer: Pubkey,
+    mint: Pubkey,
+    current_name: &str,
+    args: args::RenameNft,
+) -> Instruction {
+    build(
+        accounts::RenameNft {
+            name_record: find_name_record(current_name).0,
+            new_name_record: find_name_record(&args.new_name).0,
+            mint,
+            metadata: find_metadata_account(&mint).0,
+            mint_authority: find_mint_authority().0,
+            token_metadata_program: mpl_token_metadata::ID,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn set_field_reporter(
+    payer: Pubkey,
+    project_account: Pubkey,
+    args: args::SetFieldReporter,
+) -> Instruction {
+    build(
+        accounts::SetFieldReporter {
+            reporter_pda: find_field_reporter(&project_account, &args.reporter).0,
+            project_pda: find_project(&project_account).0,
+            project_account,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `project_account` is the conservation project of the nft, which registered the reporter
+pub fn post_status_update(
+    reporter: Pubkey,
+    mint: Pubkey,
+    project_account: Pubkey,
+    args: args::PostStatusUpdate,
+) -> Instruction {
+    build(
+        accounts::PostStatusUpdate {
+            status_log: find_status_log(&mint).0,
+            nft_pda: find_nft_pda(&mint).0,
+            reporter_pda: find_field_reporter(&project_account, &reporter).0,
+            system_program: system_program::ID,
+            reporter,
+        },
+        args,
+    )
+}
+
+pub fn create_escrow(payer: Pubkey, mint: Pubkey, args: args::CreateEscrow) -> Instruction {
+    let nft_pda = find_nft_pda(&mint).0;
+    build(
+        accounts::CreateEscrow {
+            escrow: find_escrow(&mint).0,
+            nft_pda,
+            token_account: ata(&nft_pda, &mint),
+            mint,
+            current_program: ID,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn post_milestone_evidence(
+    project: Pubkey,
+    mint: Pubkey,
+    args: args::PostMilestoneEvidence,
+) -> Instruction {
+    build(
+        accounts::PostMilestoneEvidence {
+            escrow: find_escrow(&mint).0,
+            project,
+        },
+        args,
+    )
+}
+
+pub fn attest_milestone(
+    payer: Pubkey,
+    mint: Pubkey,
+    project_account: Pubkey,
+    args: args::AttestMilestone,
+) -> Instruction {
+    build(
+        accounts::AttestMilestone {
+            escrow: find_escrow(&mint).0,
+            project_account,
+            config: find_config().0,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn refund_milestone(payer: Pubkey, mint: Pubkey, args: args::RefundMilestone) -> Instruction {
+    build(
+        accounts::RefundMilestone {
+            escrow: find_escrow(&mint).0,
+            treasury_account: find_treasury().0,
+            config: find_config().0,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn withdraw_treasury(
+    payer: Pubkey,
+    recipient: Pubkey,
+    args: args::WithdrawTreasury,
+) -> Instruction {
+    build(
+        accounts::WithdrawTreasury {
+            treasury_account: find_treasury().0,
+            recipient_ledger: find_treasury_recipient(&recipient).0,
+            recipient,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `recipient` must be the winner recorded in the election's archive
+pub fn open_grant_stream(
+    payer: Pubkey,
+    date_of_election: i64,
+    args: args::OpenGrantStream,
+) -> Instruction {
+    let archive = find_election_archive(&find_election(date_of_election).0).0;
+    build(
+        accounts::OpenGrantStream {
+            grant_stream: find_grant_stream(&archive).0,
+            archive,
+            treasury_account: find_treasury().0,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn claim_grant(recipient: Pubkey, date_of_election: i64) -> Instruction {
+    let archive = find_election_archive(&find_election(date_of_election).0).0;
+    build(
+        accounts::ClaimGrant {
+            grant_stream: find_grant_stream(&archive).0,
+            archive,
+            config: find_config().0,
+            recipient,
+        },
+        args::ClaimGrant {},
+    )
+}
+
+// Puts the cancellation of the grant of `date_of_election` to the vote in the later election of
+// `args._date_of_election`
+pub fn propose_stream_cancellation(
+    payer: Pubkey,
+    date_of_election: i64,
+    args: args::ProposeStreamCancellation,
+) -> Instruction {
+    let archive = find_election_archive(&find_election(date_of_election).0).0;
+    let grant_stream = find_grant_stream(&archive).0;
+    let election_pda = find_election(args._date_of_election).0;
+    build(
+        accounts::ProposeStreamCancellation {
+            cancellation: find_stream_cancellation(&grant_stream, &election_pda).0,
+            grant_stream,
+            archive,
+            election_pda,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `nft` is the mint the voter voted with in the later election, or the asset id of a compressed nft
+pub fn vote_on_cancellation(
+    payer: Pubkey,
+    nft: Pubkey,
+    date_of_election: i64,
+    args: args::VoteOnCancellation,
+) -> Instruction {
+    let archive = find_election_archive(&find_election(date_of_election).0).0;
+    let election_pda = find_election(args._date_of_election).0;
+    let cancellation = find_stream_cancellation(&find_grant_stream(&archive).0, &election_pda).0;
+    let vote_pda = find_vote(&nft, &election_pda).0;
+    build(
+        accounts::VoteOnCancellation {
+            cancellation,
+            election_pda,
+            vote_pda,
+            ballot: find_cancellation_ballot(&cancellation, &vote_pda).0,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `later_date_of_election` is the later election whose vote decided to cancel the stream
+pub fn cancel_grant_stream(
+    payer: Pubkey,
+    date_of_election: i64,
+    later_date_of_election: i64,
+) -> Instruction {
+    let archive = find_election_archive(&find_election(date_of_election).0).0;
+    let grant_stream = find_grant_stream(&archive).0;
+    let later_election = find_election(later_date_of_election).0;
+    build(
+        accounts::CancelGrantStream {
+            grant_stream,
+            archive,
+            later_archive: find_election_archive(&later_election).0,
+            cancellation: find_stream_cancellation(&grant_stream, &later_election).0,
+            treasury_account: find_treasury().0,
+            payer,
+        },
+        args::CancelGrantStream {},
+    )
+}
+
+pub fn mint_edition(
+    payer: Pubkey,
+    mint: Pubkey,
+    project_account: Pubkey,
+    args: args::MintEdition,
+) -> Instruction {
+    let nft_pda = find_nft_pda(&mint).0;
+    let edition_mint = find_edition_mint(&mint, args.edition_number).0;
+    build(
+        accounts::MintEdition {
+            print_run: find_print_run(&mint).0,
+            nft_pda,
+            master_token_account: ata(&nft_pda, &mint),
+            mint,
+            metadata: find_metadata_account(&mint).0,
+            master_edition: find_master_edition_account(&mint).0,
+            edition_mint,
+            edition_metadata: find_metadata_account(&edition_mint).0,
+            edition: find_master_edition_account(&edition_mint).0,
+            edition_marker: find_edition_marker(&mint, args.edition_number).0,
+            to_account: ata(&payer, &edition_mint),
+            mint_authority: find_mint_authority().0,
+            project_account,
+            treasury_account: find_treasury().0,
+            escrow: find_escrow(&mint).0,
+            donor_pda: find_donor(&payer).0,
+            leaderboard: find_leaderboard().0,
+            sale_phase: find_sale_phase().0,
+            config: find_config().0,
+            purchase_counter: find_purchase_counter(&payer).0,
+            current_program: ID,
+            token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            token_metadata_program: mpl_token_metadata::ID,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `nft_id` is the next id of the nft counter (see state::next_nft_id), which seeds the mint
+pub fn create_mint_2022(
+    payer: Pubkey,
+    organisation_wallet: Pubkey,
+    nft_id: u64,
+    args: args::CreateMint2022,
+) -> Instruction {
+    let mint = find_nft_mint(nft_id).0;
+    let nft_pda = find_nft_pda(&mint).0;
+    build(
+        accounts::CreateMint2022 {
+            nft_counter: find_nft_counter().0,
+            mint,
+            mint_authority: find_mint_authority().0,
+            nft_pda,
+            token_account: get_associated_token_address_with_program_id(
+                &nft_pda,
+                &mint,
+                &spl_token_2022::ID,
+            ),
+            organisation_wallet,
+            config: find_config().0,
+            name_record: find_name_record(&args.name).0,
+            current_program: ID,
+            token_program: spl_token_2022::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// The merkle tree account must already be allocated by the account compression program
+pub fn create_compressed_tree(
+    payer: Pubkey,
+    merkle_tree: Pubkey,
+    project_account: Pubkey,
+    args: args::CreateCompressedTree,
+) -> Instruction {
+    build(
+        accounts::CreateCompressedTree {
+            compressed_tree: find_compressed_tree(&merkle_tree).0,
+            merkle_tree,
+            tree_authority: find_tree_authority(&merkle_tree).0,
+            project_pda: find_project(&project_account).0,
+            project_account,
+            bubblegum_program: BUBBLEGUM_ID,
+            compression_program: COMPRESSION_ID,
+            log_wrapper: NOOP_ID,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+pub fn mint_compressed_nft(
+    payer: Pubkey,
+    merkle_tree: Pubkey,
+    args: args::MintCompressedNft,
+) -> Instruction {
+    build(
+        accounts::MintCompressedNft {
+            compressed_tree: find_compressed_tree(&merkle_tree).0,
+            merkle_tree,
+            tree_authority: find_tree_authority(&merkle_tree).0,
+            bubblegum_program: BUBBLEGUM_ID,
+            compression_program: COMPRESSION_ID,
+            log_wrapper: NOOP_ID,
+            config: find_config().0,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    )
+}
+
+// `proof` is the merkle proof of the leaf, from the leaf up to the root
+pub fn buy_compressed_nft(
+    payer: Pubkey,
+    merkle_tree: Pubkey,
+    project_account: Pubkey,
+    proof: &[[u8; 32]],
+    args: args::BuyCompressedNft,
+) -> Instruction {
+    let mut instruction = build(
+        accounts::BuyCompressedNft {
+            compressed_tree: find_compressed_tree(&merkle_tree).0,
+            merkle_tree,
+            tree_authority: find_tree_authority(&merkle_tree).0,
+            project_account,
+            treasury_account: find_treasury().0,
+            donor_pda: find_donor(&payer).0,
+            leaderboard: find_leaderboard().0,
+            sale_phase: find_sale_phase().0,
+            config: find_config().0,
+            purchase_counter: find_purchase_counter(&payer).0,
+            bubblegum_program: BUBBLEGUM_ID,
+            compression_program: COMPRESSION_ID,
+            log_wrapper: NOOP_ID,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    );
+    instruction.accounts.extend(proof_accounts(proof));
+    instruction
+}
+
+// `proof` is the merkle proof of the leaf, from the leaf up to the root
+pub fn create_compressed_vote(
+    payer: Pubkey,
+    merkle_tree: Pubkey,
+    leaf_delegate: Pubkey,
+    organisations: ElectionOrganisations,
+    proof: &[[u8; 32]],
+    args: args::CreateCompressedVote,
+) -> Instruction {
+    let election_pda = find_election(args._date_of_election).0;
+    let asset = compressed_asset_id(&merkle_tree, args.leaf.nonce);
+    let mut instruction = build(
+        accounts::CreateCompressedVote {
+            vote_pda: find_vote(&asset, &election_pda).0,
+            election_pda,
+            asset,
+            compressed_tree: find_compressed_tree(&merkle_tree).0,
+            merkle_tree,
+            leaf_delegate,
+            mauritius_wildlife_protection: organisations[0],
+            mauritius_nature_protection_society: organisations[1],
+            mauritius_marine_life_protection: organisations[2],
+            donor_pda: find_donor(&payer).0,
+            config: find_config().0,
+            compression_program: COMPRESSION_ID,
+            system_program: system_program::ID,
+            payer,
+        },
+        args,
+    );
+    instruction.accounts.extend(proof_accounts(proof));
+    instruction
+}
@@ -0,0 +1,21 @@
+// Client SDK for the animal-kingdom program.
+//
+// pda          - find_* helpers for every PDA the program derives
+// instructions - builders that assemble the accounts of every instruction
+// state        - decoding and fetching of the program's accounts
+
+pub mod instructions;
+pub mod pda;
+pub mod state;
+
+pub use animal_kingdom;
+pub use animal_kingdom::instruction as args;
+pub use animal_kingdom::ID;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[error("failed to fetch account: {0}")]
+    Fetch(#[from] Box<dyn std::error::Error + Send + Sync>),
+    #[error("failed to decode account: {0}")]
+    Decode(#[from] Box<anchor_lang::error::Error>),
+}
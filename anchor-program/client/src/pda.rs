@@ -0,0 +1,249 @@
+// Addresses of every account the program derives, using the seed constants of the program's constraints.
+// Each helper returns the address and its bump, like mpl_token_metadata's find_* helpers
+
+use anchor_lang::prelude::Pubkey;
+use animal_kingdom::{
+    ARCHIVE_SEED, AUCTION_SEED, BADGE_MINT_SEED, BADGE_TIER_SEED, BUBBLEGUM_ID,
+    CANCELLATION_BALLOT_SEED, COMPRESSED_TREE_SEED, CONFIG_SEED, DONOR_SEED, EDITION_MINT_SEED,
+    ELECTION_SEED, ESCROW_SEED, FIELD_REPORTER_SEED, GRANT_STREAM_SEED, ID, LEADERBOARD_SEED,
+    MINT_AUTHORITY_SEED, NAME_RECORD_SEED, NFT_COUNTER_SEED, NFT_MINT_SEED, NFT_PDA_SEED,
+    PAYMENT_MINT_SEED, PRESALE_SEED, PRINT_RUN_SEED, PROJECT_SEED, PURCHASE_COUNTER_SEED,
+    RESERVATION_COOLDOWN_SEED, RESERVATION_SEED, SALE_PHASE_SEED, STATUS_LOG_SEED,
+    STREAM_CANCELLATION_SEED, TOKEN_DONOR_SEED, TREASURY_RECIPIENT_SEED, TREASURY_SEED,
+    VOTE_RECEIPT_CONFIG_SEED, VOTE_RECEIPT_SEED,
+};
+
+pub use animal_kingdom::{compressed_asset_id, name_hash, normalise_name};
+pub use mpl_token_metadata::pda::{
+    find_edition_account, find_master_edition_account, find_metadata_account,
+};
+
+// Signs for every nft mint and is the update authority of their metadata
+pub fn find_mint_authority() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[MINT_AUTHORITY_SEED], &ID)
+}
+
+// Nft mints are derived from the sequential id the nft counter gave them
+pub fn find_nft_mint(nft_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NFT_MINT_SEED, nft_id.to_le_bytes().as_ref()], &ID)
+}
+
+// Mints of nfts minted before sequential ids were derived from their name
+pub fn find_legacy_nft_mint(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[name.as_bytes(), ID.as_ref()], &ID)
+}
+
+// Hands out the id of the next nft
+pub fn find_nft_counter() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NFT_COUNTER_SEED], &ID)
+}
+
+// Maps a name to the mint of the nft registered with it - any spelling that normalises to the same name
+// finds the same record
+pub fn find_name_record(name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NAME_RECORD_SEED, name_hash(name).as_ref()], &ID)
+}
+
+// Holds the unsold nft and its conservation project
+pub fn find_nft_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[NFT_PDA_SEED, mint.as_ref()], &ID)
+}
+
+pub fn find_treasury() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_SEED], &ID)
+}
+
+pub fn find_config() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED], &ID)
+}
+
+pub fn find_leaderboard() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[LEADERBOARD_SEED], &ID)
+}
+
+pub fn find_sale_phase() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SALE_PHASE_SEED], &ID)
+}
+
+// Elections are derived from the decimal string of their unix timestamp
+pub fn find_election(date_of_election: i64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        &ID,
+    )
+}
+
+// One vote per nft per election - `nft` is the mint, or the asset id of a compressed nft
+pub fn find_vote(nft: &Pubkey, election: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[nft.as_ref(), election.as_ref()], &ID)
+}
+
+// Name, symbol and uri of the "I voted" receipt of an election
+pub fn find_vote_receipt_config(election: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOTE_RECEIPT_CONFIG_SEED, election.as_ref()], &ID)
+}
+
+// One receipt per vote pda
+pub fn find_vote_receipt_mint(vote: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[VOTE_RECEIPT_SEED, vote.as_ref()], &ID)
+}
+
+pub fn find_election_archive(election: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ARCHIVE_SEED, election.as_ref()], &ID)
+}
+
+pub fn find_auction(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[AUCTION_SEED, mint.as_ref()], &ID)
+}
+
+pub fn find_reservation(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[RESERVATION_SEED, mint.as_ref()], &ID)
+}
+
+pub fn find_reservation_cooldown(mint: &Pubkey, buyer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RESERVATION_COOLDOWN_SEED, mint.as_ref(), buyer.as_ref()],
+        &ID,
+    )
+}
+
+pub fn find_escrow(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ESCROW_SEED, mint.as_ref()], &ID)
+}
+
+pub fn find_print_run(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PRINT_RUN_SEED, mint.as_ref()], &ID)
+}
+
+// Mint of a numbered print of a master edition
+pub fn find_edition_mint(mint: &Pubkey, edition_number: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            EDITION_MINT_SEED,
+            mint.as_ref(),
+            edition_number.to_le_bytes().as_ref(),
+        ],
+        &ID,
+    )
+}
+
+// Marker account that records which prints of a master edition have been minted
+pub fn find_edition_marker(mint: &Pubkey, edition_number: u64) -> (Pubkey, u8) {
+    find_edition_account(
+        mint,
+        (edition_number / mpl_token_metadata::state::EDITION_MARKER_BIT_SIZE).to_string(),
+    )
+}
+
+pub fn find_status_log(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[STATUS_LOG_SEED, mint.as_ref()], &ID)
+}
+
+pub fn find_donor(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[DONOR_SEED, wallet.as_ref()], &ID)
+}
+
+pub fn find_token_donor(wallet: &Pubkey, payment_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[TOKEN_DONOR_SEED, wallet.as_ref(), payment_mint.as_ref()],
+        &ID,
+    )
+}
+
+pub fn find_purchase_counter(wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PURCHASE_COUNTER_SEED, wallet.as_ref()], &ID)
+}
+
+// Presale purchases are counted per presale, identified by its start (see SalePhase::presale_start)
+pub fn find_presale_purchases(wallet: &Pubkey, presale_start: i64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PRESALE_SEED, wallet.as_ref(), &presale_start.to_le_bytes()],
+        &ID,
+    )
+}
+
+// Registration of a conservation project's wallet
+pub fn find_project(project_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROJECT_SEED, project_account.as_ref()], &ID)
+}
+
+pub fn find_field_reporter(project_account: &Pubkey, reporter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FIELD_REPORTER_SEED,
+            project_account.as_ref(),
+            reporter.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_payment_mint(payment_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PAYMENT_MINT_SEED, payment_mint.as_ref()], &ID)
+}
+
+pub fn find_badge_tier(tier: u8) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BADGE_TIER_SEED, &[tier]], &ID)
+}
+
+pub fn find_badge_mint(tier: u8, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BADGE_MINT_SEED, &[tier], wallet.as_ref()], &ID)
+}
+
+pub fn find_treasury_recipient(recipient: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[TREASURY_RECIPIENT_SEED, recipient.as_ref()], &ID)
+}
+
+pub fn find_grant_stream(archive: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[GRANT_STREAM_SEED, archive.as_ref()], &ID)
+}
+
+// Proposal to cancel a grant stream, voted on in a later election
+pub fn find_stream_cancellation(grant_stream: &Pubkey, election: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            STREAM_CANCELLATION_SEED,
+            grant_stream.as_ref(),
+            election.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+// One ballot per vote pda on a stream cancellation
+pub fn find_cancellation_ballot(cancellation: &Pubkey, vote: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            CANCELLATION_BALLOT_SEED,
+            cancellation.as_ref(),
+            vote.as_ref(),
+        ],
+        &ID,
+    )
+}
+
+pub fn find_compressed_tree(merkle_tree: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[COMPRESSED_TREE_SEED, merkle_tree.as_ref()], &ID)
+}
+
+// Bubblegum's config account for a merkle tree
+pub fn find_tree_authority(merkle_tree: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[merkle_tree.as_ref()], &BUBBLEGUM_ID)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_records_ignore_case_and_extra_whitespace() {
+        assert_eq!(
+            find_name_record("Pink Pigeon"),
+            find_name_record("  pink   PIGEON ")
+        );
+        assert_ne!(
+            find_name_record("Pink Pigeon"),
+            find_name_record("Pink Pigeons")
+        );
+    }
+}
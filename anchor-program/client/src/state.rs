@@ -0,0 +1,307 @@
+// Decoding and fetching of the program's accounts.
+// The crate doesn't depend on an rpc client - anything that can load an account's data implements
+// AccountFetcher, including a closure around RpcClient::get_account_with_commitment
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+
+pub use animal_kingdom::{
+    Auction, BadgeTier, CancellationBallot, CompressedTree, ConservationProject, Donor, Election,
+    ElectionArchive, Escrow, FieldReporter, GrantStream, Leaderboard, MintAuthority, NameRecord,
+    NftCounter, NftPDA, PaymentMint, PresalePurchases, PrintRun, ProgramConfig, PurchaseCounter,
+    Reservation, ReservationCooldown, SalePhase, StatusLog, StatusUpdate, StreamCancellation,
+    TokenDonor, Treasury, TreasuryRecipient, Vote, VoteReceiptConfig,
+};
+
+use crate::instructions::ElectionOrganisations;
+use crate::pda::*;
+use crate::ClientError;
+
+pub trait AccountFetcher {
+    // Data of the account, or None if it doesn't exist
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError>;
+}
+
+impl<F> AccountFetcher for F
+where
+    F: Fn(&Pubkey) -> Result<Option<Vec<u8>>, ClientError>,
+{
+    fn account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, ClientError> {
+        self(address)
+    }
+}
+
+// Decodes an account, checking its discriminator.
+// Nft and election PDAs that haven't been migrated to the current layout fail to decode
+pub fn decode<T: AccountDeserialize>(data: &[u8]) -> Result<T, ClientError> {
+    let mut data = data;
+    Ok(T::try_deserialize(&mut data).map_err(Box::new)?)
+}
+
+pub fn fetch<T: AccountDeserialize>(
+    fetcher: &impl AccountFetcher,
+    address: &Pubkey,
+) -> Result<Option<T>, ClientError> {
+    fetcher
+        .account_data(address)?
+        .map(|data| decode(&data))
+        .transpose()
+}
+
+// Organisation wallets of an election, in the order the vote instructions take them
+pub fn election_organisations(election: &Election) -> ElectionOrganisations {
+    [
+        election.organisations.mauritius_wildlife_protection.address,
+        election
+            .organisations
+            .mauritius_nature_protection_society
+            .address,
+        election
+            .organisations
+            .mauritius_marine_life_protection
+            .address,
+    ]
+}
+
+pub fn fetch_mint_authority(
+    fetcher: &impl AccountFetcher,
+) -> Result<Option<MintAuthority>, ClientError> {
+    fetch(fetcher, &find_mint_authority().0)
+}
+
+pub fn fetch_nft_counter(fetcher: &impl AccountFetcher) -> Result<Option<NftCounter>, ClientError> {
+    fetch(fetcher, &find_nft_counter().0)
+}
+
+// Id the next nft will be minted with - the counter is created by the first mint
+pub fn next_nft_id(fetcher: &impl AccountFetcher) -> Result<u64, ClientError> {
+    Ok(fetch_nft_counter(fetcher)?.map_or(0, |counter| counter.next_id))
+}
+
+pub fn fetch_name_record(
+    fetcher: &impl AccountFetcher,
+    name: &str,
+) -> Result<Option<NameRecord>, ClientError> {
+    fetch(fetcher, &find_name_record(name).0)
+}
+
+pub fn fetch_treasury(fetcher: &impl AccountFetcher) -> Result<Option<Treasury>, ClientError> {
+    fetch(fetcher, &find_treasury().0)
+}
+
+pub fn fetch_config(fetcher: &impl AccountFetcher) -> Result<Option<ProgramConfig>, ClientError> {
+    fetch(fetcher, &find_config().0)
+}
+
+pub fn fetch_leaderboard(
+    fetcher: &impl AccountFetcher,
+) -> Result<Option<Leaderboard>, ClientError> {
+    fetch(fetcher, &find_leaderboard().0)
+}
+
+pub fn fetch_sale_phase(fetcher: &impl AccountFetcher) -> Result<Option<SalePhase>, ClientError> {
+    fetch(fetcher, &find_sale_phase().0)
+}
+
+pub fn fetch_nft_pda(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+) -> Result<Option<NftPDA>, ClientError> {
+    fetch(fetcher, &find_nft_pda(mint).0)
+}
+
+pub fn fetch_auction(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+) -> Result<Option<Auction>, ClientError> {
+    fetch(fetcher, &find_auction(mint).0)
+}
+
+pub fn fetch_reservation(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+) -> Result<Option<Reservation>, ClientError> {
+    fetch(fetcher, &find_reservation(mint).0)
+}
+
+pub fn fetch_escrow(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+) -> Result<Option<Escrow>, ClientError> {
+    fetch(fetcher, &find_escrow(mint).0)
+}
+
+pub fn fetch_print_run(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+) -> Result<Option<PrintRun>, ClientError> {
+    fetch(fetcher, &find_print_run(mint).0)
+}
+
+pub fn fetch_status_log(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+) -> Result<Option<StatusLog>, ClientError> {
+    fetch(fetcher, &find_status_log(mint).0)
+}
+
+// Up to `limit` status updates of an nft starting at index `start`, oldest first
+pub fn fetch_status_updates(
+    fetcher: &impl AccountFetcher,
+    mint: &Pubkey,
+    start: u32,
+    limit: u32,
+) -> Result<Vec<StatusUpdate>, ClientError> {
+    let data = match fetcher.account_data(&find_status_log(mint).0)? {
+        Some(data) => data,
+        None => return Ok(Vec::new()),
+    };
+    let status_log: StatusLog = decode(&data)?;
+    let end = start.saturating_add(limit).min(status_log.count);
+    (start..end)
+        .map(|index| Ok(StatusLog::entry(&data, index).map_err(Box::new)?))
+        .collect()
+}
+
+pub fn fetch_election(
+    fetcher: &impl AccountFetcher,
+    date_of_election: i64,
+) -> Result<Option<Election>, ClientError> {
+    fetch(fetcher, &find_election(date_of_election).0)
+}
+
+pub fn fetch_election_archive(
+    fetcher: &impl AccountFetcher,
+    date_of_election: i64,
+) -> Result<Option<ElectionArchive>, ClientError> {
+    fetch(
+        fetcher,
+        &find_election_archive(&find_election(date_of_election).0).0,
+    )
+}
+
+// `nft` is the mint the vote was cast with, or the asset id of a compressed nft
+pub fn fetch_vote(
+    fetcher: &impl AccountFetcher,
+    nft: &Pubkey,
+    date_of_election: i64,
+) -> Result<Option<Vote>, ClientError> {
+    fetch(
+        fetcher,
+        &find_vote(nft, &find_election(date_of_election).0).0,
+    )
+}
+
+pub fn fetch_vote_receipt_config(
+    fetcher: &impl AccountFetcher,
+    date_of_election: i64,
+) -> Result<Option<VoteReceiptConfig>, ClientError> {
+    fetch(
+        fetcher,
+        &find_vote_receipt_config(&find_election(date_of_election).0).0,
+    )
+}
+
+pub fn fetch_grant_stream(
+    fetcher: &impl AccountFetcher,
+    date_of_election: i64,
+) -> Result<Option<GrantStream>, ClientError> {
+    let archive = find_election_archive(&find_election(date_of_election).0).0;
+    fetch(fetcher, &find_grant_stream(&archive).0)
+}
+
+// Cancellation of the grant of `date_of_election`, voted on in the election of `later_date_of_election`
+pub fn fetch_stream_cancellation(
+    fetcher: &impl AccountFetcher,
+    date_of_election: i64,
+    later_date_of_election: i64,
+) -> Result<Option<StreamCancellation>, ClientError> {
+    let archive = find_election_archive(&find_election(date_of_election).0).0;
+    fetch(
+        fetcher,
+        &find_stream_cancellation(
+            &find_grant_stream(&archive).0,
+            &find_election(later_date_of_election).0,
+        )
+        .0,
+    )
+}
+
+pub fn fetch_donor(
+    fetcher: &impl AccountFetcher,
+    wallet: &Pubkey,
+) -> Result<Option<Donor>, ClientError> {
+    fetch(fetcher, &find_donor(wallet).0)
+}
+
+pub fn fetch_token_donor(
+    fetcher: &impl AccountFetcher,
+    wallet: &Pubkey,
+    payment_mint: &Pubkey,
+) -> Result<Option<TokenDonor>, ClientError> {
+    fetch(fetcher, &find_token_donor(wallet, payment_mint).0)
+}
+
+pub fn fetch_purchase_counter(
+    fetcher: &impl AccountFetcher,
+    wallet: &Pubkey,
+) -> Result<Option<PurchaseCounter>, ClientError> {
+    fetch(fetcher, &find_purchase_counter(wallet).0)
+}
+
+// Purchases of the wallet in the current presale
+pub fn fetch_presale_purchases(
+    fetcher: &impl AccountFetcher,
+    wallet: &Pubkey,
+) -> Result<Option<PresalePurchases>, ClientError> {
+    match fetch_sale_phase(fetcher)? {
+        Some(sale_phase) => fetch(
+            fetcher,
+            &find_presale_purchases(wallet, sale_phase.presale_start).0,
+        ),
+        None => Ok(None),
+    }
+}
+
+pub fn fetch_project(
+    fetcher: &impl AccountFetcher,
+    project_account: &Pubkey,
+) -> Result<Option<ConservationProject>, ClientError> {
+    fetch(fetcher, &find_project(project_account).0)
+}
+
+pub fn fetch_field_reporter(
+    fetcher: &impl AccountFetcher,
+    project_account: &Pubkey,
+    reporter: &Pubkey,
+) -> Result<Option<FieldReporter>, ClientError> {
+    fetch(fetcher, &find_field_reporter(project_account, reporter).0)
+}
+
+pub fn fetch_payment_mint(
+    fetcher: &impl AccountFetcher,
+    payment_mint: &Pubkey,
+) -> Result<Option<PaymentMint>, ClientError> {
+    fetch(fetcher, &find_payment_mint(payment_mint).0)
+}
+
+pub fn fetch_badge_tier(
+    fetcher: &impl AccountFetcher,
+    tier: u8,
+) -> Result<Option<BadgeTier>, ClientError> {
+    fetch(fetcher, &find_badge_tier(tier).0)
+}
+
+pub fn fetch_treasury_recipient(
+    fetcher: &impl AccountFetcher,
+    recipient: &Pubkey,
+) -> Result<Option<TreasuryRecipient>, ClientError> {
+    fetch(fetcher, &find_treasury_recipient(recipient).0)
+}
+
+pub fn fetch_compressed_tree(
+    fetcher: &impl AccountFetcher,
+    merkle_tree: &Pubkey,
+) -> Result<Option<CompressedTree>, ClientError> {
+    fetch(fetcher, &find_compressed_tree(merkle_tree).0)
+}
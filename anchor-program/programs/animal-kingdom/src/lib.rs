@@ -1,15 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
 use anchor_lang::system_program;
+use anchor_lang::Discriminator;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{mint_to, transfer, Mint, MintTo, Token, TokenAccount, Transfer},
+    token::{
+        freeze_account, mint_to, transfer, FreezeAccount, Mint, MintTo, Token, TokenAccount,
+        Transfer,
+    },
 };
 use mpl_token_metadata::{
-    instruction::{burn_nft, create_master_edition_v3, create_metadata_accounts_v3},
-    pda::find_metadata_account,
+    instruction::{
+        burn_nft, create_master_edition_v3, create_metadata_accounts_v3,
+        mint_new_edition_from_master_edition_via_token, update_metadata_accounts_v2,
+    },
+    pda::{find_edition_account, find_master_edition_account, find_metadata_account},
+    state::{DataV2, Metadata, TokenMetadataAccount, EDITION_MARKER_BIT_SIZE},
     ID as MetadataTokenId,
 };
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::StateWithExtensions;
+use std::str::FromStr;
 
 declare_id!("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87");
 
@@ -19,18 +32,37 @@ pub mod animal_kingdom {
 
     pub fn initialize_mint_authority(ctx: Context<InitializeMintAuthority>) -> Result<()> {
         ctx.accounts.mint_authority.desc = String::from("PDA authority for NFT mints");
+        ctx.accounts.mint_authority.version = ACCOUNT_VERSION;
         Ok(())
     }
 
-    pub fn create_mint(ctx: Context<CreateMint>, _name: String) -> Result<()> {
+    pub fn create_mint(
+        ctx: Context<CreateMint>,
+        name: String,
+        ecology: EcologicalData,
+    ) -> Result<()> {
+        msg!("Registering the name of the nft");
+        let nft_id = ctx.accounts.nft_counter.take_id()?;
+        ctx.accounts
+            .name_record
+            .register(&name, ctx.accounts.mint.key(), Some(nft_id))?;
+        msg!("Registered {} as nft {}", name, nft_id);
+
         msg!("Adding organisation_wallet pubkey to nftPda");
         ctx.accounts.nft_pda.conservation_address =
             ctx.accounts.organisation_wallet.key().to_string().clone();
+        ctx.accounts.nft_pda.project = ctx.accounts.organisation_wallet.key();
+        ctx.accounts.nft_pda.version = NFT_PDA_VERSION;
         msg!("Added organisation_wallet pubkey to nftPda");
 
+        msg!("Adding ecological data to nftPda");
+        ecology.validate()?;
+        ctx.accounts.nft_pda.ecology = ecology;
+        msg!("Added ecological data to nftPda");
+
         msg!("Getting seeds of mint authority pda");
         let seeds = &[
-            "mint".as_bytes(),
+            MINT_AUTHORITY_SEED,
             &[*ctx.bumps.get("mint_authority").unwrap()],
         ];
         let signer = [&seeds[..]];
@@ -80,7 +112,7 @@ pub mod animal_kingdom {
 
         msg!("Getting seeds of mint authority pda");
         let seeds = &[
-            "mint".as_bytes(),
+            MINT_AUTHORITY_SEED,
             &[*ctx.bumps.get("mint_authority").unwrap()],
         ];
         let signer = [&seeds[..]];
@@ -136,12 +168,20 @@ pub mod animal_kingdom {
         Ok(())
     }
 
-    pub fn create_master_edition(ctx: Context<CreateMasterEdition>, _name: String) -> Result<()> {
+    pub fn create_master_edition(
+        ctx: Context<CreateMasterEdition>,
+        max_supply: u64,
+        print_price: u64,
+    ) -> Result<()> {
         msg!("starting create master edition process");
 
+        if max_supply > 0 && print_price == 0 {
+            return err!(InvalidEditionError::InvalidPrice);
+        }
+
         msg!("Getting seeds of mint authority pda");
         let seeds = &[
-            "mint".as_bytes(),
+            MINT_AUTHORITY_SEED,
             &[*ctx.bumps.get("mint_authority").unwrap()],
         ];
         let signer = [&seeds[..]];
@@ -168,66 +208,218 @@ pub mod animal_kingdom {
                 ctx.accounts.mint_authority.key(), // mint authority id - set it to the current program, need to check if I need to create a PDA for this
                 ctx.accounts.metadata.key(),       // metadata account id
                 ctx.accounts.payer.key(),          // payer id
-                Some(1),                           // max supply
+                Some(max_supply),                  // max supply - number of prints allowed
             ),
             master_edition_infos.as_slice(),
             &signer,
         )?;
         msg!("Master Edition Nft Minted !!!");
 
+        msg!("Updating newly created print_run");
+        ctx.accounts.print_run.desc = String::from("printRun");
+        ctx.accounts.print_run.version = ACCOUNT_VERSION;
+        ctx.accounts.print_run.mint = ctx.accounts.mint.key();
+        ctx.accounts.print_run.max_supply = max_supply;
+        ctx.accounts.print_run.price = print_price;
+        msg!("Successfully updated newly created print_run");
+
         Ok(())
     }
 
-    pub fn delete_nft(ctx: Context<DeleteNft>, _name: String) -> Result<()> {
+    pub fn delete_nft(ctx: Context<DeleteNft>, reason: String) -> Result<()> {
         msg!("starting burn nft process");
 
+        if reason.len() > 100 {
+            return err!(InvalidBurnError::ReasonTooLong);
+        }
+
         msg!("Getting seeds of nft pda (owner of the nft)");
         let mint_key = ctx.accounts.mint.key().clone();
         let seeds = &[
-            "nftPDA".as_bytes(),
+            NFT_PDA_SEED,
             mint_key.as_ref(),
             &[*ctx.bumps.get("nft_pda").unwrap()],
         ];
         let signer = [&seeds[..]];
         msg!("Got seeds of nft pda");
 
-        msg!("Assinging account infos");
-        let burn_nft_account_infos = vec![
-            ctx.accounts.metadata.to_account_info(), // `[writable]` NFT metadata
-            ctx.accounts.nft_pda.to_account_info(),  // `[writable, signer]` Owner of NFT
-            ctx.accounts.mint.to_account_info(),     // `[writable]` Mint of NFT
-            ctx.accounts.token_account.to_account_info(), // `[writable]` NFT token account
-            ctx.accounts.master_edition.to_account_info(), // `[writable]` NFT edition account
-            ctx.accounts.token_program.to_account_info(), // `[]` SPL Token program
-                                                     // Optional `[writable]` Collection metadata account
-        ];
-        msg!("Account infos assigned");
-        msg!("Invoking metaplex");
-        invoke_signed(
-            &burn_nft(
-                ctx.accounts.token_metadata_program.key(), // program_id: Pubkey,
-                ctx.accounts.metadata.key(),               // metadata: Pubkey,
-                ctx.accounts.nft_pda.key(),                // owner: Pubkey,
-                ctx.accounts.mint.key(),                   // mint: Pubkey,
-                ctx.accounts.token_account.key(),          // token: Pubkey,
-                ctx.accounts.master_edition.key(),         // edition: Pubkey,
-                ctx.accounts.token_program.key(),          // spl_token: Pubkey,
-                None,                                      // collection_metadata: Option<Pubkey>,
-            ),
-            burn_nft_account_infos.as_slice(),
-            &signer,
-        )?;
+        if ctx.accounts.token_program.key() == spl_token_2022::ID {
+            msg!("Burning the token-2022 nft and closing its token account");
+            let token_account_rent = ctx.accounts.token_account.lamports();
+            invoke_signed(
+                &spl_token_2022::instruction::burn_checked(
+                    &spl_token_2022::ID,
+                    &ctx.accounts.token_account.key(),
+                    &ctx.accounts.mint.key(),
+                    &ctx.accounts.nft_pda.key(),
+                    &[],
+                    1,
+                    0,
+                )?,
+                &[
+                    ctx.accounts.token_account.to_account_info(),
+                    ctx.accounts.mint.to_account_info(),
+                    ctx.accounts.nft_pda.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                ],
+                &signer,
+            )?;
+            invoke_signed(
+                &spl_token_2022::instruction::close_account(
+                    &spl_token_2022::ID,
+                    &ctx.accounts.token_account.key(),
+                    &ctx.accounts.treasury_account.key(),
+                    &ctx.accounts.nft_pda.key(),
+                    &[],
+                )?,
+                &[
+                    ctx.accounts.token_account.to_account_info(),
+                    ctx.accounts.treasury_account.to_account_info(),
+                    ctx.accounts.nft_pda.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                ],
+                &signer,
+            )?;
+            ctx.accounts
+                .treasury_account
+                .record_inflow(TreasuryInflow::Other, token_account_rent);
+        } else {
+            msg!("Assinging account infos");
+            let burn_nft_account_infos = vec![
+                ctx.accounts.metadata.to_account_info(), // `[writable]` NFT metadata
+                ctx.accounts.nft_pda.to_account_info(),  // `[writable, signer]` Owner of NFT
+                ctx.accounts.mint.to_account_info(),     // `[writable]` Mint of NFT
+                ctx.accounts.token_account.to_account_info(), // `[writable]` NFT token account
+                ctx.accounts.master_edition.to_account_info(), // `[writable]` NFT edition account
+                ctx.accounts.token_program.to_account_info(), // `[]` SPL Token program
+                                                         // Optional `[writable]` Collection metadata account
+            ];
+            msg!("Account infos assigned");
+            msg!("Invoking metaplex");
+            invoke_signed(
+                &burn_nft(
+                    ctx.accounts.token_metadata_program.key(), // program_id: Pubkey,
+                    ctx.accounts.metadata.key(),               // metadata: Pubkey,
+                    ctx.accounts.nft_pda.key(),                // owner: Pubkey,
+                    ctx.accounts.mint.key(),                   // mint: Pubkey,
+                    ctx.accounts.token_account.key(),          // token: Pubkey,
+                    ctx.accounts.master_edition.key(),         // edition: Pubkey,
+                    ctx.accounts.token_program.key(),          // spl_token: Pubkey,
+                    None, // collection_metadata: Option<Pubkey>,
+                ),
+                burn_nft_account_infos.as_slice(),
+                &signer,
+            )?;
+        }
+
+        msg!("Closing the auction of the nft, if any");
+        let auction_pda = ctx.accounts.auction_pda.to_account_info();
+        if !auction_pda.data_is_empty() {
+            ctx.accounts
+                .treasury_account
+                .record_inflow(TreasuryInflow::Other, auction_pda.lamports());
+            Account::<Auction>::try_from(&auction_pda)?
+                .close(ctx.accounts.treasury_account.to_account_info())?;
+        }
+        msg!("Closing the reservation of the nft and refunding its buyer, if any");
+        let reservation = ctx.accounts.reservation.to_account_info();
+        if !reservation.data_is_empty() {
+            let reservation = Account::<Reservation>::try_from(&reservation)?;
+            if reservation.buyer != ctx.accounts.reservation_buyer.key() {
+                return err!(InvalidBurnError::InvalidReservationBuyer);
+            }
+            reservation.close(ctx.accounts.reservation_buyer.to_account_info())?;
+        }
+        msg!("Refunding the unsettled milestones of the nft's escrow and closing it, if any");
+        let escrow = ctx.accounts.escrow.to_account_info();
+        if !escrow.data_is_empty() {
+            let mut escrow_account = Account::<Escrow>::try_from(&escrow)?;
+            let mut refunded = 0;
+            for index in 0..escrow_account.milestones.len() {
+                let status = escrow_account.milestones[index].status;
+                if status != MilestoneStatus::Released && status != MilestoneStatus::Refunded {
+                    refunded += escrow_account.settle(index as u8, MilestoneStatus::Refunded)?;
+                }
+            }
+            ctx.accounts
+                .treasury_account
+                .record_inflow(TreasuryInflow::EscrowRefund, refunded);
+            ctx.accounts
+                .treasury_account
+                .record_inflow(TreasuryInflow::Other, escrow.lamports() - refunded);
+            escrow_account.close(ctx.accounts.treasury_account.to_account_info())?;
+        }
+        msg!("Closing the print run of the nft, if any");
+        let print_run = ctx.accounts.print_run.to_account_info();
+        if !print_run.data_is_empty() {
+            ctx.accounts
+                .treasury_account
+                .record_inflow(TreasuryInflow::Other, print_run.lamports());
+            Account::<PrintRun>::try_from(&print_run)?
+                .close(ctx.accounts.treasury_account.to_account_info())?;
+        }
+        msg!("Closing the status log of the nft, if any");
+        let status_log = ctx.accounts.status_log.to_account_info();
+        if !status_log.data_is_empty() {
+            ctx.accounts
+                .treasury_account
+                .record_inflow(TreasuryInflow::Other, status_log.lamports());
+            Account::<StatusLog>::try_from(&status_log)?
+                .close(ctx.accounts.treasury_account.to_account_info())?;
+        }
+        msg!("Closing the name record of the nft, if any");
+        let name_record = ctx.accounts.name_record.to_account_info();
+        if !name_record.data_is_empty() {
+            let name_record_account = Account::<NameRecord>::try_from(&name_record)?;
+            if name_record_account.mint != ctx.accounts.mint.key() {
+                return err!(InvalidBurnError::InvalidNameRecord);
+            }
+            ctx.accounts
+                .treasury_account
+                .record_inflow(TreasuryInflow::Other, name_record.lamports());
+            name_record_account.close(ctx.accounts.treasury_account.to_account_info())?;
+        }
+        msg!("Closing the nft pda - its rent goes to the treasury");
+        let nft_pda_rent = ctx.accounts.nft_pda.to_account_info().lamports();
+        ctx.accounts
+            .treasury_account
+            .record_inflow(TreasuryInflow::Other, nft_pda_rent);
+        ctx.accounts.treasury_account.version = ACCOUNT_VERSION;
+
+        emit!(NftBurned {
+            mint: ctx.accounts.mint.key(),
+            reason,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
         msg!("Success!");
         Ok(())
     }
 
-    pub fn transfer_nft(ctx: Context<TransferNft>, _name: String) -> Result<()> {
+    pub fn transfer_nft<'info>(ctx: Context<'_, '_, '_, 'info, TransferNft<'info>>) -> Result<()> {
         msg!("starting transfer nft process");
 
+        msg!("Checking the purchase limits of the buyer");
+        ctx.accounts.purchase_counter.desc = String::from("purchase counter");
+        ctx.accounts.purchase_counter.version = ACCOUNT_VERSION;
+        ctx.accounts.purchase_counter.wallet = ctx.accounts.payer.key();
+        ctx.accounts
+            .purchase_counter
+            .record_purchase(&load_config(&ctx.accounts.config)?, Clock::get()?.slot)?;
+
+        check_presale_ended(
+            &ctx.accounts.sale_phase.to_account_info(),
+            Clock::get()?.unix_timestamp,
+        )?;
+        settle_reservation(
+            &ctx.accounts.reservation.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
         msg!("Getting seeds of nft pda (current owner of the nft)");
         let mint_key = ctx.accounts.mint.key().clone();
         let seeds = &[
-            "nftPDA".as_bytes(),
+            NFT_PDA_SEED,
             mint_key.as_ref(),
             &[*ctx.bumps.get("authority_account").unwrap()],
         ];
@@ -237,14 +429,12 @@ pub mod animal_kingdom {
         msg!(
             "Invoking anchor_spl::token::transfer for transferring sol to the conservation project"
         );
-        system_program::transfer(
-            CpiContext::new(
-                ctx.accounts.system_program.to_account_info(),
-                system_program::Transfer {
-                    from: ctx.accounts.payer.to_account_info(),
-                    to: ctx.accounts.project_account.to_account_info(),
-                },
-            ),
+        pay_project_share(
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.project_account.to_account_info(),
+            &mut ctx.accounts.treasury_account,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
             500_000_000,
         )?;
         msg!("Invoking anchor_spl::token::transfer for transferring sol to treasury");
@@ -259,21 +449,44 @@ pub mod animal_kingdom {
             500_000_000, // In lamports - should equal 0.5 sol
         )?;
 
-        msg!("Invoking anchor_spl::token::transfer for transferring the nft");
+        msg!("Creating the buyer's token account, if needed");
+        create_ata_idempotent(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.to_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+        )?;
 
-        transfer(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.from_account.to_account_info(),
-                    to: ctx.accounts.to_account.to_account_info(),
-                    authority: ctx.accounts.authority_account.to_account_info(),
-                },
-                &signer,
-            ),
-            1,
+        msg!("Invoking transfer_checked of the nft's token program for transferring the nft");
+        transfer_nft_interface(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.from_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.to_account.to_account_info(),
+            &ctx.accounts.authority_account.to_account_info(),
+            ctx.remaining_accounts,
+            &signer,
         )?;
 
+        msg!("Updating donor_pda and leaderboard");
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.lamports_given += NFT_PRICE;
+        ctx.accounts.donor_pda.nfts_bought += 1;
+        ctx.accounts.leaderboard.record(
+            ctx.accounts.payer.key(),
+            ctx.accounts.donor_pda.lamports_given,
+        );
+        msg!("Updated donor_pda and leaderboard");
+
+        ctx.accounts
+            .treasury_account
+            .record_inflow(TreasuryInflow::Sale, 500_000_000);
+        ctx.accounts.treasury_account.version = ACCOUNT_VERSION;
         msg!("Success!!");
 
         Ok(())
@@ -311,6 +524,7 @@ pub mod animal_kingdom {
         msg!("Successfully created new organisations struct");
         msg!("Updating newly created election_pda");
         ctx.accounts.election_pda.desc = String::from("election");
+        ctx.accounts.election_pda.version = ACCOUNT_VERSION;
         ctx.accounts.election_pda.date = date_of_election;
         ctx.accounts.election_pda.organisations = election_organisations;
         msg!("Successfully updated newly created election_pda");
@@ -319,7 +533,6 @@ pub mod animal_kingdom {
 
     pub fn create_vote(
         ctx: Context<CreateVote>,
-        _name_of_nft: String,
         _date_of_election: i64,
         mauritius_wildlife_protection_vote: u32,
         mauritius_nature_protection_society_vote: u32,
@@ -327,45 +540,47 @@ pub mod animal_kingdom {
     ) -> Result<()> {
         msg!("Starting create vote process");
 
-        msg!("Validating that the election is still active");
-        let current_timestamp = Clock::get()?.unix_timestamp;
-        if ctx.accounts.election_pda.date < current_timestamp {
-            return err!(InvalidVoteError::ElectionClosed);
-        };
-        msg!("Validated that the election is still active");
-
-        msg!("Validating that the amount of votes passed in is <= 100");
-        if mauritius_wildlife_protection_vote
-            + mauritius_nature_protection_society_vote
-            + mauritius_marine_life_protection_vote
-            > 100
-        {
-            return err!(InvalidVoteError::VoteTooLarge);
+        msg!("Validating that the voter holds the nft");
+        unpack_nft_mint(
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+        let ata_account = unpack_token_account(
+            &ctx.accounts.ata_account.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+        if ata_account.amount != 1 {
+            return err!(InvalidTokenInterfaceError::NftNotHeld);
         }
-        msg!("Validated the number of votes passed in");
+        msg!("Validated that the voter holds the nft");
 
         msg!("Creating Votes struct based on the votes passed in");
-        let vote = Votes {
-            mauritius_wildlife_protection: UserVote {
-                amount: mauritius_wildlife_protection_vote,
-                organisation_name: String::from("mauritius_wildlife_protection"),
-                organisation_address: ctx.accounts.mauritius_wildlife_protection.key(),
-            },
-            mauritius_nature_protection_society: UserVote {
-                amount: mauritius_nature_protection_society_vote,
-                organisation_name: String::from("mauritius_nature_protection_society"),
-                organisation_address: ctx.accounts.mauritius_nature_protection_society.key(),
-            },
-            mauritius_marine_life_protection: UserVote {
-                amount: mauritius_marine_life_protection_vote,
-                organisation_name: String::from("mauritius_marine_life_protection"),
-                organisation_address: ctx.accounts.mauritius_marine_life_protection.key(),
-            },
-        };
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        let vote = Votes::new(
+            (
+                mauritius_wildlife_protection_vote,
+                ctx.accounts.mauritius_wildlife_protection.key(),
+            ),
+            (
+                mauritius_nature_protection_society_vote,
+                ctx.accounts.mauritius_nature_protection_society.key(),
+            ),
+            (
+                mauritius_marine_life_protection_vote,
+                ctx.accounts.mauritius_marine_life_protection.key(),
+            ),
+        )?;
         msg!("Created Votes struct based on the votes passed in");
 
+        msg!("Updating Election PDA with new values for the votes for each organisation");
+        ctx.accounts
+            .election_pda
+            .add_votes(&vote, current_timestamp)?;
+        msg!("Updated Election PDA with new values for the votes for each organisation");
+
         msg!("Updating newly created Vote PDA with the appropriate values and votes");
         ctx.accounts.vote_pda.desc = String::from("vote");
+        ctx.accounts.vote_pda.version = ACCOUNT_VERSION;
         ctx.accounts.vote_pda.mint = ctx.accounts.mint.key();
         ctx.accounts.vote_pda.voter = ctx.accounts.payer.key();
         ctx.accounts.vote_pda.ata = ctx.accounts.ata_account.key();
@@ -373,469 +588,6315 @@ pub mod animal_kingdom {
         ctx.accounts.vote_pda.vote = vote;
         msg!("Updated newly created Vote PDA with the appropriate values and votes");
 
-        msg!("Updating Election PDA with new values for the votes for each organisation");
-        ctx.accounts
-            .election_pda
-            .organisations
-            .mauritius_wildlife_protection
-            .votes += mauritius_wildlife_protection_vote as u64;
-        ctx.accounts
-            .election_pda
-            .organisations
-            .mauritius_nature_protection_society
-            .votes += mauritius_nature_protection_society_vote as u64;
-        ctx.accounts
-            .election_pda
-            .organisations
-            .mauritius_marine_life_protection
-            .votes += mauritius_marine_life_protection_vote as u64;
-        msg!("Updated Election PDA with new values for the votes for each organisation");
+        msg!("Updating donor_pda");
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.votes_cast += 1;
+        msg!("Updated donor_pda");
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct InitializeMintAuthority<'info> {
-    #[account(
-        init,
-        seeds = [b"mint".as_ref()],
-        bump,
-        payer = payer,
-        space = 100
-    )]
-    pub mint_authority: Account<'info, MintAuthority>,
-    pub system_program: Program<'info, System>,
-    pub token_program: Program<'info, Token>,
-    #[account(mut)]
-    pub payer: Signer<'info>,
-}
+    pub fn set_vote_receipt(
+        ctx: Context<SetVoteReceipt>,
+        _date_of_election: i64,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        msg!("Starting set vote receipt process");
 
-#[derive(Accounts)]
-#[instruction(name: String)]
-pub struct CreateMint<'info> {
-    #[account(
-        init,
-        seeds = [&name.as_bytes().as_ref(), current_program.key.as_ref()],
-        bump,
-        mint::decimals = 0,
-        mint::authority = mint_authority,
-        mint::freeze_authority = mint_authority,
-        payer = payer
-    )]
-    pub mint: Account<'info, Mint>,
-    #[account(
-        seeds = [b"mint".as_ref()],
-        bump,
-    )]
-    pub mint_authority: Account<'info, MintAuthority>,
-    pub token_program: Program<'info, Token>,
-    #[account(
-        init,
-        payer = payer,
-        space = 200,
-        seeds = [b"nftPDA".as_ref(), mint.key().as_ref()],
-        bump
-    )]
-    pub nft_pda: Account<'info, NftPDA>,
-    #[account(
-        init,
-        payer = payer,
-        associated_token::mint = mint, // mint of the token
-        associated_token::authority = nft_pda, // authority that should be a PDA account
-    )]
-    pub token_account: Account<'info, TokenAccount>,
-    pub token_metadata_program: Program<'info, TokenMetaData>,
-    // Security - I need to verify that the address requesting to create an NFT is authorised to request this, otherwise anyone can use this program to create fake NFTs
-    #[account(mut, constraint = payer.key.to_string() == String::from("kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"))]
-    pub payer: Signer<'info>, // Why do I have a payer and a signer - are they not the same thing?
-    pub system_program: Program<'info, System>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
-    pub current_program: UncheckedAccount<'info>,
-    /// CHECK: This is dangerous - I need to think about how to make this safe
-    #[account(mut)]
-    pub organisation_wallet: UncheckedAccount<'info>,
-}
+        msg!("Validating the receipt metadata passed in");
+        if name.len() > 32 || symbol.len() > 10 || uri.len() > 200 {
+            return err!(InvalidVoteReceiptError::MetadataTooLong);
+        }
+        msg!("Validated the receipt metadata passed in");
 
-#[derive(Accounts)]
-#[instruction(name: String)]
-pub struct CreateMetadata<'info> {
-    #[account(
-        seeds = [&name.as_bytes().as_ref(), current_program.key.as_ref()],
-        bump,
-        mint::decimals = 0,
-        mint::authority = mint_authority,
-        mint::freeze_authority = mint_authority
-    )]
-    pub mint: Account<'info, Mint>,
-    #[account(
-        seeds = [b"mint".as_ref()],
-        bump,
-    )]
-    pub mint_authority: Account<'info, MintAuthority>,
-    pub token_program: Program<'info, Token>,
-    /// CHECK: Using "address" constraint to validate metadata account address
-    #[account(
-        mut,
-        address=find_metadata_account(&mint.key()).0
-    )]
-    pub metadata: UncheckedAccount<'info>,
-    pub token_metadata_program: Program<'info, TokenMetaData>,
-    // Security - I need to verify that the address requesting to create an NFT is authorised to request this, otherwise anyone can use this program to create fake NFTs
-    #[account(mut, constraint = payer.key.to_string() == String::from("kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"))]
-    pub payer: Signer<'info>, // Why do I have a payer and a signer - are they not the same thing?
-    pub system_program: Program<'info, System>,
-    /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
-    pub current_program: UncheckedAccount<'info>,
-}
+        msg!("Validating that no receipts have been claimed yet");
+        if ctx.accounts.receipt_config.claimed > 0 {
+            return err!(InvalidVoteReceiptError::ReceiptsClaimed);
+        }
 
-#[derive(Accounts)]
-#[instruction(name: String)]
-pub struct CreateMasterEdition<'info> {
-    #[account(
-        seeds = [&name.as_bytes().as_ref(), current_program.key.as_ref()],
-        bump,
-        mint::decimals = 0,
-        mint::authority = mint_authority,
-        mint::freeze_authority = mint_authority,
-        mut
-    )]
-    pub mint: Account<'info, Mint>,
-    #[account(
-        seeds = [b"mint".as_ref()],
-        bump,
-    )]
-    pub mint_authority: Account<'info, MintAuthority>,
-    pub token_program: Program<'info, Token>,
-    /// CHECK: Using "address" constraint to validate metadata account address
-    #[account(
-        mut,
-        address=find_metadata_account(&mint.key()).0
-    )]
-    pub metadata: UncheckedAccount<'info>,
-    pub token_metadata_program: Program<'info, TokenMetaData>,
-    // Security - I need to verify that the address requesting to create an NFT is authorised to request this, otherwise anyone can use this program to create fake NFTs
-    #[account(mut, constraint = payer.key.to_string() == String::from("kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"))]
-    pub payer: Signer<'info>, // Why do I have a payer and a signer - are they not the same thing?
-    pub system_program: Program<'info, System>,
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    #[account(mut)]
-    pub master_edition: UncheckedAccount<'info>,
-    /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
-    pub current_program: UncheckedAccount<'info>,
-}
+        msg!("Updating receipt_config");
+        ctx.accounts.receipt_config.desc = String::from("vote receipt");
+        ctx.accounts.receipt_config.version = ACCOUNT_VERSION;
+        ctx.accounts.receipt_config.election = ctx.accounts.election_pda.key();
+        ctx.accounts.receipt_config.name = name;
+        ctx.accounts.receipt_config.symbol = symbol;
+        ctx.accounts.receipt_config.uri = uri;
+        msg!("Successfully updated receipt_config");
+        Ok(())
+    }
 
-#[derive(Accounts)]
-#[instruction(name: String)]
-pub struct DeleteNft<'info> {
-    // `[writable]` NFT metadata
-    /// CHECK: Using "address" constraint to validate metadata account address
-    #[account(
-        mut,
-        address=find_metadata_account(&mint.key()).0
-    )]
-    pub metadata: UncheckedAccount<'info>,
+    pub fn claim_vote_receipt(
+        ctx: Context<ClaimVoteReceipt>,
+        _date_of_election: i64,
+    ) -> Result<()> {
+        msg!("Starting claim vote receipt process");
 
-    // `[writable, signer]` Owner of NFT
-    #[account(
-        mut,
-        seeds = [b"nftPDA".as_ref(), mint.key().as_ref()],
-        bump
-    )]
-    pub nft_pda: Account<'info, NftPDA>,
+        msg!("Getting seeds of mint authority pda");
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            &[*ctx.bumps.get("mint_authority").unwrap()],
+        ];
+        let signer = [&seeds[..]];
+        msg!("Got seeds of mint authority pda");
 
-    // `[writable]` Mint of NFT
+        msg!("Minting receipt");
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                    to: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.receipt_mint.to_account_info(),
+                },
+                &signer,
+            ),
+            1,
+        )?;
+        msg!("Receipt Minted !!!");
+
+        msg!("Creating receipt metadata account");
+        let account_info = vec![
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.receipt_mint.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ];
+        // The election is listed as an unverified creator so the receipt links to it on chain
+        let creator = vec![
+            mpl_token_metadata::state::Creator {
+                address: ctx.accounts.mint_authority.key(),
+                verified: true,
+                share: 100,
+            },
+            mpl_token_metadata::state::Creator {
+                address: ctx.accounts.election_pda.key(),
+                verified: false,
+                share: 0,
+            },
+        ];
+        invoke_signed(
+            &create_metadata_accounts_v3(
+                ctx.accounts.token_metadata_program.key(), // token metadata program
+                ctx.accounts.metadata.key(),               // metadata account PDA for mint
+                ctx.accounts.receipt_mint.key(),           // mint account
+                ctx.accounts.mint_authority.key(),         // mint authority
+                ctx.accounts.payer.key(),                  // payer for transaction
+                ctx.accounts.mint_authority.key(),         // update authority
+                ctx.accounts.receipt_config.name.clone(),  // name
+                ctx.accounts.receipt_config.symbol.clone(), // symbol
+                ctx.accounts.receipt_config.uri.clone(),   // uri (offchain metadata)
+                Some(creator),                             // (optional) creators
+                0,                                         // seller fee basis points
+                true,                                      // (bool) update authority is signer
+                true,                                      // (bool) is mutable
+                None,                                      // (optional) collection
+                None,                                      // (optional) uses
+                None,                                      // (optional) collection details
+            ),
+            account_info.as_slice(),
+            &signer,
+        )?;
+        msg!("Receipt Metadata Account Created !!!");
+
+        msg!("Creating receipt master edition account");
+        let master_edition_infos = vec![
+            ctx.accounts.master_edition.to_account_info(),
+            ctx.accounts.receipt_mint.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ];
+        invoke_signed(
+            &create_master_edition_v3(
+                ctx.accounts.token_metadata_program.key(), // token metadata program
+                ctx.accounts.master_edition.key(),         // id of master edition account
+                ctx.accounts.receipt_mint.key(),           // mint id
+                ctx.accounts.mint_authority.key(),         // update authority
+                ctx.accounts.mint_authority.key(),         // mint authority
+                ctx.accounts.metadata.key(),               // metadata account id
+                ctx.accounts.payer.key(),                  // payer id
+                Some(0),                                   // max supply - receipts can't be printed
+            ),
+            master_edition_infos.as_slice(),
+            &signer,
+        )?;
+        msg!("Receipt Master Edition Created !!!");
+
+        ctx.accounts.receipt_config.claimed += 1;
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn create_auction(
+        ctx: Context<CreateAuction>,
+        start_price: u64,
+        floor_price: u64,
+        start_date: i64,
+        duration: i64,
+    ) -> Result<()> {
+        msg!("Starting create auction process");
+
+        msg!("Validating the auction parameters passed in");
+        if floor_price > start_price {
+            return err!(InvalidAuctionError::InvalidPrice);
+        }
+        if duration <= 0 {
+            return err!(InvalidAuctionError::InvalidDuration);
+        }
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if start_date < current_timestamp {
+            return err!(InvalidAuctionError::InvalidDate);
+        }
+        msg!("Validated the auction parameters passed in");
+
+        msg!("Validating that the nft isn't reserved");
+        let reservation = &ctx.accounts.reservation;
+        if !reservation.data_is_empty()
+            && Account::<Reservation>::try_from(&reservation.to_account_info())?.expires_at
+                > current_timestamp
+        {
+            return err!(InvalidReservationError::NftReserved);
+        }
+
+        msg!("Updating newly created auction_pda");
+        ctx.accounts.auction_pda.desc = String::from("auction");
+        ctx.accounts.auction_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.auction_pda.mint = ctx.accounts.mint.key();
+        ctx.accounts.auction_pda.start_price = start_price;
+        ctx.accounts.auction_pda.floor_price = floor_price;
+        ctx.accounts.auction_pda.start_date = start_date;
+        ctx.accounts.auction_pda.duration = duration;
+        ctx.accounts.nft_pda.on_auction = true;
+        msg!("Successfully updated newly created auction_pda");
+        Ok(())
+    }
+
+    pub fn buy_auction_nft(ctx: Context<BuyAuctionNft>) -> Result<()> {
+        msg!("starting buy auction nft process");
+
+        msg!("Checking the purchase limits of the buyer");
+        ctx.accounts.purchase_counter.desc = String::from("purchase counter");
+        ctx.accounts.purchase_counter.version = ACCOUNT_VERSION;
+        ctx.accounts.purchase_counter.wallet = ctx.accounts.payer.key();
+        ctx.accounts
+            .purchase_counter
+            .record_purchase(&load_config(&ctx.accounts.config)?, Clock::get()?.slot)?;
+
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        check_presale_ended(
+            &ctx.accounts.sale_phase.to_account_info(),
+            current_timestamp,
+        )?;
+        settle_reservation(
+            &ctx.accounts.reservation.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            current_timestamp,
+        )?;
+
+        msg!("Calculating the current price of the auction");
+        if current_timestamp < ctx.accounts.auction_pda.start_date {
+            return err!(InvalidAuctionError::AuctionNotStarted);
+        }
+        let price = ctx.accounts.auction_pda.current_price(current_timestamp);
+        let (project_share, treasury_share) = split_proceeds(price);
+        msg!("Current price is {} lamports", price);
+
+        msg!("Getting seeds of nft pda (current owner of the nft)");
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            NFT_PDA_SEED,
+            mint_key.as_ref(),
+            &[*ctx.bumps.get("authority_account").unwrap()],
+        ];
+        let signer = [&seeds[..]];
+        msg!("Got seeds of nft pda");
+
+        msg!("Invoking system_program::transfer for transferring sol to the conservation project");
+        pay_project_share(
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.project_account.to_account_info(),
+            &mut ctx.accounts.treasury_account,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            project_share,
+        )?;
+        msg!("Invoking system_program::transfer for transferring sol to treasury");
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.treasury_account.to_account_info(),
+                },
+            ),
+            treasury_share,
+        )?;
+
+        msg!("Invoking anchor_spl::token::transfer for transferring the nft");
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.from_account.to_account_info(),
+                    to: ctx.accounts.to_account.to_account_info(),
+                    authority: ctx.accounts.authority_account.to_account_info(),
+                },
+                &signer,
+            ),
+            1,
+        )?;
+
+        msg!("Recording the sale on the auction_pda");
+        ctx.accounts.auction_pda.buyer = ctx.accounts.payer.key();
+        ctx.accounts.auction_pda.sale_price = price;
+        ctx.accounts.authority_account.on_auction = false;
+
+        msg!("Updating donor_pda and leaderboard");
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.lamports_given += price;
+        ctx.accounts.donor_pda.nfts_bought += 1;
+        ctx.accounts.leaderboard.record(
+            ctx.accounts.payer.key(),
+            ctx.accounts.donor_pda.lamports_given,
+        );
+        msg!("Updated donor_pda and leaderboard");
+
+        ctx.accounts
+            .treasury_account
+            .record_inflow(TreasuryInflow::Auction, treasury_share);
+        ctx.accounts.treasury_account.version = ACCOUNT_VERSION;
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn set_payment_mint(ctx: Context<SetPaymentMint>, price: u64, enabled: bool) -> Result<()> {
+        msg!("Starting set payment mint process");
+
+        if price == 0 {
+            return err!(InvalidPaymentError::InvalidPrice);
+        }
+
+        msg!("Updating payment_mint_pda");
+        ctx.accounts.payment_mint_pda.desc = String::from("payment mint");
+        ctx.accounts.payment_mint_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.payment_mint_pda.mint = ctx.accounts.payment_mint.key();
+        ctx.accounts.payment_mint_pda.vault = ctx.accounts.treasury_vault.key();
+        ctx.accounts.payment_mint_pda.price = price;
+        ctx.accounts.payment_mint_pda.enabled = enabled;
+        ctx.accounts.treasury_account.version = ACCOUNT_VERSION;
+        msg!("Successfully updated payment_mint_pda");
+        Ok(())
+    }
+
+    pub fn transfer_nft_with_token(ctx: Context<TransferNftWithToken>) -> Result<()> {
+        msg!("starting transfer nft with token process");
+
+        if !ctx.accounts.escrow.data_is_empty() {
+            return err!(InvalidEscrowError::EscrowRequiresSol);
+        }
+
+        msg!("Checking the purchase limits of the buyer");
+        ctx.accounts.purchase_counter.desc = String::from("purchase counter");
+        ctx.accounts.purchase_counter.version = ACCOUNT_VERSION;
+        ctx.accounts.purchase_counter.wallet = ctx.accounts.payer.key();
+        ctx.accounts
+            .purchase_counter
+            .record_purchase(&load_config(&ctx.accounts.config)?, Clock::get()?.slot)?;
+
+        check_presale_ended(
+            &ctx.accounts.sale_phase.to_account_info(),
+            Clock::get()?.unix_timestamp,
+        )?;
+        settle_reservation(
+            &ctx.accounts.reservation.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let price = ctx.accounts.payment_mint_pda.price;
+        let (project_share, treasury_share) = split_proceeds(price);
+
+        msg!("Getting seeds of nft pda (current owner of the nft)");
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            NFT_PDA_SEED,
+            mint_key.as_ref(),
+            &[*ctx.bumps.get("authority_account").unwrap()],
+        ];
+        let signer = [&seeds[..]];
+        msg!("Got seeds of nft pda");
+
+        msg!("Invoking anchor_spl::token::transfer for transferring tokens to the conservation project");
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer_token_account.to_account_info(),
+                    to: ctx.accounts.project_token_account.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            project_share,
+        )?;
+        msg!("Invoking anchor_spl::token::transfer for transferring tokens to the treasury vault");
+        transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer_token_account.to_account_info(),
+                    to: ctx.accounts.treasury_vault.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            treasury_share,
+        )?;
+
+        msg!("Invoking anchor_spl::token::transfer for transferring the nft");
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.from_account.to_account_info(),
+                    to: ctx.accounts.to_account.to_account_info(),
+                    authority: ctx.accounts.authority_account.to_account_info(),
+                },
+                &signer,
+            ),
+            1,
+        )?;
+
+        msg!("Updating donor_pda and leaderboard");
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.lamports_given += ctx.accounts.payment_mint_pda.lamport_value(price);
+        ctx.accounts.donor_pda.nfts_bought += 1;
+        ctx.accounts.leaderboard.record(
+            ctx.accounts.payer.key(),
+            ctx.accounts.donor_pda.lamports_given,
+        );
+        msg!("Updated donor_pda and leaderboard");
+
+        ctx.accounts.payment_mint_pda.sales += treasury_share;
+
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn register_project(ctx: Context<RegisterProject>, name: String) -> Result<()> {
+        msg!("Starting register project process");
+
+        if name.len() > 50 {
+            return err!(InvalidProjectError::NameTooLong);
+        }
+
+        msg!("Updating project_pda");
+        ctx.accounts.project_pda.desc = String::from("project");
+        ctx.accounts.project_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.project_pda.name = name;
+        ctx.accounts.project_pda.wallet = ctx.accounts.project_account.key();
+        ctx.accounts.project_pda.active = true;
+        msg!("Successfully updated project_pda");
+        Ok(())
+    }
+
+    pub fn donate(
+        ctx: Context<Donate>,
+        project_amount: u64,
+        treasury_amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        msg!("Starting donate process");
+
+        msg!("Validating the donation passed in");
+        if project_amount == 0 && treasury_amount == 0 {
+            return err!(InvalidDonationError::EmptyDonation);
+        }
+        validate_memo(&memo)?;
+        msg!("Validated the donation passed in");
+
+        if project_amount > 0 {
+            check_project(&ctx.accounts.project_pda)?;
+            msg!("Invoking system_program::transfer for transferring sol to the conservation project");
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: ctx.accounts.project_account.to_account_info(),
+                    },
+                ),
+                project_amount,
+            )?;
+        }
+        if treasury_amount > 0 {
+            msg!("Invoking system_program::transfer for transferring sol to treasury");
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: ctx.accounts.treasury_account.to_account_info(),
+                    },
+                ),
+                treasury_amount,
+            )?;
+        }
+        if let Some(memo) = memo {
+            msg!("Memo: {}", memo);
+        }
+
+        msg!("Updating donor_pda and leaderboard");
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.lamports_given += project_amount + treasury_amount;
+        ctx.accounts.donor_pda.donations += 1;
+        ctx.accounts.leaderboard.record(
+            ctx.accounts.payer.key(),
+            ctx.accounts.donor_pda.lamports_given,
+        );
+        ctx.accounts
+            .treasury_account
+            .record_inflow(TreasuryInflow::Donation, treasury_amount);
+        ctx.accounts.treasury_account.version = ACCOUNT_VERSION;
+        msg!("Updated donor_pda and leaderboard");
+        Ok(())
+    }
+
+    pub fn donate_token(
+        ctx: Context<DonateToken>,
+        project_amount: u64,
+        treasury_amount: u64,
+        memo: Option<String>,
+    ) -> Result<()> {
+        msg!("Starting donate token process");
+
+        msg!("Validating the donation passed in");
+        if project_amount == 0 && treasury_amount == 0 {
+            return err!(InvalidDonationError::EmptyDonation);
+        }
+        validate_memo(&memo)?;
+        msg!("Validated the donation passed in");
+
+        if project_amount > 0 {
+            check_project(&ctx.accounts.project_pda)?;
+            msg!("Creating the conservation project's token account, if needed");
+            create_ata_idempotent(
+                &ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.project_account.to_account_info(),
+                &ctx.accounts.project_token_account.to_account_info(),
+                &ctx.accounts.payment_mint.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                &ctx.accounts.token_program.to_account_info(),
+                &ctx.accounts.associated_token_program.to_account_info(),
+            )?;
+            msg!("Invoking anchor_spl::token::transfer for transferring tokens to the conservation project");
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer_token_account.to_account_info(),
+                        to: ctx.accounts.project_token_account.to_account_info(),
+                        authority: ctx.accounts.payer.to_account_info(),
+                    },
+                ),
+                project_amount,
+            )?;
+        }
+        if treasury_amount > 0 {
+            msg!("Invoking anchor_spl::token::transfer for transferring tokens to the treasury vault");
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer_token_account.to_account_info(),
+                        to: ctx.accounts.treasury_vault.to_account_info(),
+                        authority: ctx.accounts.payer.to_account_info(),
+                    },
+                ),
+                treasury_amount,
+            )?;
+        }
+        if let Some(memo) = memo {
+            msg!("Memo: {}", memo);
+        }
+
+        let amount = project_amount + treasury_amount;
+        msg!("Updating token_donor_pda");
+        ctx.accounts.token_donor_pda.desc = String::from("token donor");
+        ctx.accounts.token_donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.token_donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.token_donor_pda.mint = ctx.accounts.payment_mint.key();
+        ctx.accounts.token_donor_pda.amount_given += amount;
+        ctx.accounts.token_donor_pda.donations += 1;
+        msg!("Updated token_donor_pda");
+
+        msg!("Updating donor_pda and leaderboard");
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.lamports_given +=
+            ctx.accounts.payment_mint_pda.lamport_value(amount);
+        ctx.accounts.donor_pda.token_donations += 1;
+        ctx.accounts.donor_pda.donations += 1;
+        ctx.accounts.leaderboard.record(
+            ctx.accounts.payer.key(),
+            ctx.accounts.donor_pda.lamports_given,
+        );
+        ctx.accounts.payment_mint_pda.donations += treasury_amount;
+        msg!("Updated donor_pda and leaderboard");
+        Ok(())
+    }
+
+    pub fn set_badge_tier(
+        ctx: Context<SetBadgeTier>,
+        tier: u8,
+        threshold: u64,
+        name: String,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        msg!("Starting set badge tier process");
+
+        msg!("Validating the badge tier passed in");
+        if name.len() > 32 || symbol.len() > 10 || uri.len() > 200 {
+            return err!(InvalidBadgeError::MetadataTooLong);
+        }
+        if threshold == 0 {
+            return err!(InvalidBadgeError::InvalidThreshold);
+        }
+        msg!("Validated the badge tier passed in");
+
+        msg!("Updating badge_tier_pda");
+        ctx.accounts.badge_tier_pda.desc = String::from("badge tier");
+        ctx.accounts.badge_tier_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.badge_tier_pda.tier = tier;
+        ctx.accounts.badge_tier_pda.threshold = threshold;
+        ctx.accounts.badge_tier_pda.name = name;
+        ctx.accounts.badge_tier_pda.symbol = symbol;
+        ctx.accounts.badge_tier_pda.uri = uri;
+        msg!("Successfully updated badge_tier_pda");
+        Ok(())
+    }
+
+    pub fn claim_badge(ctx: Context<ClaimBadge>, _tier: u8) -> Result<()> {
+        msg!("Starting claim badge process");
+
+        msg!("Getting seeds of mint authority pda");
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            &[*ctx.bumps.get("mint_authority").unwrap()],
+        ];
+        let signer = [&seeds[..]];
+        msg!("Got seeds of mint authority pda");
+
+        msg!("Minting badge");
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                    to: ctx.accounts.token_account.to_account_info(),
+                    mint: ctx.accounts.badge_mint.to_account_info(),
+                },
+                &signer,
+            ),
+            1,
+        )?;
+        msg!("Badge Minted !!!");
+
+        msg!("Creating badge metadata account");
+        let account_info = vec![
+            ctx.accounts.metadata.to_account_info(),
+            ctx.accounts.badge_mint.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ];
+        let creator = vec![mpl_token_metadata::state::Creator {
+            address: ctx.accounts.mint_authority.key(),
+            verified: true,
+            share: 100,
+        }];
+        invoke_signed(
+            &create_metadata_accounts_v3(
+                ctx.accounts.token_metadata_program.key(), // token metadata program
+                ctx.accounts.metadata.key(),               // metadata account PDA for mint
+                ctx.accounts.badge_mint.key(),             // mint account
+                ctx.accounts.mint_authority.key(),         // mint authority
+                ctx.accounts.payer.key(),                  // payer for transaction
+                ctx.accounts.mint_authority.key(),         // update authority
+                ctx.accounts.badge_tier_pda.name.clone(),  // name
+                ctx.accounts.badge_tier_pda.symbol.clone(), // symbol
+                ctx.accounts.badge_tier_pda.uri.clone(),   // uri (offchain metadata)
+                Some(creator),                             // (optional) creators
+                0,                                         // seller fee basis points
+                true,                                      // (bool) update authority is signer
+                true,                                      // (bool) is mutable
+                None,                                      // (optional) collection
+                None,                                      // (optional) uses
+                None,                                      // (optional) collection details
+            ),
+            account_info.as_slice(),
+            &signer,
+        )?;
+        msg!("Badge Metadata Account Created !!!");
+
+        msg!("Freezing the holder's token account so the badge can't be transferred");
+        freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.token_account.to_account_info(),
+                mint: ctx.accounts.badge_mint.to_account_info(),
+                authority: ctx.accounts.mint_authority.to_account_info(),
+            },
+            &signer,
+        ))?;
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn set_sale_phase(
+        ctx: Context<SetSalePhase>,
+        presale_start: i64,
+        presale_end: i64,
+        merkle_root: [u8; 32],
+        max_presale_purchases: u32,
+    ) -> Result<()> {
+        msg!("Starting set sale phase process");
+
+        if presale_end < presale_start {
+            return err!(InvalidPresaleError::InvalidWindow);
+        }
+
+        msg!("Updating sale_phase");
+        ctx.accounts.sale_phase.desc = String::from("sale phase");
+        ctx.accounts.sale_phase.version = ACCOUNT_VERSION;
+        ctx.accounts.sale_phase.presale_start = presale_start;
+        ctx.accounts.sale_phase.presale_end = presale_end;
+        ctx.accounts.sale_phase.merkle_root = merkle_root;
+        ctx.accounts.sale_phase.max_presale_purchases = max_presale_purchases;
+        msg!("Successfully updated sale_phase");
+        Ok(())
+    }
+
+    pub fn presale_transfer_nft(
+        ctx: Context<PresaleTransferNft>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        msg!("starting presale transfer nft process");
+
+        msg!("Checking the purchase limits of the buyer");
+        ctx.accounts.purchase_counter.desc = String::from("purchase counter");
+        ctx.accounts.purchase_counter.version = ACCOUNT_VERSION;
+        ctx.accounts.purchase_counter.wallet = ctx.accounts.payer.key();
+        ctx.accounts
+            .purchase_counter
+            .record_purchase(&load_config(&ctx.accounts.config)?, Clock::get()?.slot)?;
+
+        msg!("Validating that the presale is running and the payer is allowlisted");
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if !ctx.accounts.sale_phase.is_presale(current_timestamp) {
+            return err!(InvalidPresaleError::PresaleNotActive);
+        }
+        let leaf = keccak::hash(ctx.accounts.payer.key().as_ref()).0;
+        if !verify_merkle_proof(&proof, ctx.accounts.sale_phase.merkle_root, leaf) {
+            return err!(InvalidPresaleError::InvalidProof);
+        }
+        ctx.accounts.presale_purchases.presale_start = ctx.accounts.sale_phase.presale_start;
+        if ctx.accounts.presale_purchases.purchases >= ctx.accounts.sale_phase.max_presale_purchases
+        {
+            return err!(InvalidPresaleError::PresaleLimitReached);
+        }
+        msg!("Validated that the presale is running and the payer is allowlisted");
+
+        settle_reservation(
+            &ctx.accounts.reservation.to_account_info(),
+            &ctx.accounts.payer.to_account_info(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        msg!("Getting seeds of nft pda (current owner of the nft)");
+        let mint_key = ctx.accounts.mint.key();
+        let seeds = &[
+            NFT_PDA_SEED,
+            mint_key.as_ref(),
+            &[*ctx.bumps.get("authority_account").unwrap()],
+        ];
+        let signer = [&seeds[..]];
+        msg!("Got seeds of nft pda");
+
+        let (project_share, treasury_share) = split_proceeds(NFT_PRICE);
+        msg!("Invoking system_program::transfer for transferring sol to the conservation project");
+        pay_project_share(
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.project_account.to_account_info(),
+            &mut ctx.accounts.treasury_account,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            project_share,
+        )?;
+        msg!("Invoking system_program::transfer for transferring sol to treasury");
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.treasury_account.to_account_info(),
+                },
+            ),
+            treasury_share,
+        )?;
+
+        msg!("Invoking anchor_spl::token::transfer for transferring the nft");
+        transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.from_account.to_account_info(),
+                    to: ctx.accounts.to_account.to_account_info(),
+                    authority: ctx.accounts.authority_account.to_account_info(),
+                },
+                &signer,
+            ),
+            1,
+        )?;
+
+        msg!("Updating presale_purchases, donor_pda and leaderboard");
+        ctx.accounts.presale_purchases.desc = String::from("presale purchases");
+        ctx.accounts.presale_purchases.version = ACCOUNT_VERSION;
+        ctx.accounts.presale_purchases.wallet = ctx.accounts.payer.key();
+        ctx.accounts.presale_purchases.purchases += 1;
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.lamports_given += NFT_PRICE;
+        ctx.accounts.donor_pda.nfts_bought += 1;
+        ctx.accounts.leaderboard.record(
+            ctx.accounts.payer.key(),
+            ctx.accounts.donor_pda.lamports_given,
+        );
+        msg!("Updated presale_purchases, donor_pda and leaderboard");
+
+        ctx.accounts
+            .treasury_account
+            .record_inflow(TreasuryInflow::Presale, treasury_share);
+        ctx.accounts.treasury_account.version = ACCOUNT_VERSION;
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn set_purchase_limits(
+        ctx: Context<SetPurchaseLimits>,
+        max_nfts_per_wallet: u32,
+        max_purchases_per_window: u32,
+        slot_window: u64,
+    ) -> Result<()> {
+        msg!("Starting set purchase limits process");
+
+        if max_purchases_per_window > 0 && slot_window == 0 {
+            return err!(InvalidPurchaseError::InvalidSlotWindow);
+        }
+
+        msg!("Updating config");
+        ctx.accounts.config.desc = String::from("config");
+        ctx.accounts.config.version = ACCOUNT_VERSION;
+        ctx.accounts.config.max_nfts_per_wallet = max_nfts_per_wallet;
+        ctx.accounts.config.max_purchases_per_window = max_purchases_per_window;
+        ctx.accounts.config.slot_window = slot_window;
+        msg!("Successfully updated config");
+        Ok(())
+    }
+
+    pub fn set_reservation_terms(
+        ctx: Context<SetReservationTerms>,
+        reservation_seconds: i64,
+        reservation_deposit: u64,
+    ) -> Result<()> {
+        msg!("Starting set reservation terms process");
+
+        if reservation_seconds <= 0 {
+            return err!(InvalidReservationError::InvalidDuration);
+        }
+        if reservation_deposit == 0 {
+            return err!(InvalidReservationError::InvalidDeposit);
+        }
+
+        msg!("Updating config");
+        ctx.accounts.config.desc = String::from("config");
+        ctx.accounts.config.version = ACCOUNT_VERSION;
+        ctx.accounts.config.reservation_seconds = reservation_seconds;
+        ctx.accounts.config.reservation_deposit = reservation_deposit;
+        msg!("Successfully updated config");
+        Ok(())
+    }
+
+    pub fn reserve_nft(ctx: Context<ReserveNft>) -> Result<()> {
+        msg!("Starting reserve nft process");
+
+        let config = load_config(&ctx.accounts.config)?;
+        if config.reservation_seconds <= 0 {
+            return err!(InvalidReservationError::ReservationsDisabled);
+        }
+
+        msg!("Validating that the buyer's cooldown on this nft is over");
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if ctx.accounts.cooldown.reservable_at > current_timestamp {
+            return err!(InvalidReservationError::CooldownActive);
+        }
+
+        let deposit = config.reservation_deposit;
+        msg!("Invoking system_program::transfer for transferring the deposit to the reservation");
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.reservation.to_account_info(),
+                },
+            ),
+            deposit,
+        )?;
+
+        msg!("Updating newly created reservation");
+        ctx.accounts.reservation.desc = String::from("reservation");
+        ctx.accounts.reservation.version = ACCOUNT_VERSION;
+        ctx.accounts.reservation.mint = ctx.accounts.mint.key();
+        ctx.accounts.reservation.buyer = ctx.accounts.payer.key();
+        ctx.accounts.reservation.expires_at = current_timestamp + config.reservation_seconds;
+        ctx.accounts.reservation.deposit = deposit;
+        msg!("Successfully updated newly created reservation");
+
+        msg!("Updating cooldown");
+        ctx.accounts.cooldown.desc = String::from("reservation cooldown");
+        ctx.accounts.cooldown.version = ACCOUNT_VERSION;
+        ctx.accounts.cooldown.mint = ctx.accounts.mint.key();
+        ctx.accounts.cooldown.buyer = ctx.accounts.payer.key();
+        ctx.accounts.cooldown.reservable_at =
+            ctx.accounts.reservation.expires_at + config.reservation_seconds;
+        msg!("Successfully updated cooldown");
+        Ok(())
+    }
+
+    pub fn release_reservation(ctx: Context<ReleaseReservation>) -> Result<()> {
+        msg!("Starting release reservation process");
+
+        if ctx.accounts.payer.key() != ctx.accounts.buyer.key() {
+            msg!("Validating that the reservation has expired");
+            let current_timestamp = Clock::get()?.unix_timestamp;
+            if ctx.accounts.reservation.expires_at > current_timestamp {
+                return err!(InvalidReservationError::NftReserved);
+            }
+        }
+
+        msg!("Closing the reservation and refunding the deposit to the buyer");
+        Ok(())
+    }
+
+    pub fn finalize_election(ctx: Context<FinalizeElection>, _date_of_election: i64) -> Result<()> {
+        msg!("Starting finalize election process");
+
+        msg!("Validating that the election is over");
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if ctx.accounts.election_pda.date >= current_timestamp {
+            return err!(InvalidElectionError::ElectionNotOver);
+        }
+        if ctx.accounts.election_pda.finalized {
+            return err!(InvalidElectionError::AlreadyFinalized);
+        }
+        msg!("Validated that the election is over");
+
+        msg!("Marking the election as finalized");
+        ctx.accounts.election_pda.finalized = true;
+        ctx.accounts.election_pda.finalized_at = current_timestamp;
+
+        msg!("Archiving the results of the election");
+        let organisations = &ctx.accounts.election_pda.organisations;
+        let results = vec![
+            ArchivedResult {
+                address: organisations.mauritius_wildlife_protection.address,
+                votes: organisations.mauritius_wildlife_protection.votes,
+            },
+            ArchivedResult {
+                address: organisations.mauritius_nature_protection_society.address,
+                votes: organisations.mauritius_nature_protection_society.votes,
+            },
+            ArchivedResult {
+                address: organisations.mauritius_marine_life_protection.address,
+                votes: organisations.mauritius_marine_life_protection.votes,
+            },
+        ];
+        let mut winner = results[0].clone();
+        for result in results.iter().skip(1) {
+            if result.votes > winner.votes {
+                winner = result.clone();
+            }
+        }
+        ctx.accounts.archive.desc = String::from("archive");
+        ctx.accounts.archive.version = ACCOUNT_VERSION;
+        ctx.accounts.archive.election = ctx.accounts.election_pda.key();
+        ctx.accounts.archive.date = ctx.accounts.election_pda.date;
+        ctx.accounts.archive.finalized_at = current_timestamp;
+        ctx.accounts.archive.winner = winner.address;
+        ctx.accounts.archive.results = results;
+        msg!("Archived the results of the election");
+        Ok(())
+    }
+
+    pub fn close_vote(ctx: Context<CloseVote>, _date_of_election: i64) -> Result<()> {
+        msg!("Starting close vote process");
+
+        msg!("Validating that the grace period has passed");
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if ctx.accounts.archive.finalized_at + ELECTION_GRACE_PERIOD > current_timestamp {
+            return err!(InvalidElectionError::GracePeriodNotOver);
+        }
+        msg!("Closing the vote pda and refunding the voter");
+        Ok(())
+    }
+
+    pub fn close_election(ctx: Context<CloseElection>, _date_of_election: i64) -> Result<()> {
+        msg!("Starting close election process");
+
+        msg!("Validating that the election is finalized and the grace period has passed");
+        if !ctx.accounts.election_pda.finalized {
+            return err!(InvalidElectionError::NotFinalized);
+        }
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if ctx.accounts.election_pda.finalized_at + ELECTION_GRACE_PERIOD > current_timestamp {
+            return err!(InvalidElectionError::GracePeriodNotOver);
+        }
+        msg!("Closing the election pda and refunding the creator");
+        Ok(())
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> Result<()> {
+        msg!("Starting set guardian process");
+        ctx.accounts.config.desc = String::from("config");
+        ctx.accounts.config.version = ACCOUNT_VERSION;
+        ctx.accounts.config.guardian = guardian;
+        msg!("Guardian set to {}", guardian);
+        Ok(())
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+        msg!("Starting set paused process");
+        ctx.accounts.config.paused = paused;
+        msg!("Paused flags set to {:#07b}", paused);
+        Ok(())
+    }
+
+    pub fn migrate_nft_pda(ctx: Context<MigrateNftPda>, ecology: EcologicalData) -> Result<()> {
+        msg!("Starting migrate nft pda process");
+
+        if ctx.accounts.nft_pda.version >= NFT_PDA_VERSION {
+            return err!(InvalidMigrationError::AlreadyMigrated);
+        }
+
+        if ctx.accounts.nft_pda.version < 1 {
+            msg!("Converting the conservation address to a pubkey");
+            ctx.accounts.nft_pda.project = ctx.accounts.nft_pda.project_address()?;
+        }
+
+        msg!("Adding ecological data");
+        ecology.validate()?;
+        ctx.accounts.nft_pda.ecology = ecology;
+        ctx.accounts.nft_pda.version = NFT_PDA_VERSION;
+        msg!("Migrated nft pda to version {}", NFT_PDA_VERSION);
+        Ok(())
+    }
+
+    pub fn migrate_election(ctx: Context<MigrateElection>, _date_of_election: i64) -> Result<()> {
+        msg!("Starting migrate election process");
+
+        let election_info = ctx.accounts.election_pda.to_account_info();
+        let election = read_election(&election_info)?;
+        if election.version >= ACCOUNT_VERSION {
+            return err!(InvalidMigrationError::AlreadyMigrated);
+        }
+
+        if election_info.data_len() < ELECTION_SPACE {
+            msg!("Growing the election pda to {} bytes", ELECTION_SPACE);
+            let minimum_balance = Rent::get()?.minimum_balance(ELECTION_SPACE);
+            let top_up = minimum_balance.saturating_sub(election_info.lamports());
+            if top_up > 0 {
+                system_program::transfer(
+                    CpiContext::new(
+                        ctx.accounts.system_program.to_account_info(),
+                        system_program::Transfer {
+                            from: ctx.accounts.payer.to_account_info(),
+                            to: election_info.clone(),
+                        },
+                    ),
+                    top_up,
+                )?;
+            }
+            election_info.realloc(ELECTION_SPACE, false)?;
+        }
+
+        msg!("Rewriting the election in the current layout");
+        let election = Election {
+            version: ACCOUNT_VERSION,
+            ..election
+        };
+        let mut data = election_info.try_borrow_mut_data()?;
+        election.try_serialize(&mut &mut data[..])?;
+        msg!("Migrated election to version {}", ACCOUNT_VERSION);
+        Ok(())
+    }
+
+    // Registers the name of an nft whose mint is seeded by its name, so new nfts can't reuse it
+    pub fn migrate_nft_name(ctx: Context<MigrateNftName>, name: String) -> Result<()> {
+        msg!("Starting migrate nft name process");
+
+        ctx.accounts
+            .name_record
+            .register(&name, ctx.accounts.mint.key(), None)?;
+        msg!("Registered {} for mint {}", name, ctx.accounts.mint.key());
+        Ok(())
+    }
+
+    pub fn rename_nft(ctx: Context<RenameNft>, new_name: String) -> Result<()> {
+        msg!("Starting rename nft process");
+
+        msg!("Moving the name record to the new name");
+        let old_name = ctx.accounts.name_record.name.clone();
+        ctx.accounts.new_name_record.register(
+            &new_name,
+            ctx.accounts.name_record.mint,
+            ctx.accounts.name_record.nft_id,
+        )?;
+
+        msg!("Updating the name in the metadata");
+        let metadata = Metadata::from_account_info(&ctx.accounts.metadata.to_account_info())?;
+        let data = DataV2 {
+            name: new_name.clone(),
+            symbol: metadata.data.symbol.trim_end_matches('\0').to_string(),
+            uri: metadata.data.uri.trim_end_matches('\0').to_string(),
+            seller_fee_basis_points: metadata.data.seller_fee_basis_points,
+            creators: metadata.data.creators,
+            collection: metadata.collection,
+            uses: metadata.uses,
+        };
+        let seeds = &[
+            MINT_AUTHORITY_SEED,
+            &[*ctx.bumps.get("mint_authority").unwrap()],
+        ];
+        let signer = [&seeds[..]];
+        invoke_signed(
+            &update_metadata_accounts_v2(
+                ctx.accounts.token_metadata_program.key(),
+                ctx.accounts.metadata.key(),
+                ctx.accounts.mint_authority.key(),
+                None,
+                Some(data),
+                None,
+                None,
+            ),
+            &[
+                ctx.accounts.metadata.to_account_info(),
+                ctx.accounts.mint_authority.to_account_info(),
+            ],
+            &signer,
+        )?;
+
+        msg!("Renamed {} to {}", old_name, new_name);
+        Ok(())
+    }
+
+    pub fn set_field_reporter(
+        ctx: Context<SetFieldReporter>,
+        reporter: Pubkey,
+        active: bool,
+    ) -> Result<()> {
+        msg!("Starting set field reporter process");
+        ctx.accounts.reporter_pda.desc = String::from("field reporter");
+        ctx.accounts.reporter_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.reporter_pda.project = ctx.accounts.project_account.key();
+        ctx.accounts.reporter_pda.reporter = reporter;
+        ctx.accounts.reporter_pda.active = active;
+        msg!("Field reporter {} active: {}", reporter, active);
+        Ok(())
+    }
+
+    pub fn post_status_update(
+        ctx: Context<PostStatusUpdate>,
+        health: HealthStatus,
+        evidence_hash: [u8; 32],
+        evidence_uri: String,
+    ) -> Result<()> {
+        msg!("Starting post status update process");
+
+        if evidence_uri.len() > MAX_EVIDENCE_URI_LENGTH {
+            return err!(InvalidStatusError::UriTooLong);
+        }
+
+        msg!("Growing the status log to fit the new update");
+        let index = ctx.accounts.status_log.count;
+        let offset = StatusLog::entry_offset(index);
+        let new_len = offset + STATUS_UPDATE_SPACE;
+        let status_log = ctx.accounts.status_log.to_account_info();
+        let rent_top_up = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(status_log.lamports());
+        if rent_top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.reporter.to_account_info(),
+                        to: status_log.clone(),
+                    },
+                ),
+                rent_top_up,
+            )?;
+        }
+        status_log.realloc(new_len, true)?;
+
+        msg!("Appending the status update");
+        let update = StatusUpdate {
+            health,
+            timestamp: Clock::get()?.unix_timestamp,
+            reporter: ctx.accounts.reporter.key(),
+            evidence_hash,
+            evidence_uri,
+        };
+        update.serialize(&mut &mut status_log.try_borrow_mut_data()?[offset..new_len])?;
+
+        let status_log = &mut ctx.accounts.status_log;
+        status_log.desc = String::from("status log");
+        status_log.version = ACCOUNT_VERSION;
+        status_log.mint = ctx.accounts.nft_pda.mint;
+        status_log.count += 1;
+        msg!("Status update {} posted", index);
+        Ok(())
+    }
+
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        verifier: Pubkey,
+        milestones: Vec<MilestoneTerms>,
+    ) -> Result<()> {
+        msg!("Starting create escrow process");
+
+        msg!("Validating the milestones passed in");
+        if milestones.is_empty() || milestones.len() > MAX_MILESTONES {
+            return err!(InvalidEscrowError::InvalidMilestoneCount);
+        }
+        let total_basis_points: u32 = milestones.iter().map(|m| m.basis_points as u32).sum();
+        if total_basis_points != 10_000 {
+            return err!(InvalidEscrowError::InvalidBasisPoints);
+        }
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if milestones.iter().any(|m| m.deadline < current_timestamp) {
+            return err!(InvalidEscrowError::InvalidDeadline);
+        }
+        msg!("Validated the milestones passed in");
+
+        msg!("Updating newly created escrow");
+        ctx.accounts.escrow.desc = String::from("escrow");
+        ctx.accounts.escrow.version = ACCOUNT_VERSION;
+        ctx.accounts.escrow.mint = ctx.accounts.mint.key();
+        ctx.accounts.escrow.project = ctx.accounts.nft_pda.project_address()?;
+        ctx.accounts.escrow.verifier = verifier;
+        ctx.accounts.escrow.milestones = milestones
+            .into_iter()
+            .map(|terms| Milestone {
+                basis_points: terms.basis_points,
+                deadline: terms.deadline,
+                ..Default::default()
+            })
+            .collect();
+        msg!("Successfully updated newly created escrow");
+        Ok(())
+    }
+
+    pub fn post_milestone_evidence(
+        ctx: Context<PostMilestoneEvidence>,
+        index: u8,
+        evidence_hash: [u8; 32],
+        evidence_uri: String,
+    ) -> Result<()> {
+        msg!("Starting post milestone evidence process");
+
+        if evidence_uri.len() > 100 {
+            return err!(InvalidEscrowError::UriTooLong);
+        }
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        let milestone = ctx.accounts.escrow.milestone_mut(index)?;
+        if milestone.status != MilestoneStatus::Pending
+            && milestone.status != MilestoneStatus::EvidencePosted
+        {
+            return err!(InvalidEscrowError::MilestoneSettled);
+        }
+        if milestone.deadline < current_timestamp {
+            return err!(InvalidEscrowError::DeadlinePassed);
+        }
+
+        milestone.evidence_hash = evidence_hash;
+        milestone.evidence_uri = evidence_uri;
+        milestone.status = MilestoneStatus::EvidencePosted;
+        msg!("Evidence posted for milestone {}", index);
+        Ok(())
+    }
+
+    pub fn attest_milestone(ctx: Context<AttestMilestone>, index: u8) -> Result<()> {
+        msg!("Starting attest milestone process");
+
+        if ctx.accounts.escrow.milestone_mut(index)?.status != MilestoneStatus::EvidencePosted {
+            return err!(InvalidEscrowError::EvidenceNotPosted);
+        }
+        let amount = ctx
+            .accounts
+            .escrow
+            .settle(index, MilestoneStatus::Released)?;
+
+        msg!("Releasing {} lamports to the conservation project", amount);
+        **ctx
+            .accounts
+            .escrow
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= amount;
+        **ctx
+            .accounts
+            .project_account
+            .to_account_info()
+            .try_borrow_mut_lamports()? += amount;
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn refund_milestone(ctx: Context<RefundMilestone>, index: u8) -> Result<()> {
+        msg!("Starting refund milestone process");
+
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if ctx.accounts.escrow.milestone_mut(index)?.deadline >= current_timestamp {
+            return err!(InvalidEscrowError::DeadlineNotPassed);
+        }
+        let amount = ctx
+            .accounts
+            .escrow
+            .settle(index, MilestoneStatus::Refunded)?;
+
+        msg!("Refunding {} lamports to the treasury", amount);
+        **ctx
+            .accounts
+            .escrow
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= amount;
+        **ctx
+            .accounts
+            .treasury_account
+            .to_account_info()
+            .try_borrow_mut_lamports()? += amount;
+        ctx.accounts
+            .treasury_account
+            .record_inflow(TreasuryInflow::EscrowRefund, amount);
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        amount: u64,
+        reason: String,
+    ) -> Result<()> {
+        msg!("Starting withdraw treasury process");
+
+        withdraw_from_treasury(
+            &mut ctx.accounts.treasury_account,
+            &mut ctx.accounts.recipient_ledger,
+            &ctx.accounts.recipient.to_account_info(),
+            amount,
+            reason,
+            Pubkey::default(),
+        )?;
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn open_grant_stream(ctx: Context<OpenGrantStream>, amount: u64) -> Result<()> {
+        msg!("Starting open grant stream process");
+
+        msg!(
+            "Funding the stream with {} lamports from the treasury",
+            amount
+        );
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        debit_treasury(
+            &mut ctx.accounts.treasury_account,
+            &ctx.accounts.grant_stream.to_account_info(),
+            amount,
+        )?;
+
+        msg!("Updating newly created grant stream");
+        ctx.accounts.grant_stream.desc = String::from("grantStream");
+        ctx.accounts.grant_stream.version = ACCOUNT_VERSION;
+        ctx.accounts.grant_stream.archive = ctx.accounts.archive.key();
+        ctx.accounts.grant_stream.recipient = ctx.accounts.archive.winner;
+        ctx.accounts.grant_stream.total = amount;
+        ctx.accounts.grant_stream.start = current_timestamp;
+        ctx.accounts.grant_stream.end = current_timestamp + GRANT_STREAM_DURATION;
+        msg!("Successfully updated newly created grant stream");
+        Ok(())
+    }
+
+    pub fn claim_grant(ctx: Context<ClaimGrant>) -> Result<()> {
+        msg!("Starting claim grant process");
+
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        let amount =
+            ctx.accounts.grant_stream.vested(current_timestamp) - ctx.accounts.grant_stream.claimed;
+        if amount == 0 {
+            return err!(InvalidGrantError::NothingVested);
+        }
+
+        msg!("Moving {} vested lamports to the organisation", amount);
+        **ctx
+            .accounts
+            .grant_stream
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= amount;
+        **ctx
+            .accounts
+            .recipient
+            .to_account_info()
+            .try_borrow_mut_lamports()? += amount;
+        ctx.accounts.grant_stream.claimed += amount;
+        ctx.accounts.archive.paid_out += amount;
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn propose_stream_cancellation(
+        ctx: Context<ProposeStreamCancellation>,
+        _date_of_election: i64,
+    ) -> Result<()> {
+        msg!("Starting propose stream cancellation process");
+
+        msg!("Validating that the proposal can still be voted on");
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if ctx.accounts.election_pda.date <= ctx.accounts.archive.date {
+            return err!(InvalidGrantError::ElectionNotLater);
+        }
+        if ctx.accounts.election_pda.date < current_timestamp {
+            return err!(InvalidVoteError::ElectionClosed);
+        }
+        if ctx.accounts.grant_stream.cancelled || current_timestamp >= ctx.accounts.grant_stream.end
+        {
+            return err!(InvalidGrantError::StreamEnded);
+        }
+
+        msg!("Updating newly created cancellation");
+        ctx.accounts.cancellation.desc = String::from("stream cancellation");
+        ctx.accounts.cancellation.version = ACCOUNT_VERSION;
+        ctx.accounts.cancellation.grant_stream = ctx.accounts.grant_stream.key();
+        ctx.accounts.cancellation.election = ctx.accounts.election_pda.key();
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn vote_on_cancellation(
+        ctx: Context<VoteOnCancellation>,
+        _date_of_election: i64,
+        cancel: bool,
+    ) -> Result<()> {
+        msg!("Starting vote on cancellation process");
+
+        msg!("Validating that the election is still active");
+        if ctx.accounts.election_pda.date < Clock::get()?.unix_timestamp {
+            return err!(InvalidVoteError::ElectionClosed);
+        }
+
+        msg!("Recording the ballot of the vote pda");
+        ctx.accounts.ballot.desc = String::from("ballot");
+        ctx.accounts.ballot.version = ACCOUNT_VERSION;
+        ctx.accounts.ballot.cancellation = ctx.accounts.cancellation.key();
+        ctx.accounts.ballot.vote = ctx.accounts.vote_pda.key();
+        ctx.accounts.ballot.cancel = cancel;
+        if cancel {
+            ctx.accounts.cancellation.cancel_votes += 1;
+        } else {
+            ctx.accounts.cancellation.keep_votes += 1;
+        }
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn cancel_grant_stream(ctx: Context<CancelGrantStream>) -> Result<()> {
+        msg!("Starting cancel grant stream process");
+
+        msg!("Validating that the later election voted to cancel the stream");
+        if ctx.accounts.later_archive.date <= ctx.accounts.archive.date {
+            return err!(InvalidGrantError::ElectionNotLater);
+        }
+        if !ctx.accounts.cancellation.passed() {
+            return err!(InvalidGrantError::CancellationNotPassed);
+        }
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        if ctx.accounts.grant_stream.cancelled || current_timestamp >= ctx.accounts.grant_stream.end
+        {
+            return err!(InvalidGrantError::StreamEnded);
+        }
+
+        msg!("Stopping the stream - what has vested can still be claimed");
+        let later_archive = ctx.accounts.later_archive.key();
+        let remainder = ctx
+            .accounts
+            .grant_stream
+            .cancel(current_timestamp, later_archive);
+
+        msg!("Returning the unvested remainder to the treasury");
+        **ctx
+            .accounts
+            .grant_stream
+            .to_account_info()
+            .try_borrow_mut_lamports()? -= remainder;
+        **ctx
+            .accounts
+            .treasury_account
+            .to_account_info()
+            .try_borrow_mut_lamports()? += remainder;
+        ctx.accounts
+            .treasury_account
+            .record_inflow(TreasuryInflow::GrantRefund, remainder);
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn mint_edition(ctx: Context<MintEdition>, edition_number: u64) -> Result<()> {
+        msg!("starting mint edition process");
+
+        msg!("Validating the edition number");
+        if edition_number != ctx.accounts.print_run.minted + 1 {
+            return err!(InvalidEditionError::InvalidEditionNumber);
+        }
+        if edition_number > ctx.accounts.print_run.max_supply {
+            return err!(InvalidEditionError::SoldOut);
+        }
+
+        msg!("Checking the purchase limits of the buyer");
+        ctx.accounts.purchase_counter.desc = String::from("purchase counter");
+        ctx.accounts.purchase_counter.version = ACCOUNT_VERSION;
+        ctx.accounts.purchase_counter.wallet = ctx.accounts.payer.key();
+        ctx.accounts
+            .purchase_counter
+            .record_purchase(&load_config(&ctx.accounts.config)?, Clock::get()?.slot)?;
+        check_presale_ended(
+            &ctx.accounts.sale_phase.to_account_info(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let price = ctx.accounts.print_run.price;
+        let (project_share, treasury_share) = split_proceeds(price);
+        msg!("Invoking system_program::transfer for transferring sol to the conservation project");
+        pay_project_share(
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.project_account.to_account_info(),
+            &mut ctx.accounts.treasury_account,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            project_share,
+        )?;
+        msg!("Invoking system_program::transfer for transferring sol to treasury");
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.treasury_account.to_account_info(),
+                },
+            ),
+            treasury_share,
+        )?;
+
+        msg!("Getting seeds of mint authority pda and nft pda (owner of the master edition)");
+        let mint_key = ctx.accounts.mint.key();
+        let mint_authority_seeds = &[
+            MINT_AUTHORITY_SEED,
+            &[*ctx.bumps.get("mint_authority").unwrap()],
+        ];
+        let nft_pda_seeds = &[
+            NFT_PDA_SEED,
+            mint_key.as_ref(),
+            &[*ctx.bumps.get("nft_pda").unwrap()],
+        ];
+        let signer = [&mint_authority_seeds[..], &nft_pda_seeds[..]];
+        msg!("Got seeds of mint authority pda and nft pda");
+
+        msg!("Minting the print's token to the buyer");
+        mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.edition_mint.to_account_info(),
+                    to: ctx.accounts.to_account.to_account_info(),
+                    authority: ctx.accounts.mint_authority.to_account_info(),
+                },
+                &signer,
+            ),
+            1,
+        )?;
+
+        let edition_infos = vec![
+            ctx.accounts.edition_metadata.to_account_info(), // new metadata
+            ctx.accounts.edition.to_account_info(),          // new edition
+            ctx.accounts.master_edition.to_account_info(),   // master edition
+            ctx.accounts.edition_mint.to_account_info(),     // new mint
+            ctx.accounts.edition_marker.to_account_info(),   // edition marker
+            ctx.accounts.mint_authority.to_account_info(),   // new mint authority
+            ctx.accounts.payer.to_account_info(),            // payer
+            ctx.accounts.nft_pda.to_account_info(),          // owner of the master edition token
+            ctx.accounts.master_token_account.to_account_info(), // master edition token account
+            ctx.accounts.mint_authority.to_account_info(),   // update authority of the new metadata
+            ctx.accounts.metadata.to_account_info(),         // master metadata
+            ctx.accounts.token_program.to_account_info(),    // spl token id
+            ctx.accounts.system_program.to_account_info(),   // system program id
+        ];
+        msg!("Edition Account Infos Assigned");
+        invoke_signed(
+            &mint_new_edition_from_master_edition_via_token(
+                ctx.accounts.token_metadata_program.key(), // token metadata program
+                ctx.accounts.edition_metadata.key(),       // new metadata
+                ctx.accounts.edition.key(),                // new edition
+                ctx.accounts.master_edition.key(),         // master edition
+                ctx.accounts.edition_mint.key(),           // new mint
+                ctx.accounts.mint_authority.key(),         // new mint authority
+                ctx.accounts.payer.key(),                  // payer
+                ctx.accounts.nft_pda.key(),                // owner of the master edition token
+                ctx.accounts.master_token_account.key(),   // master edition token account
+                ctx.accounts.mint_authority.key(),         // update authority of the new metadata
+                ctx.accounts.metadata.key(),               // master metadata
+                ctx.accounts.mint.key(),                   // master mint
+                edition_number,                            // edition number
+            ),
+            edition_infos.as_slice(),
+            &signer,
+        )?;
+        msg!(
+            "Edition {} of {} Minted !!!",
+            edition_number,
+            ctx.accounts.print_run.max_supply
+        );
+
+        msg!("Updating print_run, donor_pda and leaderboard");
+        ctx.accounts.print_run.minted += 1;
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.lamports_given += price;
+        ctx.accounts.donor_pda.nfts_bought += 1;
+        ctx.accounts.leaderboard.record(
+            ctx.accounts.payer.key(),
+            ctx.accounts.donor_pda.lamports_given,
+        );
+        msg!("Updated print_run, donor_pda and leaderboard");
+
+        ctx.accounts
+            .treasury_account
+            .record_inflow(TreasuryInflow::Sale, treasury_share);
+        ctx.accounts.treasury_account.version = ACCOUNT_VERSION;
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn create_mint_2022(
+        ctx: Context<CreateMint2022>,
+        name: String,
+        symbol: String,
+        uri: String,
+        ecology: EcologicalData,
+        transfer_hook_program: Option<Pubkey>,
+    ) -> Result<()> {
+        msg!("starting create token-2022 mint process");
+
+        msg!("Adding organisation_wallet pubkey and ecological data to nftPda");
+        ecology.validate()?;
+        ctx.accounts.nft_pda.conservation_address =
+            ctx.accounts.organisation_wallet.key().to_string();
+        ctx.accounts.nft_pda.project = ctx.accounts.organisation_wallet.key();
+        ctx.accounts.nft_pda.mint = ctx.accounts.mint.key();
+        ctx.accounts.nft_pda.ecology = ecology;
+        ctx.accounts.nft_pda.version = NFT_PDA_VERSION;
+        msg!("Added organisation_wallet pubkey and ecological data to nftPda");
+
+        msg!("Registering the name of the nft");
+        let nft_id = ctx.accounts.nft_counter.take_id()?;
+        ctx.accounts
+            .name_record
+            .register(&name, ctx.accounts.mint.key(), Some(nft_id))?;
+        msg!("Registered {} as nft {}", name, nft_id);
+
+        msg!("Getting seeds of mint pda and mint authority pda");
+        let nft_id_bytes = nft_id.to_le_bytes();
+        let mint_seeds = &[
+            NFT_MINT_SEED,
+            nft_id_bytes.as_ref(),
+            &[*ctx.bumps.get("mint").unwrap()],
+        ];
+        let mint_authority_seeds = &[
+            MINT_AUTHORITY_SEED,
+            &[*ctx.bumps.get("mint_authority").unwrap()],
+        ];
+        let mint_signer = [&mint_seeds[..]];
+        let mint_authority_signer = [&mint_authority_seeds[..]];
+        msg!("Got seeds of mint pda and mint authority pda");
+
+        msg!("Creating the mint account with room for its extensions");
+        let space = mint_2022_space(transfer_hook_program.is_some());
+        let metadata_space = token_metadata_space(&name, &symbol, &uri);
+        system_program::create_account(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.mint.to_account_info(),
+                },
+                &mint_signer,
+            ),
+            Rent::get()?.minimum_balance(space + metadata_space),
+            space as u64,
+            &spl_token_2022::ID,
+        )?;
+
+        let mint_key = ctx.accounts.mint.key();
+        let mint_authority_key = ctx.accounts.mint_authority.key();
+        let mint_infos = [
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ];
+        msg!("Pointing the mint's metadata at the mint itself");
+        invoke(
+            &initialize_metadata_pointer(&mint_key, &mint_authority_key),
+            &mint_infos,
+        )?;
+        if let Some(hook_program) = transfer_hook_program {
+            msg!(
+                "Adding transfer hook {} for enforcing royalties",
+                hook_program
+            );
+            invoke(
+                &initialize_transfer_hook(&mint_key, &mint_authority_key, &hook_program),
+                &mint_infos,
+            )?;
+        }
+        msg!("Initializing the mint");
+        invoke(
+            &spl_token_2022::instruction::initialize_mint2(
+                &spl_token_2022::ID,
+                &mint_key,
+                &mint_authority_key,
+                Some(&mint_authority_key),
+                0,
+            )?,
+            &mint_infos,
+        )?;
+        msg!("Writing the token metadata into the mint");
+        invoke_signed(
+            &initialize_token_metadata(&mint_key, &mint_authority_key, name, symbol, uri),
+            &[
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.mint_authority.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+            ],
+            &mint_authority_signer,
+        )?;
+
+        msg!("Creating the token account of the nft pda");
+        create_ata_idempotent(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.nft_pda.to_account_info(),
+            &ctx.accounts.token_account.to_account_info(),
+            &ctx.accounts.mint.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.associated_token_program.to_account_info(),
+        )?;
+
+        msg!("Minting token");
+        let mint_to_infos = [
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.token_account.to_account_info(),
+            ctx.accounts.mint_authority.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ];
+        invoke_signed(
+            &spl_token_2022::instruction::mint_to(
+                &spl_token_2022::ID,
+                &mint_key,
+                &ctx.accounts.token_account.key(),
+                &mint_authority_key,
+                &[],
+                1,
+            )?,
+            &mint_to_infos,
+            &mint_authority_signer,
+        )?;
+        msg!("Token Minted !!!");
+
+        msg!("Removing the mint authority so the supply stays at 1");
+        invoke_signed(
+            &spl_token_2022::instruction::set_authority(
+                &spl_token_2022::ID,
+                &mint_key,
+                None,
+                spl_token_2022::instruction::AuthorityType::MintTokens,
+                &mint_authority_key,
+                &[],
+            )?,
+            &[
+                ctx.accounts.mint.to_account_info(),
+                ctx.accounts.mint_authority.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+            ],
+            &mint_authority_signer,
+        )?;
+        msg!("Token-2022 Nft Minted !!!");
+        Ok(())
+    }
+
+    pub fn create_compressed_tree(
+        ctx: Context<CreateCompressedTree>,
+        family: String,
+        max_depth: u32,
+        max_buffer_size: u32,
+        price: u64,
+    ) -> Result<()> {
+        msg!("Starting create compressed tree process");
+
+        if family.len() > 32 {
+            return err!(InvalidCompressedError::FamilyTooLong);
+        }
+        if price == 0 {
+            return err!(InvalidCompressedError::InvalidPrice);
+        }
+
+        msg!("Invoking bubblegum to create the merkle tree");
+        let merkle_tree_key = ctx.accounts.merkle_tree.key();
+        let seeds = &[
+            COMPRESSED_TREE_SEED,
+            merkle_tree_key.as_ref(),
+            &[*ctx.bumps.get("compressed_tree").unwrap()],
+        ];
+        let signer = [&seeds[..]];
+        let mut data = BUBBLEGUM_CREATE_TREE.to_vec();
+        // public: Some(false) - only the compressed tree pda can mint into the tree
+        data.extend_from_slice(&(max_depth, max_buffer_size, Some(false)).try_to_vec()?);
+        invoke_signed(
+            &Instruction {
+                program_id: BUBBLEGUM_ID,
+                accounts: vec![
+                    AccountMeta::new(ctx.accounts.tree_authority.key(), false),
+                    AccountMeta::new(merkle_tree_key, false),
+                    AccountMeta::new(ctx.accounts.payer.key(), true),
+                    AccountMeta::new_readonly(ctx.accounts.compressed_tree.key(), true),
+                    AccountMeta::new_readonly(ctx.accounts.log_wrapper.key(), false),
+                    AccountMeta::new_readonly(ctx.accounts.compression_program.key(), false),
+                    AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+                ],
+                data,
+            },
+            &[
+                ctx.accounts.tree_authority.to_account_info(),
+                ctx.accounts.merkle_tree.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.compressed_tree.to_account_info(),
+                ctx.accounts.log_wrapper.to_account_info(),
+                ctx.accounts.compression_program.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.bubblegum_program.to_account_info(),
+            ],
+            &signer,
+        )?;
+        msg!("Merkle tree created");
+
+        msg!("Updating newly created compressed tree");
+        ctx.accounts.compressed_tree.desc = String::from("compressedTree");
+        ctx.accounts.compressed_tree.version = ACCOUNT_VERSION;
+        ctx.accounts.compressed_tree.merkle_tree = merkle_tree_key;
+        ctx.accounts.compressed_tree.family = family;
+        ctx.accounts.compressed_tree.project = ctx.accounts.project_account.key();
+        ctx.accounts.compressed_tree.price = price;
+        msg!("Successfully updated newly created compressed tree");
+        Ok(())
+    }
+
+    pub fn mint_compressed_nft(
+        ctx: Context<MintCompressedNft>,
+        name: String,
+        symbol: String,
+        uri: String,
+        seller_fee_basis_points: u16,
+    ) -> Result<()> {
+        msg!("Starting mint compressed nft process");
+
+        let merkle_tree_key = ctx.accounts.merkle_tree.key();
+        let compressed_tree_key = ctx.accounts.compressed_tree.key();
+        let seeds = &[
+            COMPRESSED_TREE_SEED,
+            merkle_tree_key.as_ref(),
+            &[*ctx.bumps.get("compressed_tree").unwrap()],
+        ];
+        let signer = [&seeds[..]];
+
+        msg!("Invoking bubblegum to mint the nft into the tree");
+        let metadata = BubblegumMetadataArgs {
+            name: name.clone(),
+            symbol,
+            uri: uri.clone(),
+            seller_fee_basis_points,
+            primary_sale_happened: false,
+            is_mutable: true,
+            edition_nonce: None,
+            token_standard: Some(0), // NonFungible
+            collection: None,
+            uses: None,
+            token_program_version: 0, // Original
+            creators: vec![BubblegumCreator {
+                address: ctx.accounts.compressed_tree.project,
+                verified: false,
+                share: 100,
+            }],
+        };
+        let mut data = BUBBLEGUM_MINT_V1.to_vec();
+        data.extend_from_slice(&metadata.try_to_vec()?);
+        invoke_signed(
+            &Instruction {
+                program_id: BUBBLEGUM_ID,
+                accounts: vec![
+                    AccountMeta::new(ctx.accounts.tree_authority.key(), false),
+                    AccountMeta::new_readonly(compressed_tree_key, false), // leaf owner
+                    AccountMeta::new_readonly(compressed_tree_key, false), // leaf delegate
+                    AccountMeta::new(merkle_tree_key, false),
+                    AccountMeta::new(ctx.accounts.payer.key(), true),
+                    AccountMeta::new_readonly(compressed_tree_key, true), // tree delegate
+                    AccountMeta::new_readonly(ctx.accounts.log_wrapper.key(), false),
+                    AccountMeta::new_readonly(ctx.accounts.compression_program.key(), false),
+                    AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+                ],
+                data,
+            },
+            &[
+                ctx.accounts.tree_authority.to_account_info(),
+                ctx.accounts.compressed_tree.to_account_info(),
+                ctx.accounts.merkle_tree.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.log_wrapper.to_account_info(),
+                ctx.accounts.compression_program.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.bubblegum_program.to_account_info(),
+            ],
+            &signer,
+        )?;
+
+        let nonce = ctx.accounts.compressed_tree.minted;
+        ctx.accounts.compressed_tree.minted += 1;
+        emit!(CompressedNftMinted {
+            merkle_tree: merkle_tree_key,
+            asset_id: compressed_asset_id(&merkle_tree_key, nonce),
+            nonce,
+            name,
+            uri,
+        });
+        msg!("Compressed Nft Minted !!!");
+        Ok(())
+    }
+
+    pub fn buy_compressed_nft<'info>(
+        ctx: Context<'_, '_, '_, 'info, BuyCompressedNft<'info>>,
+        leaf: CompressedLeaf,
+    ) -> Result<()> {
+        msg!("Starting buy compressed nft process");
+
+        msg!("Checking the purchase limits of the buyer");
+        ctx.accounts.purchase_counter.desc = String::from("purchase counter");
+        ctx.accounts.purchase_counter.version = ACCOUNT_VERSION;
+        ctx.accounts.purchase_counter.wallet = ctx.accounts.payer.key();
+        ctx.accounts
+            .purchase_counter
+            .record_purchase(&load_config(&ctx.accounts.config)?, Clock::get()?.slot)?;
+
+        check_presale_ended(
+            &ctx.accounts.sale_phase.to_account_info(),
+            Clock::get()?.unix_timestamp,
+        )?;
+
+        let price = ctx.accounts.compressed_tree.price;
+        let (project_share, treasury_share) = split_proceeds(price);
+        msg!("Invoking system_program::transfer for transferring sol to the conservation project");
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.project_account.to_account_info(),
+                },
+            ),
+            project_share,
+        )?;
+        msg!("Invoking system_program::transfer for transferring sol to treasury");
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: ctx.accounts.treasury_account.to_account_info(),
+                },
+            ),
+            treasury_share,
+        )?;
+
+        msg!("Invoking bubblegum to transfer the nft to the buyer");
+        let merkle_tree_key = ctx.accounts.merkle_tree.key();
+        let compressed_tree_key = ctx.accounts.compressed_tree.key();
+        let seeds = &[
+            COMPRESSED_TREE_SEED,
+            merkle_tree_key.as_ref(),
+            &[*ctx.bumps.get("compressed_tree").unwrap()],
+        ];
+        let signer = [&seeds[..]];
+        let mut data = BUBBLEGUM_TRANSFER.to_vec();
+        data.extend_from_slice(&leaf.try_to_vec()?);
+        let mut accounts = vec![
+            AccountMeta::new_readonly(ctx.accounts.tree_authority.key(), false),
+            AccountMeta::new_readonly(compressed_tree_key, true), // leaf owner
+            AccountMeta::new_readonly(compressed_tree_key, false), // leaf delegate
+            AccountMeta::new_readonly(ctx.accounts.payer.key(), false), // new leaf owner
+            AccountMeta::new(merkle_tree_key, false),
+            AccountMeta::new_readonly(ctx.accounts.log_wrapper.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.compression_program.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.system_program.key(), false),
+        ];
+        let mut infos = vec![
+            ctx.accounts.tree_authority.to_account_info(),
+            ctx.accounts.compressed_tree.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.merkle_tree.to_account_info(),
+            ctx.accounts.log_wrapper.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.bubblegum_program.to_account_info(),
+        ];
+        msg!("Appending the merkle proof from the remaining accounts");
+        for proof_node in ctx.remaining_accounts {
+            accounts.push(AccountMeta::new_readonly(proof_node.key(), false));
+            infos.push(proof_node.clone());
+        }
+        invoke_signed(
+            &Instruction {
+                program_id: BUBBLEGUM_ID,
+                accounts,
+                data,
+            },
+            &infos,
+            &signer,
+        )?;
+
+        msg!("Updating compressed_tree, donor_pda and leaderboard");
+        ctx.accounts.compressed_tree.sold += 1;
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.lamports_given += price;
+        ctx.accounts.donor_pda.nfts_bought += 1;
+        ctx.accounts.leaderboard.record(
+            ctx.accounts.payer.key(),
+            ctx.accounts.donor_pda.lamports_given,
+        );
+        msg!("Updated compressed_tree, donor_pda and leaderboard");
+
+        ctx.accounts
+            .treasury_account
+            .record_inflow(TreasuryInflow::Sale, treasury_share);
+        ctx.accounts.treasury_account.version = ACCOUNT_VERSION;
+        msg!("Success!!");
+        Ok(())
+    }
+
+    pub fn create_compressed_vote<'info>(
+        ctx: Context<'_, '_, '_, 'info, CreateCompressedVote<'info>>,
+        _date_of_election: i64,
+        leaf: CompressedLeaf,
+        mauritius_wildlife_protection_vote: u32,
+        mauritius_nature_protection_society_vote: u32,
+        mauritius_marine_life_protection_vote: u32,
+    ) -> Result<()> {
+        msg!("Starting create compressed vote process");
+
+        msg!("Validating that the voter owns the compressed nft");
+        let leaf_hash = leaf.hash(
+            &ctx.accounts.asset.key(),
+            &ctx.accounts.payer.key(),
+            &ctx.accounts.leaf_delegate.key(),
+        );
+        let mut data = COMPRESSION_VERIFY_LEAF.to_vec();
+        data.extend_from_slice(&(leaf.root, leaf_hash, leaf.index).try_to_vec()?);
+        let mut accounts = vec![AccountMeta::new_readonly(
+            ctx.accounts.merkle_tree.key(),
+            false,
+        )];
+        let mut infos = vec![
+            ctx.accounts.merkle_tree.to_account_info(),
+            ctx.accounts.compression_program.to_account_info(),
+        ];
+        for proof_node in ctx.remaining_accounts {
+            accounts.push(AccountMeta::new_readonly(proof_node.key(), false));
+            infos.push(proof_node.clone());
+        }
+        invoke(
+            &Instruction {
+                program_id: COMPRESSION_ID,
+                accounts,
+                data,
+            },
+            &infos,
+        )?;
+        msg!("Validated that the voter owns the compressed nft");
+
+        let current_timestamp = Clock::get()?.unix_timestamp;
+        let vote = Votes::new(
+            (
+                mauritius_wildlife_protection_vote,
+                ctx.accounts.mauritius_wildlife_protection.key(),
+            ),
+            (
+                mauritius_nature_protection_society_vote,
+                ctx.accounts.mauritius_nature_protection_society.key(),
+            ),
+            (
+                mauritius_marine_life_protection_vote,
+                ctx.accounts.mauritius_marine_life_protection.key(),
+            ),
+        )?;
+        ctx.accounts
+            .election_pda
+            .add_votes(&vote, current_timestamp)?;
+
+        msg!("Updating newly created Vote PDA with the appropriate values and votes");
+        ctx.accounts.vote_pda.desc = String::from("vote");
+        ctx.accounts.vote_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.vote_pda.mint = ctx.accounts.asset.key();
+        ctx.accounts.vote_pda.voter = ctx.accounts.payer.key();
+        ctx.accounts.vote_pda.ata = ctx.accounts.merkle_tree.key();
+        ctx.accounts.vote_pda.date = current_timestamp;
+        ctx.accounts.vote_pda.vote = vote;
+        msg!("Updated newly created Vote PDA with the appropriate values and votes");
+
+        msg!("Updating donor_pda");
+        ctx.accounts.donor_pda.desc = String::from("donor");
+        ctx.accounts.donor_pda.version = ACCOUNT_VERSION;
+        ctx.accounts.donor_pda.wallet = ctx.accounts.payer.key();
+        ctx.accounts.donor_pda.votes_cast += 1;
+        msg!("Updated donor_pda");
+        Ok(())
+    }
+}
+
+// Price of an nft bought at a fixed price, in lamports - 1 sol
+pub const NFT_PRICE: u64 = 1_000_000_000;
+
+// Splits the proceeds of a sale between the conservation project and the treasury (50% each)
+pub fn split_proceeds(amount: u64) -> (u64, u64) {
+    let project_share = amount / 2;
+    (project_share, amount - project_share)
+}
+
+// Nfts with an Escrow PDA hold the project's share of a sale until its milestones are met.
+// The part owed to milestones that are already settled is passed straight on to the project
+// or back to the treasury. Everything else pays the conservation project straight away
+pub fn pay_project_share<'info>(
+    escrow: &AccountInfo<'info>,
+    project_account: &AccountInfo<'info>,
+    treasury: &mut Account<'info, Treasury>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let escrowed = !escrow.data_is_empty();
+    let destination = if escrowed { escrow } else { project_account };
+    system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            system_program::Transfer {
+                from: payer.clone(),
+                to: destination.clone(),
+            },
+        ),
+        amount,
+    )?;
+    if escrowed {
+        let mut escrow_account = Account::<Escrow>::try_from(escrow)?;
+        if escrow_account.project != project_account.key() {
+            return err!(InvalidProjectError::ProjectMismatch);
+        }
+        let (released, refunded) = escrow_account.deposit(amount);
+        escrow_account.exit(&crate::ID)?;
+        **escrow.try_borrow_mut_lamports()? -= released + refunded;
+        **project_account.try_borrow_mut_lamports()? += released;
+        **treasury.to_account_info().try_borrow_mut_lamports()? += refunded;
+        treasury.record_inflow(TreasuryInflow::EscrowRefund, refunded);
+    }
+    Ok(())
+}
+
+// Pays a wallet out of the treasury and records the outflow against the recipient's ledger
+pub fn withdraw_from_treasury<'info>(
+    treasury: &mut Account<'info, Treasury>,
+    recipient_ledger: &mut Account<'info, TreasuryRecipient>,
+    recipient: &AccountInfo<'info>,
+    amount: u64,
+    reason: String,
+    election: Pubkey,
+) -> Result<()> {
+    if reason.len() > 100 {
+        return err!(InvalidTreasuryError::ReasonTooLong);
+    }
+
+    msg!("Withdrawing {} lamports to {}", amount, recipient.key());
+    let balance = debit_treasury(treasury, recipient, amount)?;
+
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    treasury.withdrawals += 1;
+    recipient_ledger.desc = String::from("treasuryRecipient");
+    recipient_ledger.version = ACCOUNT_VERSION;
+    recipient_ledger.recipient = recipient.key();
+    recipient_ledger.withdrawn += amount;
+    recipient_ledger.withdrawals += 1;
+    recipient_ledger.last_withdrawal_at = current_timestamp;
+
+    emit!(TreasuryWithdrawal {
+        recipient: recipient.key(),
+        amount,
+        reason,
+        election,
+        balance,
+        timestamp: current_timestamp,
+    });
+    Ok(())
+}
+
+// Moves lamports out of the treasury PDA, refusing to leave it below rent exemption.
+// Returns the balance left in the treasury
+pub fn debit_treasury<'info>(
+    treasury: &mut Account<'info, Treasury>,
+    destination: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<u64> {
+    if amount == 0 {
+        return err!(InvalidTreasuryError::InvalidAmount);
+    }
+
+    msg!("Validating that the treasury stays rent exempt");
+    let treasury_info = treasury.to_account_info();
+    let minimum_balance = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let balance = treasury_info
+        .lamports()
+        .checked_sub(amount)
+        .filter(|balance| *balance >= minimum_balance)
+        .ok_or_else(|| error!(InvalidTreasuryError::BelowRentExemption))?;
+
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    **destination.try_borrow_mut_lamports()? += amount;
+
+    treasury.version = ACCOUNT_VERSION;
+    treasury.withdrawn += amount;
+    Ok(balance)
+}
+
+// Token interface - Animal Kingdom nfts live under either SPL Token (with Metaplex metadata) or
+// Token-2022 (with the metadata extension). Instructions that accept both take the token program,
+// mint and token accounts unchecked and validate them with the helpers below
+pub fn is_token_program(key: &Pubkey) -> bool {
+    *key == anchor_spl::token::ID || *key == spl_token_2022::ID
+}
+
+// Unpacks a mint of either token program and checks that it is an nft
+pub fn unpack_nft_mint(
+    mint: &AccountInfo,
+    token_program: &AccountInfo,
+) -> Result<spl_token_2022::state::Mint> {
+    if mint.owner != token_program.key {
+        return err!(InvalidTokenInterfaceError::InvalidOwner);
+    }
+    let data = mint.try_borrow_data()?;
+    let mint = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&data)?.base;
+    if mint.decimals != 0 || mint.supply != 1 {
+        return err!(InvalidTokenInterfaceError::NotAnNft);
+    }
+    Ok(mint)
+}
+
+// Unpacks a token account of either token program
+pub fn unpack_token_account(
+    token_account: &AccountInfo,
+    token_program: &AccountInfo,
+) -> Result<spl_token_2022::state::Account> {
+    if token_account.owner != token_program.key {
+        return err!(InvalidTokenInterfaceError::InvalidOwner);
+    }
+    let data = token_account.try_borrow_data()?;
+    Ok(StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?.base)
+}
+
+// Creates the ATA of a wallet for either token program, if it does not exist yet
+pub fn create_ata_idempotent<'info>(
+    payer: &AccountInfo<'info>,
+    wallet: &AccountInfo<'info>,
+    ata: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    associated_token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    invoke(
+        &spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+            payer.key,
+            wallet.key,
+            mint.key,
+            token_program.key,
+        ),
+        &[
+            payer.clone(),
+            ata.clone(),
+            wallet.clone(),
+            mint.clone(),
+            system_program.clone(),
+            token_program.clone(),
+            associated_token_program.clone(),
+        ],
+    )?;
+    Ok(())
+}
+
+// Transfers an nft with either token program. Token-2022 mints with a transfer hook need the
+// hook program and its extra accounts, which are passed through from the remaining accounts
+pub fn transfer_nft_interface<'info>(
+    token_program: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    extra_accounts: &[AccountInfo<'info>],
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    let mut instruction = spl_token_2022::instruction::transfer_checked(
+        token_program.key,
+        from.key,
+        mint.key,
+        to.key,
+        authority.key,
+        &[],
+        1,
+        0,
+    )?;
+    let mut infos = vec![
+        from.clone(),
+        mint.clone(),
+        to.clone(),
+        authority.clone(),
+        token_program.clone(),
+    ];
+    for account in extra_accounts {
+        instruction.accounts.push(AccountMeta {
+            pubkey: account.key(),
+            is_signer: false,
+            is_writable: account.is_writable,
+        });
+        infos.push(account.clone());
+    }
+    invoke_signed(&instruction, &infos, signer)?;
+    Ok(())
+}
+
+// Size of a Token-2022 nft mint before the token metadata is written into it
+pub fn mint_2022_space(transfer_hook: bool) -> usize {
+    let mut space = MINT_2022_BASE_SPACE + METADATA_POINTER_SPACE;
+    if transfer_hook {
+        space += TRANSFER_HOOK_SPACE;
+    }
+    space
+}
+
+// Size of the token metadata extension - Token-2022 reallocs the mint for it, so the mint
+// must already hold enough lamports to stay rent exempt
+pub fn token_metadata_space(name: &str, symbol: &str, uri: &str) -> usize {
+    // 2 type + 2 length + 32 update authority + 32 mint + name + symbol + uri + 4 additional metadata
+    2 + 2 + 32 + 32 + (4 + name.len()) + (4 + symbol.len()) + (4 + uri.len()) + 4
+}
+
+// MetadataPointerInstruction::Initialize { authority, metadata_address }
+pub fn initialize_metadata_pointer(mint: &Pubkey, authority: &Pubkey) -> Instruction {
+    let mut data = vec![METADATA_POINTER_EXTENSION, 0];
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(mint.as_ref());
+    Instruction {
+        program_id: spl_token_2022::ID,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data,
+    }
+}
+
+// TransferHookInstruction::Initialize { authority, program_id }
+pub fn initialize_transfer_hook(
+    mint: &Pubkey,
+    authority: &Pubkey,
+    hook_program: &Pubkey,
+) -> Instruction {
+    let mut data = vec![TRANSFER_HOOK_EXTENSION, 0];
+    data.extend_from_slice(authority.as_ref());
+    data.extend_from_slice(hook_program.as_ref());
+    Instruction {
+        program_id: spl_token_2022::ID,
+        accounts: vec![AccountMeta::new(*mint, false)],
+        data,
+    }
+}
+
+// TokenMetadataInstruction::Initialize { name, symbol, uri } - the metadata lives in the mint
+pub fn initialize_token_metadata(
+    mint: &Pubkey,
+    authority: &Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    let mut data = TOKEN_METADATA_INITIALIZE.to_vec();
+    data.extend_from_slice(&(name, symbol, uri).try_to_vec().unwrap());
+    Instruction {
+        program_id: spl_token_2022::ID,
+        accounts: vec![
+            AccountMeta::new(*mint, false),
+            AccountMeta::new_readonly(*authority, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(*authority, true),
+        ],
+        data,
+    }
+}
+
+// Fixed price sales are only open to everyone once the presale (if any) has ended
+pub fn check_presale_ended(sale_phase: &AccountInfo, timestamp: i64) -> Result<()> {
+    if sale_phase.data_is_empty() {
+        return Ok(());
+    }
+    let sale_phase = SalePhase::try_deserialize(&mut &sale_phase.data.borrow()[..])?;
+    if sale_phase.is_presale(timestamp) {
+        return err!(InvalidPresaleError::PresaleActive);
+    }
+    Ok(())
+}
+
+// The program config PDA is only created once an admin first configures the program.
+// Until then there are no purchase limits and nothing is paused
+pub fn load_config(config: &AccountInfo) -> Result<ProgramConfig> {
+    if config.data_is_empty() {
+        return Ok(ProgramConfig::default());
+    }
+    ProgramConfig::try_deserialize(&mut &config.data.borrow()[..])
+}
+
+// While a hold lasts only its buyer can buy the nft. When the buyer completes the purchase
+// their reservation is closed and the deposit refunded
+pub fn settle_reservation<'info>(
+    reservation: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    timestamp: i64,
+) -> Result<()> {
+    if reservation.data_is_empty() {
+        return Ok(());
+    }
+    let reservation = Account::<Reservation>::try_from(reservation)?;
+    if reservation.buyer == payer.key() {
+        return reservation.close(payer.clone());
+    }
+    if reservation.expires_at > timestamp {
+        return err!(InvalidReservationError::NftReserved);
+    }
+    Ok(())
+}
+
+// Verifies a keccak merkle proof, hashing each pair of nodes in sorted order
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed_hash = leaf;
+    for node in proof {
+        computed_hash = if computed_hash <= *node {
+            keccak::hashv(&[&computed_hash, node]).0
+        } else {
+            keccak::hashv(&[node, &computed_hash]).0
+        };
+    }
+    computed_hash == root
+}
+
+// Donations to a project must go to a registered, active conservation project
+pub fn check_project(project_pda: &AccountInfo) -> Result<()> {
+    let project = Account::<ConservationProject>::try_from(project_pda)?;
+    if !project.active {
+        return err!(InvalidProjectError::ProjectInactive);
+    }
+    Ok(())
+}
+
+// Memos attached to donations are logged, so keep them short
+pub fn validate_memo(memo: &Option<String>) -> Result<()> {
+    if let Some(memo) = memo {
+        if memo.len() > 100 {
+            return err!(InvalidDonationError::MemoTooLong);
+        }
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeMintAuthority<'info> {
+    #[account(
+        init,
+        seeds = [MINT_AUTHORITY_SEED],
+        bump,
+        payer = payer,
+        space = 100
+    )]
+    pub mint_authority: Account<'info, MintAuthority>,
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateMint<'info> {
+    // NFT counter PDA - the id of the next nft seeds its mint
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = NFT_COUNTER_SPACE,
+        seeds = [NFT_COUNTER_SEED],
+        bump
+    )]
+    pub nft_counter: Box<Account<'info, NftCounter>>,
+    #[account(
+        init,
+        seeds = [NFT_MINT_SEED, nft_counter.next_id.to_le_bytes().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+        payer = payer
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump,
+    )]
+    pub mint_authority: Account<'info, MintAuthority>,
+    pub token_program: Program<'info, Token>,
+    #[account(
+        init,
+        payer = payer,
+        space = NFT_PDA_SPACE,
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub nft_pda: Account<'info, NftPDA>,
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = mint, // mint of the token
+        associated_token::authority = nft_pda, // authority that should be a PDA account
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+    pub token_metadata_program: Program<'info, TokenMetaData>,
+    // Security - I need to verify that the address requesting to create an NFT is authorised to request this, otherwise anyone can use this program to create fake NFTs
+    #[account(mut, constraint = payer.key.to_string() == String::from("kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"))]
+    pub payer: Signer<'info>, // Why do I have a payer and a signer - are they not the same thing?
+    pub system_program: Program<'info, System>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
+    pub current_program: UncheckedAccount<'info>,
+    /// CHECK: This is dangerous - I need to think about how to make this safe
+    #[account(mut)]
+    pub organisation_wallet: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_MINTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Name record PDA - can only be created once per normalised name, so nft names are unique
+    #[account(
+        init,
+        payer = payer,
+        space = NAME_RECORD_SPACE,
+        seeds = [NAME_RECORD_SEED, name_hash(&name).as_ref()],
+        bump
+    )]
+    pub name_record: Box<Account<'info, NameRecord>>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateMetadata<'info> {
+    #[account(
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump,
+    )]
+    pub mint_authority: Account<'info, MintAuthority>,
+    pub token_program: Program<'info, Token>,
+    /// CHECK: Using "address" constraint to validate metadata account address
+    #[account(
+        mut,
+        address=find_metadata_account(&mint.key()).0
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    pub token_metadata_program: Program<'info, TokenMetaData>,
+    // Security - I need to verify that the address requesting to create an NFT is authorised to request this, otherwise anyone can use this program to create fake NFTs
+    #[account(mut, constraint = payer.key.to_string() == String::from("kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"))]
+    pub payer: Signer<'info>, // Why do I have a payer and a signer - are they not the same thing?
+    pub system_program: Program<'info, System>,
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
+    pub current_program: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_MINTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Name record PDA of the nft - ensures the metadata name is the registered name of this mint
+    #[account(
+        seeds = [NAME_RECORD_SEED, name_hash(&name).as_ref()],
+        bump,
+        has_one = mint
+    )]
+    pub name_record: Box<Account<'info, NameRecord>>,
+}
+
+#[derive(Accounts)]
+pub struct CreateMasterEdition<'info> {
+    #[account(
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+        mut
+    )]
+    pub mint: Account<'info, Mint>,
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump,
+    )]
+    pub mint_authority: Account<'info, MintAuthority>,
+    // NFT PDA - ensures the mint was created by this program
+    #[account(
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub nft_pda: Box<Account<'info, NftPDA>>,
+    pub token_program: Program<'info, Token>,
+    /// CHECK: Using "address" constraint to validate metadata account address
+    #[account(
+        mut,
+        address=find_metadata_account(&mint.key()).0
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    pub token_metadata_program: Program<'info, TokenMetaData>,
+    // Security - I need to verify that the address requesting to create an NFT is authorised to request this, otherwise anyone can use this program to create fake NFTs
+    #[account(mut, constraint = payer.key.to_string() == String::from("kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"))]
+    pub payer: Signer<'info>, // Why do I have a payer and a signer - are they not the same thing?
+    pub system_program: Program<'info, System>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
+    pub current_program: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_MINTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Print run PDA - tracks the prints sold from this master edition
+    #[account(
+        init,
+        payer = payer,
+        space = PRINT_RUN_SPACE,
+        seeds = [PRINT_RUN_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub print_run: Box<Account<'info, PrintRun>>,
+}
+
+#[derive(Accounts)]
+pub struct DeleteNft<'info> {
+    // `[writable]` NFT metadata
+    /// CHECK: Using "address" constraint to validate metadata account address
+    #[account(
+        mut,
+        address=find_metadata_account(&mint.key()).0
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    // `[writable, signer]` Owner of NFT
+    #[account(
+        mut,
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump,
+        close = treasury_account
+    )]
+    pub nft_pda: Account<'info, NftPDA>,
+
+    // `[writable]` Mint of NFT - an SPL Token or Token-2022 mint
+    /// CHECK: This is safe because the nft pda seeds constraint ensures it is a mint created by this program
+    #[account(
+        mut,
+        owner = token_program.key()
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    // `[writable]` NFT token account
+    /// CHECK: Using "address" constraint to validate the associated token account address
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&nft_pda.key(), &mint.key(), token_program.key)
+    )]
+    pub token_account: UncheckedAccount<'info>,
+
+    // `[writable]` NFT edition account
+    // Need to implement manual validation for this
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub master_edition: UncheckedAccount<'info>,
+
+    // `[]` SPL Token or Token-2022 program
+    /// CHECK: This is safe because I verify that it is one of the two token programs
+    #[account(constraint = is_token_program(token_program.key) @ InvalidTokenInterfaceError::InvalidTokenProgram)]
+    pub token_program: UncheckedAccount<'info>,
+    // Optional `[writable]` Collection metadata account
+    // Not included yet - need to implement collections first
+
+    // Token Metadata program
+    pub token_metadata_program: Program<'info, TokenMetaData>,
+
+    // Mint authority
+    // It seems that after the creation of the master edition, the master edition becomes the mint authority
+    // Need to implement manual validation for this
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub mint_authority: UncheckedAccount<'info>,
+
+    //Current program
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
+    pub current_program: UncheckedAccount<'info>,
+
+    // Treasury account - receives the rent of the closed accounts
+    #[account(
+        init_if_needed,
+        space = 200,
+        payer = payer,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+
+    // Auction PDA (may not exist if the nft was never put up for auction)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Auction PDA
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub auction_pda: UncheckedAccount<'info>,
+
+    // Reservation PDA (may not exist if the nft is not reserved)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Reservation PDA
+    #[account(
+        mut,
+        seeds = [RESERVATION_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub reservation: UncheckedAccount<'info>,
+
+    // Buyer of the reservation, refunded if the nft is reserved - can be any account otherwise
+    /// CHECK: This is safe because I verify it against the reservation's buyer before refunding it
+    #[account(mut)]
+    pub reservation_buyer: UncheckedAccount<'info>,
+
+    // Escrow PDA (may not exist if the nft is not a habitat nft)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Escrow PDA
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub escrow: UncheckedAccount<'info>,
+
+    // Print run PDA (may not exist if no prints of the nft were ever offered)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's PrintRun PDA
+    #[account(
+        mut,
+        seeds = [PRINT_RUN_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub print_run: UncheckedAccount<'info>,
+
+    // Status log PDA (may not exist if no field reporter ever posted about the nft)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's StatusLog PDA
+    #[account(
+        mut,
+        seeds = [STATUS_LOG_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub status_log: UncheckedAccount<'info>,
+
+    // Name record PDA of the nft's name (may not exist if the nft was minted before the name registry)
+    /// CHECK: This is safe because I verify that it is a NameRecord of this mint before closing it
+    #[account(mut)]
+    pub name_record: UncheckedAccount<'info>,
+
+    // Program config PDA (may not exist if the program has never been configured)
+    // Burning is paused along with minting - both change which nfts exist
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_MINTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+
+    // Payer - Security - only the trusted address can burn NFTs
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferNft<'info> {
+    // project_account (sol account of the conservation project)
+    /// CHECK: This is safe because I verify that it is the conservation address stored on the nft_pda
+    #[account(
+        mut,
+        constraint = project_account.key() == authority_account.project_address()? @ InvalidProjectError::ProjectMismatch
+    )]
+    pub project_account: UncheckedAccount<'info>,
+    // Treasury account
+    #[account(
+        init_if_needed,
+        space = 200,
+        payer = payer,
+        seeds = [TREASURY_SEED],
+        bump,
+        owner = current_program.key()
+    )]
+    pub treasury_account: Account<'info, Treasury>,
+    // From account (token_account of the nft_pda)
+    /// CHECK: Using "address" constraint to validate the associated token account address
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&authority_account.key(), &mint.key(), token_program.key)
+    )]
+    pub from_account: UncheckedAccount<'info>,
+    // To account (ATA of the wallet we are transferring to. Created in the instruction if needed)
+    /// CHECK: Using "address" constraint to validate the associated token account address
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(payer.key, &mint.key(), token_program.key)
+    )]
+    pub to_account: UncheckedAccount<'info>,
+    // Authority account (nft_pda)
+    #[account(
+        mut,
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump,
+        constraint = !authority_account.on_auction @ InvalidAuctionError::NftOnAuction
+    )]
+    pub authority_account: Account<'info, NftPDA>,
+    // Mint of the nft (used for validating accounts above) - an SPL Token or Token-2022 mint
+    /// CHECK: This is safe because the nft pda seeds constraint ensures it is a mint created by this program
+    #[account(
+        mut,
+        owner = token_program.key()
+    )]
+    pub mint: UncheckedAccount<'info>,
+    // Mint authority
+    // It seems that after the creation of the master edition, the master edition becomes the mint authority
+    // Need to implement validation for this
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub mint_authority: UncheckedAccount<'info>,
+    // Current program
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
+    pub current_program: UncheckedAccount<'info>,
+    // SPL Token or Token-2022 program
+    /// CHECK: This is safe because I verify that it is one of the two token programs
+    #[account(constraint = is_token_program(token_program.key) @ InvalidTokenInterfaceError::InvalidTokenProgram)]
+    pub token_program: UncheckedAccount<'info>,
+    // Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Donor PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Leaderboard PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    // Sale phase PDA (may not exist if no presale has been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's SalePhase PDA
+    #[account(
+        seeds = [SALE_PHASE_SEED],
+        bump
+    )]
+    pub sale_phase: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_SALES) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Purchase counter PDA of the buyer
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 100,
+        seeds = [PURCHASE_COUNTER_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub purchase_counter: Box<Account<'info, PurchaseCounter>>,
+    // Reservation PDA (may not exist if the nft has never been reserved)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Reservation PDA
+    #[account(
+        mut,
+        seeds = [RESERVATION_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub reservation: UncheckedAccount<'info>,
+    // Escrow PDA (only exists for nfts whose project share is held until milestones are met)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Escrow PDA
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub escrow: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // User
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64)]
+pub struct CreateElection<'info> {
+    // Election PDA
+    #[account(
+        init,
+        payer = payer,
+        space = ELECTION_SPACE,
+        seeds = [ELECTION_SEED, &date_of_election.to_string().as_bytes().as_ref()],
+        bump
+    )]
+    pub election_pda: Box<Account<'info, Election>>,
+    // mauritius_wildlife_protection account
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = mauritius_wildlife_protection.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
+    pub mauritius_wildlife_protection: SystemAccount<'info>,
+    // mauritius_nature_protection_society account
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = mauritius_nature_protection_society.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
+    pub mauritius_nature_protection_society: SystemAccount<'info>,
+    // mauritius_marine_life_protection account
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = mauritius_marine_life_protection.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
+    pub mauritius_marine_life_protection: SystemAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_VOTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - I verify that the address requesting to create an election is authorised to request this, otherwise anyone can use this program to create fake elections
+    #[account(mut, constraint = payer.key.to_string() == String::from("kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"))]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64, mauritius_wildlife_protection_vote: u32, mauritius_nature_protection_society_vote: u32, mauritius_marine_life_protection_vote: u32)]
+pub struct CreateVote<'info> {
+    // Vote PDA
+    #[account(
+            init,
+            payer = payer,
+            space = 400,
+            seeds = [mint.key().as_ref(), election_pda.key().as_ref()],
+            bump
+        )]
+    pub vote_pda: Box<Account<'info, Vote>>,
+    // Election PDA
+    #[account(
+            seeds = [ELECTION_SEED, &date_of_election.to_string().as_bytes().as_ref()],
+            bump
+        )]
+    pub election_pda: Box<Account<'info, Election>>,
+    // Mint of the nft (used for validating accounts above) - an SPL Token or Token-2022 mint, its supply is checked to be 1 in the instruction
+    /// CHECK: This is safe because the nft pda seeds constraint ensures it is a mint created by this program
+    #[account(
+            mut,
+            owner = token_program.key()
+        )]
+    pub mint: UncheckedAccount<'info>,
+    // NFT PDA - only exists for mints created by this program
+    /// CHECK: This is safe because the seeds and owner constraints ensure it is the nft pda of this mint. It isn't deserialized so nfts whose pda hasn't been migrated can still vote
+    #[account(
+            seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+            bump,
+            owner = current_program.key()
+        )]
+    pub nft_pda: UncheckedAccount<'info>,
+    // Mint authority
+    // It seems that after the creation of the master edition, the master edition becomes the mint authority
+    // Need to implement validation for this
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub mint_authority: UncheckedAccount<'info>,
+    // ATA account (the token balance is checked to be 1 in the instruction)
+    /// CHECK: Using "address" constraint to validate the associated token account address
+    #[account(
+            mut,
+            address = get_associated_token_address_with_program_id(payer.key, &mint.key(), token_program.key)
+        )]
+    pub ata_account: UncheckedAccount<'info>,
+    // SPL Token or Token-2022 program
+    /// CHECK: This is safe because I verify that it is one of the two token programs
+    #[account(constraint = is_token_program(token_program.key) @ InvalidTokenInterfaceError::InvalidTokenProgram)]
+    pub token_program: UncheckedAccount<'info>,
+    // mauritius_wildlife_protection account
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = mauritius_wildlife_protection.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
+    pub mauritius_wildlife_protection: SystemAccount<'info>,
+    // mauritius_nature_protection_society account
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = mauritius_nature_protection_society.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
+    pub mauritius_nature_protection_society: SystemAccount<'info>,
+    // mauritius_marine_life_protection account
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = mauritius_marine_life_protection.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
+    pub mauritius_marine_life_protection: SystemAccount<'info>,
+    // Donor PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_VOTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Current program
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
+    pub current_program: UncheckedAccount<'info>,
+    // Payer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateAuction<'info> {
+    // Auction PDA
+    #[account(
+        init,
+        payer = payer,
+        space = 200,
+        seeds = [AUCTION_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub auction_pda: Box<Account<'info, Auction>>,
+    // NFT PDA (current owner of the nft)
+    #[account(
+        mut,
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump,
+        constraint = !nft_pda.on_auction @ InvalidAuctionError::NftOnAuction
+    )]
+    pub nft_pda: Account<'info, NftPDA>,
+    // Token account of the nft_pda - the nft must still be unsold
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = nft_pda,
+        constraint = token_account.amount == 1
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+    // Mint of the nft - the nft pda seeds constraint ensures it was created by this program
+    pub mint: Account<'info, Mint>,
+    // Reservation PDA (only exists while the nft is held for a buyer)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Reservation PDA
+    #[account(
+        seeds = [RESERVATION_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub reservation: UncheckedAccount<'info>,
+    // Current program
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key() == crate::ID)]
+    pub current_program: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_SALES) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can put NFTs up for auction
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyAuctionNft<'info> {
+    // Auction PDA
+    #[account(
+        mut,
+        seeds = [AUCTION_SEED, mint.key().as_ref()],
+        bump,
+        has_one = mint
+    )]
+    pub auction_pda: Box<Account<'info, Auction>>,
+    // Sale phase PDA (may not exist if no presale has been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's SalePhase PDA
+    #[account(
+        seeds = [SALE_PHASE_SEED],
+        bump
+    )]
+    pub sale_phase: UncheckedAccount<'info>,
+    // Reservation PDA (only exists while the nft is held for a buyer)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Reservation PDA
+    #[account(
+        mut,
+        seeds = [RESERVATION_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub reservation: UncheckedAccount<'info>,
+    // project_account (sol account of the conservation project)
+    /// CHECK: This is safe because I verify that it is the conservation address stored on the nft_pda
+    #[account(mut, constraint = project_account.key() == authority_account.project_address()?)]
+    pub project_account: UncheckedAccount<'info>,
+    // Treasury account
+    #[account(
+        init_if_needed,
+        space = 200,
+        payer = payer,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // From account (token_account of the nft_pda)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = authority_account,
+    )]
+    pub from_account: Box<Account<'info, TokenAccount>>,
+    // To account (ATA of the buyer. May need to initialise it)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = payer
+    )]
+    pub to_account: Box<Account<'info, TokenAccount>>,
+    // Authority account (nft_pda)
+    #[account(
+        mut,
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump,
+        constraint = authority_account.on_auction @ InvalidAuctionError::NftNotOnAuction
+    )]
+    pub authority_account: Box<Account<'info, NftPDA>>,
+    // Mint of the nft - the nft pda seeds constraint ensures it was created by this program
+    pub mint: Box<Account<'info, Mint>>,
+    // Current program
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key() == crate::ID)]
+    pub current_program: UncheckedAccount<'info>,
+    // SPL Token program
+    pub token_program: Program<'info, Token>,
+    // Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Donor PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Leaderboard PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_SALES) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Purchase counter PDA of the buyer
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 100,
+        seeds = [PURCHASE_COUNTER_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub purchase_counter: Box<Account<'info, PurchaseCounter>>,
+    // Escrow PDA (only exists for nfts whose project share is held until milestones are met)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Escrow PDA
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub escrow: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // User
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaymentMint<'info> {
+    // Payment mint PDA - one per whitelisted SPL mint
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [PAYMENT_MINT_SEED, payment_mint.key().as_ref()],
+        bump
+    )]
+    pub payment_mint_pda: Box<Account<'info, PaymentMint>>,
+    // SPL mint being whitelisted (e.g. USDC)
+    pub payment_mint: Box<Account<'info, Mint>>,
+    // Treasury account
+    #[account(
+        init_if_needed,
+        space = 200,
+        payer = payer,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Treasury vault (ATA of the treasury PDA for the payment mint)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury_account
+    )]
+    pub treasury_vault: Box<Account<'info, TokenAccount>>,
+    // SPL Token program
+    pub token_program: Program<'info, Token>,
+    // Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can whitelist payment mints
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct TransferNftWithToken<'info> {
+    // Payment mint PDA (validates that the payment mint is whitelisted)
+    #[account(
+        mut,
+        seeds = [PAYMENT_MINT_SEED, payment_mint.key().as_ref()],
+        bump,
+        constraint = payment_mint_pda.enabled @ InvalidPaymentError::PaymentMintDisabled,
+        constraint = payment_mint_pda.mint == payment_mint.key()
+    )]
+    pub payment_mint_pda: Box<Account<'info, PaymentMint>>,
+    // SPL mint the buyer is paying with
+    pub payment_mint: Box<Account<'info, Mint>>,
+    // Token account of the buyer for the payment mint
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = payer
+    )]
+    pub payer_token_account: Box<Account<'info, TokenAccount>>,
+    // project_account (wallet of the conservation project)
+    /// CHECK: This is safe because I verify that it is the conservation address stored on the nft_pda
+    #[account(constraint = project_account.key() == authority_account.project_address()?)]
+    pub project_account: UncheckedAccount<'info>,
+    // Token account of the conservation project for the payment mint. May need to initialise it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = payment_mint,
+        associated_token::authority = project_account
+    )]
+    pub project_token_account: Box<Account<'info, TokenAccount>>,
+    // Treasury account
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Treasury vault (ATA of the treasury PDA for the payment mint)
+    #[account(
+        mut,
+        address = payment_mint_pda.vault,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury_account
+    )]
+    pub treasury_vault: Box<Account<'info, TokenAccount>>,
+    // From account (token_account of the nft_pda)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = authority_account,
+    )]
+    pub from_account: Box<Account<'info, TokenAccount>>,
+    // To account (ATA of the buyer. May need to initialise it)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = payer
+    )]
+    pub to_account: Box<Account<'info, TokenAccount>>,
+    // Authority account (nft_pda)
+    #[account(
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump,
+        constraint = !authority_account.on_auction @ InvalidAuctionError::NftOnAuction
+    )]
+    pub authority_account: Box<Account<'info, NftPDA>>,
+    // Mint of the nft - the nft pda seeds constraint ensures it was created by this program
+    pub mint: Box<Account<'info, Mint>>,
+    // Current program
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key() == crate::ID)]
+    pub current_program: UncheckedAccount<'info>,
+    // SPL Token program
+    pub token_program: Program<'info, Token>,
+    // Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Donor PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Leaderboard PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    // Sale phase PDA (may not exist if no presale has been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's SalePhase PDA
+    #[account(
+        seeds = [SALE_PHASE_SEED],
+        bump
+    )]
+    pub sale_phase: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_SALES) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Purchase counter PDA of the buyer
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 100,
+        seeds = [PURCHASE_COUNTER_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub purchase_counter: Box<Account<'info, PurchaseCounter>>,
+    // Reservation PDA (may not exist if the nft has never been reserved)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Reservation PDA
+    #[account(
+        mut,
+        seeds = [RESERVATION_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub reservation: UncheckedAccount<'info>,
+    // Escrow PDA (only exists for nfts whose project share is held until milestones are met)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Escrow PDA
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub escrow: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // User
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterProject<'info> {
+    // Project PDA
+    #[account(
+        init,
+        payer = payer,
+        space = 200,
+        seeds = [PROJECT_SEED, project_account.key().as_ref()],
+        bump
+    )]
+    pub project_pda: Box<Account<'info, ConservationProject>>,
+    // Wallet of the conservation project
+    pub project_account: SystemAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can register conservation projects
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Donate<'info> {
+    // Donor PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Project PDA (validates that the project is registered, only needed when donating to a project)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the project's ConservationProject PDA, which is checked whenever the donation has a project share
+    #[account(
+        seeds = [PROJECT_SEED, project_account.key().as_ref()],
+        bump
+    )]
+    pub project_pda: UncheckedAccount<'info>,
+    // Wallet of the conservation project (any system account for treasury-only donations)
+    #[account(mut)]
+    pub project_account: SystemAccount<'info>,
+    // Treasury account
+    #[account(
+        init_if_needed,
+        space = 200,
+        payer = payer,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Leaderboard PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_DONATIONS) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Donor
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DonateToken<'info> {
+    // Donor PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Token donor PDA - the donor's running total in the payment mint
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TOKEN_DONOR_SPACE,
+        seeds = [TOKEN_DONOR_SEED, payer.key().as_ref(), payment_mint.key().as_ref()],
+        bump
+    )]
+    pub token_donor_pda: Box<Account<'info, TokenDonor>>,
+    // Project PDA (validates that the project is registered, only needed when donating to a project)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the project's ConservationProject PDA, which is checked whenever the donation has a project share
+    #[account(
+        seeds = [PROJECT_SEED, project_account.key().as_ref()],
+        bump
+    )]
+    pub project_pda: UncheckedAccount<'info>,
+    // Wallet of the conservation project (any system account for treasury-only donations)
+    pub project_account: SystemAccount<'info>,
+    // Payment mint PDA (validates that the payment mint is whitelisted)
+    #[account(
+        mut,
+        seeds = [PAYMENT_MINT_SEED, payment_mint.key().as_ref()],
+        bump,
+        constraint = payment_mint_pda.enabled @ InvalidPaymentError::PaymentMintDisabled,
+        constraint = payment_mint_pda.mint == payment_mint.key()
+    )]
+    pub payment_mint_pda: Box<Account<'info, PaymentMint>>,
+    // SPL mint the donor is paying with
+    pub payment_mint: Box<Account<'info, Mint>>,
+    // Token account of the donor for the payment mint
+    #[account(
+        mut,
+        token::mint = payment_mint,
+        token::authority = payer
+    )]
+    pub payer_token_account: Box<Account<'info, TokenAccount>>,
+    // Token account of the conservation project for the payment mint. Created when the donation has a project share
+    /// CHECK: This is safe because the associated token program only creates or accepts the project's ATA for the payment mint
+    #[account(mut)]
+    pub project_token_account: UncheckedAccount<'info>,
+    // Treasury account
+    #[account(
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Treasury vault (ATA of the treasury PDA for the payment mint)
+    #[account(
+        mut,
+        address = payment_mint_pda.vault,
+        associated_token::mint = payment_mint,
+        associated_token::authority = treasury_account
+    )]
+    pub treasury_vault: Box<Account<'info, TokenAccount>>,
+    // Leaderboard PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    // SPL Token program
+    pub token_program: Program<'info, Token>,
+    // Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_DONATIONS) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Donor
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct SetBadgeTier<'info> {
+    // Badge tier PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 300,
+        seeds = [BADGE_TIER_SEED, &[tier]],
+        bump
+    )]
+    pub badge_tier_pda: Box<Account<'info, BadgeTier>>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can configure badge tiers
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(tier: u8)]
+pub struct ClaimBadge<'info> {
+    // Badge tier PDA
+    #[account(
+        seeds = [BADGE_TIER_SEED, &[tier]],
+        bump
+    )]
+    pub badge_tier_pda: Box<Account<'info, BadgeTier>>,
+    // Donor PDA - the donor must have crossed the tier's threshold
+    #[account(
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump,
+        constraint = donor_pda.lamports_given >= badge_tier_pda.threshold @ InvalidBadgeError::ThresholdNotReached
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Badge mint - one per wallet per tier
+    #[account(
+        init,
+        payer = payer,
+        seeds = [BADGE_MINT_SEED, &[tier], payer.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority
+    )]
+    pub badge_mint: Box<Account<'info, Mint>>,
+    // Token account of the donor for the badge
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = badge_mint,
+        associated_token::authority = payer
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+    // Mint authority
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    /// CHECK: Using "address" constraint to validate metadata account address
+    #[account(
+        mut,
+        address=find_metadata_account(&badge_mint.key()).0
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    // Token Metadata program
+    pub token_metadata_program: Program<'info, TokenMetaData>,
+    // SPL Token program
+    pub token_program: Program<'info, Token>,
+    // Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_MINTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Donor
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64)]
+pub struct SetVoteReceipt<'info> {
+    // Vote receipt config PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = VOTE_RECEIPT_CONFIG_SPACE,
+        seeds = [VOTE_RECEIPT_CONFIG_SEED, election_pda.key().as_ref()],
+        bump
+    )]
+    pub receipt_config: Box<Account<'info, VoteReceiptConfig>>,
+    // Election PDA
+    /// CHECK: This is safe because the seeds and owner constraints ensure it is the election's pda
+    #[account(
+        seeds = [ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        bump,
+        owner = crate::ID
+    )]
+    pub election_pda: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can configure vote receipts
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64)]
+pub struct ClaimVoteReceipt<'info> {
+    // Vote receipt config PDA - exists once the receipt of the election has been set
+    #[account(
+        mut,
+        seeds = [VOTE_RECEIPT_CONFIG_SEED, election_pda.key().as_ref()],
+        bump
+    )]
+    pub receipt_config: Box<Account<'info, VoteReceiptConfig>>,
+    // Vote PDA - only the wallet that cast the vote can claim its receipt
+    #[account(
+        seeds = [vote_pda.mint.as_ref(), election_pda.key().as_ref()],
+        bump,
+        constraint = vote_pda.voter == payer.key() @ InvalidVoteReceiptError::NotVoter
+    )]
+    pub vote_pda: Box<Account<'info, Vote>>,
+    // Election PDA (only its address is used)
+    /// CHECK: This is safe because the seeds constraint ensures it is the election's address
+    #[account(
+        seeds = [ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        bump
+    )]
+    pub election_pda: UncheckedAccount<'info>,
+    // Receipt mint - one per vote pda, so each (mint, election) vote can only be claimed once
+    #[account(
+        init,
+        payer = payer,
+        seeds = [VOTE_RECEIPT_SEED, vote_pda.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority
+    )]
+    pub receipt_mint: Box<Account<'info, Mint>>,
+    // Token account of the voter for the receipt
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = payer
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+    // Mint authority
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    /// CHECK: Using "address" constraint to validate metadata account address
+    #[account(
+        mut,
+        address=find_metadata_account(&receipt_mint.key()).0
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    // Master edition of the receipt - makes the receipt a one of one nft
+    /// CHECK: Using "address" constraint to validate master edition account address
+    #[account(
+        mut,
+        address=find_master_edition_account(&receipt_mint.key()).0
+    )]
+    pub master_edition: UncheckedAccount<'info>,
+    // Token Metadata program
+    pub token_metadata_program: Program<'info, TokenMetaData>,
+    // SPL Token program
+    pub token_program: Program<'info, Token>,
+    // Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_MINTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Voter
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetSalePhase<'info> {
+    // Sale phase PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [SALE_PHASE_SEED],
+        bump
+    )]
+    pub sale_phase: Box<Account<'info, SalePhase>>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can configure sale phases
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PresaleTransferNft<'info> {
+    // Sale phase PDA
+    #[account(
+        seeds = [SALE_PHASE_SEED],
+        bump
+    )]
+    pub sale_phase: Box<Account<'info, SalePhase>>,
+    // Presale purchases PDA of the buyer
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 100,
+        seeds = [
+            PRESALE_SEED,
+            payer.key().as_ref(),
+            sale_phase.presale_start.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub presale_purchases: Box<Account<'info, PresalePurchases>>,
+    // project_account (sol account of the conservation project)
+    /// CHECK: This is safe because I verify that it is the conservation address stored on the nft_pda
+    #[account(mut, constraint = project_account.key() == authority_account.project_address()?)]
+    pub project_account: UncheckedAccount<'info>,
+    // Treasury account
+    #[account(
+        init_if_needed,
+        space = 200,
+        payer = payer,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // From account (token_account of the nft_pda)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = authority_account,
+    )]
+    pub from_account: Box<Account<'info, TokenAccount>>,
+    // To account (ATA of the buyer. May need to initialise it)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = payer
+    )]
+    pub to_account: Box<Account<'info, TokenAccount>>,
+    // Authority account (nft_pda)
+    #[account(
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump,
+        constraint = !authority_account.on_auction @ InvalidAuctionError::NftOnAuction
+    )]
+    pub authority_account: Box<Account<'info, NftPDA>>,
+    // Mint of the nft - the nft pda seeds constraint ensures it was created by this program
+    pub mint: Box<Account<'info, Mint>>,
+    // Current program
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key() == crate::ID)]
+    pub current_program: UncheckedAccount<'info>,
+    // SPL Token program
+    pub token_program: Program<'info, Token>,
+    // Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // Donor PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Leaderboard PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_SALES) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Purchase counter PDA of the buyer
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 100,
+        seeds = [PURCHASE_COUNTER_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub purchase_counter: Box<Account<'info, PurchaseCounter>>,
+    // Reservation PDA (may not exist if the nft has never been reserved)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Reservation PDA
+    #[account(
+        mut,
+        seeds = [RESERVATION_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub reservation: UncheckedAccount<'info>,
+    // Escrow PDA (only exists for nfts whose project share is held until milestones are met)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Escrow PDA
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub escrow: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // User
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPurchaseLimits<'info> {
+    // Program config PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can change the program config
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetReservationTerms<'info> {
+    // Program config PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can change the program config
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReserveNft<'info> {
+    // Reservation PDA - only one hold can exist per nft at a time
+    #[account(
+        init,
+        payer = payer,
+        space = 100,
+        seeds = [RESERVATION_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub reservation: Box<Account<'info, Reservation>>,
+    // Reservation cooldown PDA - stops the buyer from holding the nft again straight after their hold
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RESERVATION_COOLDOWN_SPACE,
+        seeds = [RESERVATION_COOLDOWN_SEED, mint.key().as_ref(), payer.key().as_ref()],
+        bump
+    )]
+    pub cooldown: Box<Account<'info, ReservationCooldown>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_SALES) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // NFT PDA (current owner of the nft)
+    #[account(
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump,
+        constraint = !nft_pda.on_auction @ InvalidAuctionError::NftOnAuction
+    )]
+    pub nft_pda: Box<Account<'info, NftPDA>>,
+    // Token account of the nft_pda - the nft must still be unsold
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = nft_pda,
+        constraint = token_account.amount == 1 @ InvalidReservationError::NftSold
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+    // Mint of the nft - the nft pda seeds constraint ensures it was created by this program
+    pub mint: Box<Account<'info, Mint>>,
+    // Current program
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key() == crate::ID)]
+    pub current_program: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Buyer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReleaseReservation<'info> {
+    // Reservation PDA
+    #[account(
+        mut,
+        seeds = [RESERVATION_SEED, reservation.mint.as_ref()],
+        bump,
+        has_one = buyer,
+        close = buyer
+    )]
+    pub reservation: Box<Account<'info, Reservation>>,
+    // Buyer that made the reservation - receives the rent and the deposit
+    #[account(mut)]
+    pub buyer: SystemAccount<'info>,
+    // Anyone can release an expired reservation
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64)]
+pub struct FinalizeElection<'info> {
+    // Election PDA
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        bump
+    )]
+    pub election_pda: Box<Account<'info, Election>>,
+    // Archive PDA - summary of the results that outlives the election pda
+    #[account(
+        init,
+        payer = payer,
+        space = 250,
+        seeds = [ARCHIVE_SEED, election_pda.key().as_ref()],
+        bump
+    )]
+    pub archive: Box<Account<'info, ElectionArchive>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_VOTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can finalize elections
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64)]
+pub struct CloseVote<'info> {
+    // Vote PDA
+    #[account(
+        mut,
+        seeds = [vote_pda.mint.as_ref(), election_pda.key().as_ref()],
+        bump,
+        has_one = voter,
+        close = voter
+    )]
+    pub vote_pda: Box<Account<'info, Vote>>,
+    // Election PDA (may already have been closed - only its address is used)
+    /// CHECK: This is safe because the seeds constraint ensures it is the election's address
+    #[account(
+        seeds = [ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        bump
+    )]
+    pub election_pda: UncheckedAccount<'info>,
+    // Archive PDA - exists once the election has been finalized
+    #[account(
+        seeds = [ARCHIVE_SEED, election_pda.key().as_ref()],
+        bump
+    )]
+    pub archive: Box<Account<'info, ElectionArchive>>,
+    // Voter - receives the rent of the vote pda
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64)]
+pub struct CloseElection<'info> {
+    // Election PDA
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        bump,
+        close = payer
+    )]
+    pub election_pda: Box<Account<'info, Election>>,
+    // Payer - Security - only the trusted address creates elections, so it receives the rent
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    // Program config PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can appoint the guardian
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    // Program config PDA
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Box<Account<'info, ProgramConfig>>,
+    // Security - only the trusted address or the guardian can pause and unpause the program
+    #[account(
+        constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"
+            || payer.key() == config.guardian
+            @ InvalidConfigError::Unauthorized
+    )]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateNftPda<'info> {
+    // NFT PDA - grown to NFT_PDA_SPACE if it was created with less
+    #[account(
+        mut,
+        seeds = [NFT_PDA_SEED, nft_pda.mint.as_ref()],
+        bump,
+        realloc = NFT_PDA_SPACE,
+        realloc::payer = payer,
+        realloc::zero = false
+    )]
+    pub nft_pda: Box<Account<'info, NftPDA>>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can migrate accounts
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64)]
+pub struct MigrateElection<'info> {
+    // Election PDA - grown to ELECTION_SPACE if it was created with less. Taken unchecked because
+    // v0 elections may be too short to deserialize as the current layout
+    /// CHECK: This is safe because the seeds and owner constraints ensure it can only be the program's Election PDA, and read_election checks its discriminator
+    #[account(
+        mut,
+        seeds = [ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        bump,
+        owner = crate::ID
+    )]
+    pub election_pda: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can migrate accounts
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct MigrateNftName<'info> {
+    // Name record PDA - can only be created once per normalised name
+    #[account(
+        init,
+        payer = payer,
+        space = NAME_RECORD_SPACE,
+        seeds = [NAME_RECORD_SEED, name_hash(&name).as_ref()],
+        bump
+    )]
+    pub name_record: Box<Account<'info, NameRecord>>,
+    // Mint of an nft minted before mints were seeded by sequential ids
+    /// CHECK: This is safe because the seeds constraint ensures it is the name seeded mint pda for this name
+    #[account(
+        seeds = [name.as_bytes(), crate::ID.as_ref()],
+        bump
+    )]
+    pub mint: UncheckedAccount<'info>,
+    // NFT PDA - ensures the nft was minted and hasn't been deleted
+    /// CHECK: This is safe because the seeds and owner constraints ensure it is the nft pda of this mint
+    #[account(
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump,
+        owner = crate::ID
+    )]
+    pub nft_pda: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can migrate accounts
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+// Names that only differ in case or spacing share a name record, so they can't be renamed to each other
+#[derive(Accounts)]
+#[instruction(new_name: String)]
+pub struct RenameNft<'info> {
+    // Name record PDA of the current name - closed, its rent goes back to the payer
+    #[account(
+        mut,
+        seeds = [NAME_RECORD_SEED, name_hash(&name_record.name).as_ref()],
+        bump,
+        has_one = mint,
+        close = payer
+    )]
+    pub name_record: Box<Account<'info, NameRecord>>,
+    // Name record PDA of the new name
+    #[account(
+        init,
+        payer = payer,
+        space = NAME_RECORD_SPACE,
+        seeds = [NAME_RECORD_SEED, name_hash(&new_name).as_ref()],
+        bump
+    )]
+    pub new_name_record: Box<Account<'info, NameRecord>>,
+    // Mint of the nft - SPL Token only, Token-2022 nfts keep their name in the mint's metadata extension
+    pub mint: Box<Account<'info, Mint>>,
+    // Metadata of the nft
+    /// CHECK: Using "address" constraint to validate metadata account address
+    #[account(
+        mut,
+        address = find_metadata_account(&mint.key()).0
+    )]
+    pub metadata: UncheckedAccount<'info>,
+    // Mint authority PDA - the update authority of the metadata
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    pub token_metadata_program: Program<'info, TokenMetaData>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can rename nfts
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(reporter: Pubkey)]
+pub struct SetFieldReporter<'info> {
+    // Field reporter PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 150,
+        seeds = [FIELD_REPORTER_SEED, project_account.key().as_ref(), reporter.as_ref()],
+        bump
+    )]
+    pub reporter_pda: Box<Account<'info, FieldReporter>>,
+    // Project PDA (validates that the project is registered)
+    #[account(
+        seeds = [PROJECT_SEED, project_account.key().as_ref()],
+        bump
+    )]
+    pub project_pda: Box<Account<'info, ConservationProject>>,
+    // Wallet of the conservation project
+    pub project_account: SystemAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can accredit field reporters
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PostStatusUpdate<'info> {
+    // Status log PDA of the nft - only its header is deserialized, updates are appended as raw bytes
+    #[account(
+        init_if_needed,
+        payer = reporter,
+        space = STATUS_LOG_SPACE,
+        seeds = [STATUS_LOG_SEED, nft_pda.mint.as_ref()],
+        bump
+    )]
+    pub status_log: Box<Account<'info, StatusLog>>,
+    // NFT PDA of the nft being reported on
+    #[account(
+        seeds = [NFT_PDA_SEED, nft_pda.mint.as_ref()],
+        bump
+    )]
+    pub nft_pda: Box<Account<'info, NftPDA>>,
+    // Field reporter PDA - the reporter must be accredited for the nft's project
+    #[account(
+        seeds = [FIELD_REPORTER_SEED, nft_pda.project_address()?.as_ref(), reporter.key().as_ref()],
+        bump,
+        constraint = reporter_pda.active @ InvalidStatusError::ReporterInactive
+    )]
+    pub reporter_pda: Box<Account<'info, FieldReporter>>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Field reporter - pays for the growth of the status log
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateEscrow<'info> {
+    // Escrow PDA
+    #[account(
+        init,
+        payer = payer,
+        space = ESCROW_SPACE,
+        seeds = [ESCROW_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    // NFT PDA (current owner of the nft)
+    #[account(
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub nft_pda: Box<Account<'info, NftPDA>>,
+    // Token account of the nft_pda - the nft must still be unsold
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = nft_pda,
+        constraint = token_account.amount == 1 @ InvalidReservationError::NftSold
+    )]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+    // Mint of the nft - the nft pda seeds constraint ensures it was created by this program
+    pub mint: Box<Account<'info, Mint>>,
+    // Current program
+    /// CHECK: This is safe because I verify that the account passed in has the right public key
+    #[account(constraint = current_program.key() == crate::ID)]
+    pub current_program: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can set up escrows
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct PostMilestoneEvidence<'info> {
+    // Escrow PDA
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, escrow.mint.as_ref()],
+        bump,
+        has_one = project
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    // Wallet of the conservation project
+    pub project: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AttestMilestone<'info> {
+    // Escrow PDA
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, escrow.mint.as_ref()],
+        bump
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    // Wallet of the conservation project - receives the released funds
+    #[account(mut, address = escrow.project)]
+    pub project_account: SystemAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_TREASURY) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Security - only the trusted address or the escrow's verifier can attest milestones
+    #[account(
+        constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"
+            || payer.key() == escrow.verifier
+            @ InvalidConfigError::Unauthorized
+    )]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RefundMilestone<'info> {
+    // Escrow PDA
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, escrow.mint.as_ref()],
+        bump
+    )]
+    pub escrow: Box<Account<'info, Escrow>>,
+    // Treasury account - receives the share of missed milestones
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_TREASURY) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Anyone can refund a missed milestone
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    // Treasury account
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Outflow ledger of the recipient
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TREASURY_RECIPIENT_SPACE,
+        seeds = [TREASURY_RECIPIENT_SEED, recipient.key().as_ref()],
+        bump
+    )]
+    pub recipient_ledger: Box<Account<'info, TreasuryRecipient>>,
+    // Wallet receiving the withdrawal
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_TREASURY) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can withdraw from the treasury
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct OpenGrantStream<'info> {
+    // Grant stream PDA
+    #[account(
+        init,
+        payer = payer,
+        space = GRANT_STREAM_SPACE,
+        seeds = [GRANT_STREAM_SEED, archive.key().as_ref()],
+        bump
+    )]
+    pub grant_stream: Box<Account<'info, GrantStream>>,
+    // Archive of the finalized election whose winner receives the grant
+    #[account(
+        seeds = [ARCHIVE_SEED, archive.election.as_ref()],
+        bump
+    )]
+    pub archive: Box<Account<'info, ElectionArchive>>,
+    // Treasury account - funds the stream
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_TREASURY) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can open grant streams
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimGrant<'info> {
+    // Grant stream PDA
+    #[account(
+        mut,
+        seeds = [GRANT_STREAM_SEED, archive.key().as_ref()],
+        bump,
+        has_one = recipient,
+        has_one = archive
+    )]
+    pub grant_stream: Box<Account<'info, GrantStream>>,
+    // Archive of the election the grant was opened for
+    #[account(mut)]
+    pub archive: Box<Account<'info, ElectionArchive>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_TREASURY) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Winning organisation
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64)]
+pub struct ProposeStreamCancellation<'info> {
+    // Stream cancellation PDA - holds the tally of the vote
+    #[account(
+        init,
+        payer = payer,
+        space = STREAM_CANCELLATION_SPACE,
+        seeds = [STREAM_CANCELLATION_SEED, grant_stream.key().as_ref(), election_pda.key().as_ref()],
+        bump
+    )]
+    pub cancellation: Box<Account<'info, StreamCancellation>>,
+    // Grant stream PDA put to the vote
+    #[account(
+        seeds = [GRANT_STREAM_SEED, archive.key().as_ref()],
+        bump,
+        has_one = archive
+    )]
+    pub grant_stream: Box<Account<'info, GrantStream>>,
+    // Archive of the election the grant was opened for
+    pub archive: Box<Account<'info, ElectionArchive>>,
+    // Election PDA of the later election whose voters decide on the cancellation
+    #[account(
+        seeds = [ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        bump
+    )]
+    pub election_pda: Box<Account<'info, Election>>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can put a cancellation to the vote
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(date_of_election: i64)]
+pub struct VoteOnCancellation<'info> {
+    // Stream cancellation PDA being voted on
+    #[account(
+        mut,
+        seeds = [STREAM_CANCELLATION_SEED, cancellation.grant_stream.as_ref(), election_pda.key().as_ref()],
+        bump
+    )]
+    pub cancellation: Box<Account<'info, StreamCancellation>>,
+    // Election PDA the cancellation is voted on in
+    #[account(
+        seeds = [ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        bump
+    )]
+    pub election_pda: Box<Account<'info, Election>>,
+    // Vote PDA - each nft that voted in the election gets one ballot
     #[account(
-        mut,
-        seeds = [&name.as_bytes().as_ref(), current_program.key.as_ref()],
+        seeds = [vote_pda.mint.as_ref(), election_pda.key().as_ref()],
         bump,
-        mint::decimals = 0,
-        mint::authority = mint_authority,
-        mint::freeze_authority = mint_authority,
+        constraint = vote_pda.voter == payer.key() @ InvalidGrantError::NotVoter
     )]
-    pub mint: Account<'info, Mint>,
-
-    // `[writable]` NFT token account
+    pub vote_pda: Box<Account<'info, Vote>>,
+    // Ballot PDA - its creation stops a vote pda from voting twice
     #[account(
-        mut,
-        associated_token::mint = mint, // mint of the token
-        associated_token::authority = nft_pda, // authority that should be a PDA account
+        init,
+        payer = payer,
+        space = CANCELLATION_BALLOT_SPACE,
+        seeds = [CANCELLATION_BALLOT_SEED, cancellation.key().as_ref(), vote_pda.key().as_ref()],
+        bump
     )]
-    pub token_account: Account<'info, TokenAccount>,
-
-    // `[writable]` NFT edition account
-    // Need to implement manual validation for this
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    #[account(mut)]
-    pub master_edition: UncheckedAccount<'info>,
-
-    // `[]` SPL Token program
-    pub token_program: Program<'info, Token>,
-    // Optional `[writable]` Collection metadata account
-    // Not included yet - need to implement collections first
-
-    // Token Metadata program
-    pub token_metadata_program: Program<'info, TokenMetaData>,
-
-    // Mint authority
-    // It seems that after the creation of the master edition, the master edition becomes the mint authority
-    // Need to implement manual validation for this
-    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub ballot: Box<Account<'info, CancellationBallot>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_VOTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Voter
     #[account(mut)]
-    pub mint_authority: UncheckedAccount<'info>,
+    pub payer: Signer<'info>,
+}
 
-    //Current program
-    /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
-    pub current_program: UncheckedAccount<'info>,
+#[derive(Accounts)]
+pub struct CancelGrantStream<'info> {
+    // Grant stream PDA
+    #[account(
+        mut,
+        seeds = [GRANT_STREAM_SEED, archive.key().as_ref()],
+        bump,
+        has_one = archive
+    )]
+    pub grant_stream: Box<Account<'info, GrantStream>>,
+    // Archive of the election the grant was opened for
+    pub archive: Box<Account<'info, ElectionArchive>>,
+    // Archive of the later election whose vote decided to cancel the stream
+    #[account(
+        seeds = [ARCHIVE_SEED, later_archive.election.as_ref()],
+        bump
+    )]
+    pub later_archive: Box<Account<'info, ElectionArchive>>,
+    // Cancellation put to the vote in the later election
+    #[account(
+        seeds = [STREAM_CANCELLATION_SEED, grant_stream.key().as_ref(), later_archive.election.as_ref()],
+        bump
+    )]
+    pub cancellation: Box<Account<'info, StreamCancellation>>,
+    // Treasury account - receives the unvested remainder
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Anyone can carry out a cancellation the later election voted for
+    pub payer: Signer<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(name: String)]
-pub struct TransferNft<'info> {
+#[instruction(edition_number: u64)]
+pub struct MintEdition<'info> {
+    // Print run PDA of the master edition
+    #[account(
+        mut,
+        seeds = [PRINT_RUN_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub print_run: Box<Account<'info, PrintRun>>,
+    // NFT PDA (owner of the master edition token)
+    #[account(
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub nft_pda: Box<Account<'info, NftPDA>>,
+    // Token account of the nft_pda - prints can only be sold while the master edition is unsold
+    #[account(
+        associated_token::mint = mint,
+        associated_token::authority = nft_pda,
+        constraint = master_token_account.amount == 1 @ InvalidEditionError::MasterEditionSold
+    )]
+    pub master_token_account: Box<Account<'info, TokenAccount>>,
+    // Mint of the master edition - the nft pda seeds constraint ensures it was created by this program
+    pub mint: Box<Account<'info, Mint>>,
+    // Metadata of the master edition
+    /// CHECK: Using "address" constraint to validate metadata account address
+    #[account(address = find_metadata_account(&mint.key()).0)]
+    pub metadata: UncheckedAccount<'info>,
+    // Master edition account
+    /// CHECK: Using "address" constraint to validate master edition account address
+    #[account(mut, address = find_master_edition_account(&mint.key()).0)]
+    pub master_edition: UncheckedAccount<'info>,
+    // Mint of the print
+    #[account(
+        init,
+        payer = payer,
+        seeds = [EDITION_MINT_SEED, mint.key().as_ref(), edition_number.to_le_bytes().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = mint_authority,
+        mint::freeze_authority = mint_authority,
+    )]
+    pub edition_mint: Box<Account<'info, Mint>>,
+    // Metadata of the print - created by metaplex
+    /// CHECK: Using "address" constraint to validate metadata account address
+    #[account(mut, address = find_metadata_account(&edition_mint.key()).0)]
+    pub edition_metadata: UncheckedAccount<'info>,
+    // Edition account of the print - created by metaplex
+    /// CHECK: Using "address" constraint to validate edition account address
+    #[account(mut, address = find_master_edition_account(&edition_mint.key()).0)]
+    pub edition: UncheckedAccount<'info>,
+    // Edition marker - bitmask metaplex uses to track which edition numbers have been printed
+    /// CHECK: Using "address" constraint to validate edition marker account address
+    #[account(
+        mut,
+        address = find_edition_account(&mint.key(), (edition_number / EDITION_MARKER_BIT_SIZE).to_string()).0
+    )]
+    pub edition_marker: UncheckedAccount<'info>,
+    // To account (ATA of the buyer for the print)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = edition_mint,
+        associated_token::authority = payer
+    )]
+    pub to_account: Box<Account<'info, TokenAccount>>,
+    // Mint authority PDA - update authority of the master edition's metadata
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
     // project_account (sol account of the conservation project)
-    /// CHECK - this is dangerous - need to implement PDAs to hold information about verified conservation project addresses
-    #[account(mut)]
+    /// CHECK: This is safe because I verify that it is the conservation address stored on the nft_pda
+    #[account(mut, constraint = project_account.key() == nft_pda.project_address()?)]
     pub project_account: UncheckedAccount<'info>,
     // Treasury account
     #[account(
         init_if_needed,
         space = 200,
         payer = payer,
-        seeds = [b"treasury".as_ref()],
+        seeds = [TREASURY_SEED],
         bump,
-        owner = current_program.key()
     )]
-    pub treasury_account: Account<'info, Treasury>,
-    // From account (token_account of the nft_pda)
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Escrow PDA (only exists for nfts whose project share is held until milestones are met)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the nft's Escrow PDA
     #[account(
         mut,
-        associated_token::mint = mint, // mint of the token
-        associated_token::authority = authority_account, // authority that should be a PDA account
+        seeds = [ESCROW_SEED, mint.key().as_ref()],
+        bump
     )]
-    pub from_account: Account<'info, TokenAccount>,
-    // To account (ATA of the wallet we are transferring to. May need to initialise it)
+    pub escrow: UncheckedAccount<'info>,
+    // Donor PDA
     #[account(
         init_if_needed,
         payer = payer,
-        associated_token::mint = mint,
-        associated_token::authority = payer
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
     )]
-    pub to_account: Account<'info, TokenAccount>,
-    // Authority account (nft_pda)
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Leaderboard PDA
     #[account(
-        mut,
-        seeds = [b"nftPDA".as_ref(), mint.key().as_ref()],
+        init_if_needed,
+        payer = payer,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED],
         bump
     )]
-    pub authority_account: Account<'info, NftPDA>,
-    // Mint of the nft (used for validating accounts above)
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    // Sale phase PDA (may not exist if no presale has been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's SalePhase PDA
     #[account(
-        mut,
-        seeds = [&name.as_bytes().as_ref(), current_program.key.as_ref()],
+        seeds = [SALE_PHASE_SEED],
+        bump
+    )]
+    pub sale_phase: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
         bump,
-        mint::decimals = 0,
-        mint::authority = mint_authority,
-        mint::freeze_authority = mint_authority,
+        constraint = !load_config(&config)?.is_paused(PAUSE_SALES) @ InvalidConfigError::ProgramPaused
     )]
-    pub mint: Account<'info, Mint>,
-    // Mint authority
-    // It seems that after the creation of the master edition, the master edition becomes the mint authority
-    // Need to implement validation for this
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    #[account(mut)]
-    pub mint_authority: UncheckedAccount<'info>,
+    pub config: UncheckedAccount<'info>,
+    // Purchase counter PDA of the buyer
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 100,
+        seeds = [PURCHASE_COUNTER_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub purchase_counter: Box<Account<'info, PurchaseCounter>>,
     // Current program
     /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
+    #[account(constraint = current_program.key() == crate::ID)]
     pub current_program: UncheckedAccount<'info>,
     // SPL Token program
     pub token_program: Program<'info, Token>,
     // Associated Token Program
     pub associated_token_program: Program<'info, AssociatedToken>,
+    // Token Metadata program
+    pub token_metadata_program: Program<'info, TokenMetaData>,
     // System program
     pub system_program: Program<'info, System>,
-    // User
+    // Buyer
     #[account(mut)]
     pub payer: Signer<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(date_of_election: i64)]
-pub struct CreateElection<'info> {
-    // Election PDA
+#[instruction(name: String)]
+pub struct CreateMint2022<'info> {
+    // NFT counter PDA - the id of the next nft seeds its mint
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = NFT_COUNTER_SPACE,
+        seeds = [NFT_COUNTER_SEED],
+        bump
+    )]
+    pub nft_counter: Box<Account<'info, NftCounter>>,
+    // Mint of the nft - created as a Token-2022 mint in the instruction
+    /// CHECK: This is safe because the seeds constraint ensures it is the mint pda for the next nft id
+    #[account(
+        mut,
+        seeds = [NFT_MINT_SEED, nft_counter.next_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub mint: UncheckedAccount<'info>,
+    // Mint authority PDA - also the update authority of the token metadata
+    #[account(
+        seeds = [MINT_AUTHORITY_SEED],
+        bump,
+    )]
+    pub mint_authority: Box<Account<'info, MintAuthority>>,
+    // NFT PDA (owner of the nft)
     #[account(
         init,
         payer = payer,
-        space = 300,
-        seeds = [b"election".as_ref(), &date_of_election.to_string().as_bytes().as_ref()],
+        space = NFT_PDA_SPACE,
+        seeds = [NFT_PDA_SEED, mint.key().as_ref()],
         bump
     )]
-    pub election_pda: Box<Account<'info, Election>>,
-    // mauritius_wildlife_protection account
-    /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = mauritius_wildlife_protection.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
-    pub mauritius_wildlife_protection: SystemAccount<'info>,
-    // mauritius_nature_protection_society account
-    /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = mauritius_nature_protection_society.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
-    pub mauritius_nature_protection_society: SystemAccount<'info>,
-    // mauritius_marine_life_protection account
+    pub nft_pda: Box<Account<'info, NftPDA>>,
+    // Token-2022 ATA of the nft pda - created in the instruction
+    /// CHECK: Using "address" constraint to validate the associated token account address
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(&nft_pda.key(), &mint.key(), &spl_token_2022::ID)
+    )]
+    pub token_account: UncheckedAccount<'info>,
+    // Wallet of the conservation project
+    /// CHECK: This is dangerous - I need to think about how to make this safe
+    pub organisation_wallet: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_MINTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Name record PDA - can only be created once per normalised name, so nft names are unique
+    #[account(
+        init,
+        payer = payer,
+        space = NAME_RECORD_SPACE,
+        seeds = [NAME_RECORD_SEED, name_hash(&name).as_ref()],
+        bump
+    )]
+    pub name_record: Box<Account<'info, NameRecord>>,
+    // Current program
     /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = mauritius_marine_life_protection.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
-    pub mauritius_marine_life_protection: SystemAccount<'info>,
+    #[account(constraint = current_program.key() == crate::ID)]
+    pub current_program: UncheckedAccount<'info>,
+    // Token-2022 program
+    /// CHECK: Using "address" constraint to validate the token-2022 program id
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: UncheckedAccount<'info>,
+    // Associated Token Program
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can create NFTs
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CreateCompressedTree<'info> {
+    // Compressed tree PDA - creator of the merkle tree and owner of its unsold nfts
+    #[account(
+        init,
+        payer = payer,
+        space = COMPRESSED_TREE_SPACE,
+        seeds = [COMPRESSED_TREE_SEED, merkle_tree.key().as_ref()],
+        bump
+    )]
+    pub compressed_tree: Box<Account<'info, CompressedTree>>,
+    // Merkle tree - allocated by the client (owned by the compression program) in the same transaction
+    /// CHECK: This is safe because the compression program validates and initializes it
+    #[account(mut, owner = COMPRESSION_ID)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    // Bubblegum tree config - created by bubblegum
+    /// CHECK: Using "address" constraint to validate the bubblegum tree config address
+    #[account(
+        mut,
+        address = Pubkey::find_program_address(&[merkle_tree.key().as_ref()], &BUBBLEGUM_ID).0
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    // Project PDA (validates that the project is registered)
+    #[account(
+        seeds = [PROJECT_SEED, project_account.key().as_ref()],
+        bump,
+        constraint = project_pda.active @ InvalidProjectError::ProjectInactive
+    )]
+    pub project_pda: Box<Account<'info, ConservationProject>>,
+    // Wallet of the conservation project - receives its share of sales from the tree
+    pub project_account: SystemAccount<'info>,
+    // Bubblegum program
+    /// CHECK: Using "address" constraint to validate the bubblegum program id
+    #[account(address = BUBBLEGUM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    // Account compression program
+    /// CHECK: Using "address" constraint to validate the compression program id
+    #[account(address = COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    // Noop program - bubblegum logs the leaves through it for indexers
+    /// CHECK: Using "address" constraint to validate the noop program id
+    #[account(address = NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_MINTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can create compressed trees
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MintCompressedNft<'info> {
+    // Compressed tree PDA
+    #[account(
+        mut,
+        seeds = [COMPRESSED_TREE_SEED, merkle_tree.key().as_ref()],
+        bump
+    )]
+    pub compressed_tree: Box<Account<'info, CompressedTree>>,
+    // Merkle tree
+    /// CHECK: This is safe because the compressed tree seeds tie it to the tree created by this program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    // Bubblegum tree config
+    /// CHECK: Using "address" constraint to validate the bubblegum tree config address
+    #[account(
+        mut,
+        address = Pubkey::find_program_address(&[merkle_tree.key().as_ref()], &BUBBLEGUM_ID).0
+    )]
+    pub tree_authority: UncheckedAccount<'info>,
+    // Bubblegum program
+    /// CHECK: Using "address" constraint to validate the bubblegum program id
+    #[account(address = BUBBLEGUM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    // Account compression program
+    /// CHECK: Using "address" constraint to validate the compression program id
+    #[account(address = COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    // Noop program
+    /// CHECK: Using "address" constraint to validate the noop program id
+    #[account(address = NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_MINTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // System program
+    pub system_program: Program<'info, System>,
+    // Payer - Security - only the trusted address can mint compressed nfts
+    #[account(mut, constraint = payer.key.to_string() == "kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH")]
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BuyCompressedNft<'info> {
+    // Compressed tree PDA (current owner of the nft)
+    #[account(
+        mut,
+        seeds = [COMPRESSED_TREE_SEED, merkle_tree.key().as_ref()],
+        bump
+    )]
+    pub compressed_tree: Box<Account<'info, CompressedTree>>,
+    // Merkle tree
+    /// CHECK: This is safe because the compressed tree seeds tie it to the tree created by this program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+    // Bubblegum tree config
+    /// CHECK: Using "address" constraint to validate the bubblegum tree config address
+    #[account(address = Pubkey::find_program_address(&[merkle_tree.key().as_ref()], &BUBBLEGUM_ID).0)]
+    pub tree_authority: UncheckedAccount<'info>,
+    // project_account (sol account of the conservation project)
+    #[account(mut, address = compressed_tree.project)]
+    pub project_account: SystemAccount<'info>,
+    // Treasury account
+    #[account(
+        init_if_needed,
+        space = 200,
+        payer = payer,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury_account: Box<Account<'info, Treasury>>,
+    // Donor PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Leaderboard PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = LEADERBOARD_SPACE,
+        seeds = [LEADERBOARD_SEED],
+        bump
+    )]
+    pub leaderboard: Box<Account<'info, Leaderboard>>,
+    // Sale phase PDA (may not exist if no presale has been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's SalePhase PDA
+    #[account(
+        seeds = [SALE_PHASE_SEED],
+        bump
+    )]
+    pub sale_phase: UncheckedAccount<'info>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_SALES) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Purchase counter PDA of the buyer
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 100,
+        seeds = [PURCHASE_COUNTER_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub purchase_counter: Box<Account<'info, PurchaseCounter>>,
+    // Bubblegum program
+    /// CHECK: Using "address" constraint to validate the bubblegum program id
+    #[account(address = BUBBLEGUM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+    // Account compression program
+    /// CHECK: Using "address" constraint to validate the compression program id
+    #[account(address = COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+    // Noop program
+    /// CHECK: Using "address" constraint to validate the noop program id
+    #[account(address = NOOP_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
     // System program
     pub system_program: Program<'info, System>,
-    // Payer - Security - I verify that the address requesting to create an election is authorised to request this, otherwise anyone can use this program to create fake elections
-    #[account(mut, constraint = payer.key.to_string() == String::from("kq29PDUDGccE8WWACB76XVyn56TuozLfyGQ9NTDRyxH"))]
+    // Buyer - the new leaf owner
+    #[account(mut)]
     pub payer: Signer<'info>,
 }
 
 #[derive(Accounts)]
-#[instruction(name_of_nft: String, date_of_election: i64, mauritius_wildlife_protection_vote: u32, mauritius_nature_protection_society_vote: u32, mauritius_marine_life_protection_vote: u32)]
-pub struct CreateVote<'info> {
-    // Vote PDA
+#[instruction(date_of_election: i64, leaf: CompressedLeaf)]
+pub struct CreateCompressedVote<'info> {
+    // Vote PDA - one per compressed nft and election, like the vote pda of a regular nft
     #[account(
-            init,
-            payer = payer,
-            space = 400,
-            seeds = [mint.key().as_ref(), election_pda.key().as_ref()],
-            bump
-        )]
+        init,
+        payer = payer,
+        space = 400,
+        seeds = [asset.key().as_ref(), election_pda.key().as_ref()],
+        bump
+    )]
     pub vote_pda: Box<Account<'info, Vote>>,
     // Election PDA
     #[account(
-            seeds = [b"election".as_ref(), &date_of_election.to_string().as_bytes().as_ref()],
-            bump
-        )]
+        mut,
+        seeds = [ELECTION_SEED, date_of_election.to_string().as_bytes()],
+        bump
+    )]
     pub election_pda: Box<Account<'info, Election>>,
-    // Mint of the nft (used for validating accounts above) - need to make sure that this mint is a valid PDA of the program and the supply of this mint is 1
-    #[account(
-            mut,
-            seeds = [&name_of_nft.as_bytes().as_ref(), current_program.key.as_ref()],
-            bump,
-            mint::decimals = 0,
-            mint::authority = mint_authority,
-            mint::freeze_authority = mint_authority,
-            constraint = mint.supply == 1,
-        )]
-    pub mint: Account<'info, Mint>,
-    // Mint authority
-    // It seems that after the creation of the master edition, the master edition becomes the mint authority
-    // Need to implement validation for this
-    /// CHECK: This is not dangerous because we don't read or write from this account
-    #[account(mut)]
-    pub mint_authority: UncheckedAccount<'info>,
-    // ATA account (need to verify that the token balance is 1)
+    // Asset id of the compressed nft
+    /// CHECK: Using "address" constraint to validate the asset id of the leaf
+    #[account(address = compressed_asset_id(&merkle_tree.key(), leaf.nonce))]
+    pub asset: UncheckedAccount<'info>,
+    // Compressed tree PDA - only nfts from the program's trees can vote
     #[account(
-            mut,
-            associated_token::mint = mint, // mint of the token
-            associated_token::authority = payer, // authority that should be a PDA account
-            constraint = ata_account.amount == 1
-        )]
-    pub ata_account: Account<'info, TokenAccount>,
+        seeds = [COMPRESSED_TREE_SEED, merkle_tree.key().as_ref()],
+        bump
+    )]
+    pub compressed_tree: Box<Account<'info, CompressedTree>>,
+    // Merkle tree
+    /// CHECK: This is safe because the compression program verifies the leaf against it
+    pub merkle_tree: UncheckedAccount<'info>,
+    // Delegate of the leaf - the voter themselves unless they have delegated the nft
+    /// CHECK: This is safe because it is part of the leaf verified by the compression program
+    pub leaf_delegate: UncheckedAccount<'info>,
     // mauritius_wildlife_protection account
     /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = mauritius_wildlife_protection.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
+    #[account(constraint = mauritius_wildlife_protection.key.to_string() == "EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ")]
     pub mauritius_wildlife_protection: SystemAccount<'info>,
     // mauritius_nature_protection_society account
     /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = mauritius_nature_protection_society.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
+    #[account(constraint = mauritius_nature_protection_society.key.to_string() == "EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ")]
     pub mauritius_nature_protection_society: SystemAccount<'info>,
     // mauritius_marine_life_protection account
     /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = mauritius_marine_life_protection.key.to_string() == String::from("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"))]
+    #[account(constraint = mauritius_marine_life_protection.key.to_string() == "EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ")]
     pub mauritius_marine_life_protection: SystemAccount<'info>,
+    // Donor PDA
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 200,
+        seeds = [DONOR_SEED, payer.key().as_ref()],
+        bump
+    )]
+    pub donor_pda: Box<Account<'info, Donor>>,
+    // Program config PDA (may not exist if the program has never been configured)
+    /// CHECK: This is safe because the seeds constraint ensures it can only be the program's ProgramConfig PDA
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump,
+        constraint = !load_config(&config)?.is_paused(PAUSE_VOTING) @ InvalidConfigError::ProgramPaused
+    )]
+    pub config: UncheckedAccount<'info>,
+    // Account compression program
+    /// CHECK: Using "address" constraint to validate the compression program id
+    #[account(address = COMPRESSION_ID)]
+    pub compression_program: UncheckedAccount<'info>,
     // System program
     pub system_program: Program<'info, System>,
-    // Current program
-    /// CHECK: This is safe because I verify that the account passed in has the right public key
-    #[account(constraint = current_program.key.to_string() == String::from("Bco4dXjvoM1oPsU5c2u8rDKXesq2r9iKoM6cSGz53i87"))]
-    pub current_program: UncheckedAccount<'info>,
-    // Payer
+    // Voter - the owner of the leaf
     #[account(mut)]
     pub payer: Signer<'info>,
 }
 
-#[derive(Clone)]
-pub struct TokenMetaData;
-impl anchor_lang::Id for TokenMetaData {
-    fn id() -> Pubkey {
-        MetadataTokenId
+#[derive(Clone)]
+pub struct TokenMetaData;
+impl anchor_lang::Id for TokenMetaData {
+    fn id() -> Pubkey {
+        MetadataTokenId
+    }
+}
+
+// Seeds of the program's PDAs, shared with the find_* helpers of the client
+pub const MINT_AUTHORITY_SEED: &[u8] = b"mint";
+pub const NFT_COUNTER_SEED: &[u8] = b"nftCounter";
+pub const NFT_MINT_SEED: &[u8] = b"nft";
+pub const NAME_RECORD_SEED: &[u8] = b"name";
+pub const NFT_PDA_SEED: &[u8] = b"nftPDA";
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const LEADERBOARD_SEED: &[u8] = b"leaderboard";
+pub const SALE_PHASE_SEED: &[u8] = b"salePhase";
+pub const ELECTION_SEED: &[u8] = b"election";
+pub const VOTE_RECEIPT_CONFIG_SEED: &[u8] = b"voteReceiptConfig";
+pub const VOTE_RECEIPT_SEED: &[u8] = b"voteReceipt";
+pub const ARCHIVE_SEED: &[u8] = b"archive";
+pub const AUCTION_SEED: &[u8] = b"auction";
+pub const RESERVATION_SEED: &[u8] = b"reservation";
+pub const RESERVATION_COOLDOWN_SEED: &[u8] = b"reservationCooldown";
+pub const ESCROW_SEED: &[u8] = b"escrow";
+pub const PRINT_RUN_SEED: &[u8] = b"printRun";
+pub const EDITION_MINT_SEED: &[u8] = b"print";
+pub const STATUS_LOG_SEED: &[u8] = b"statusLog";
+pub const DONOR_SEED: &[u8] = b"donor";
+pub const TOKEN_DONOR_SEED: &[u8] = b"tokenDonor";
+pub const PURCHASE_COUNTER_SEED: &[u8] = b"purchaseCounter";
+pub const PRESALE_SEED: &[u8] = b"presale";
+pub const PROJECT_SEED: &[u8] = b"project";
+pub const FIELD_REPORTER_SEED: &[u8] = b"reporter";
+pub const PAYMENT_MINT_SEED: &[u8] = b"paymentMint";
+pub const BADGE_TIER_SEED: &[u8] = b"badgeTier";
+pub const BADGE_MINT_SEED: &[u8] = b"badge";
+pub const TREASURY_RECIPIENT_SEED: &[u8] = b"treasuryRecipient";
+pub const GRANT_STREAM_SEED: &[u8] = b"grantStream";
+pub const STREAM_CANCELLATION_SEED: &[u8] = b"streamCancellation";
+pub const CANCELLATION_BALLOT_SEED: &[u8] = b"ballot";
+pub const COMPRESSED_TREE_SEED: &[u8] = b"compressedTree";
+
+// Layout version written to every account the program creates or migrates.
+// Accounts created before versioning was added read as version 0
+pub const ACCOUNT_VERSION: u8 = 1;
+// NftPDA layout versions: 1 - project pubkey, 2 - ecological data
+pub const NFT_PDA_VERSION: u8 = 2;
+pub const NFT_PDA_SPACE: usize = 300;
+pub const ELECTION_SPACE: usize = 400;
+
+#[account]
+#[derive(Default, Debug)]
+pub struct NftPDA {
+    pub mint: Pubkey, // "mint"
+    pub conservation_address: String,
+    pub on_auction: bool, // true while the nft can only be bought through its Auction PDA
+    pub version: u8,      // layout version, see NFT_PDA_VERSION
+    pub project: Pubkey,  // v1 - conservation_address as a Pubkey
+    pub ecology: EcologicalData, // v2 - what the nft represents in the real world
+}
+
+impl NftPDA {
+    // v0 accounts only store the conservation address as a string
+    pub fn project_address(&self) -> Result<Pubkey> {
+        if self.version >= 1 {
+            return Ok(self.project);
+        }
+        Pubkey::from_str(&self.conservation_address)
+            .map_err(|_| error!(InvalidMigrationError::InvalidConservationAddress))
+    }
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct MintAuthority {
+    pub desc: String, // "mint authority"
+    pub version: u8,
+}
+
+pub const NFT_COUNTER_SPACE: usize = 8 + 4 + 10 + 8 + 1;
+
+// NFT counter PDA - hands out the sequential ids that seed nft mints
+// Seeds for this will be [b"nftCounter".as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct NftCounter {
+    pub desc: String, // "nftCounter"
+    pub next_id: u64, // id of the next nft, its mint is seeded by [b"nft".as_ref(), next_id.to_le_bytes().as_ref()]
+    pub version: u8,
+}
+
+impl NftCounter {
+    // Returns the id of the nft being minted and moves the counter on to the next one
+    pub fn take_id(&mut self) -> Result<u64> {
+        self.desc = String::from("nftCounter");
+        self.version = ACCOUNT_VERSION;
+        let id = self.next_id;
+        self.next_id += 1;
+        Ok(id)
+    }
+}
+
+// Longest name an nft can be registered with - the limit Metaplex puts on metadata names
+pub const MAX_NFT_NAME_LENGTH: usize = 32;
+pub const NAME_RECORD_SPACE: usize = 8 + 4 + 4 + 4 + MAX_NFT_NAME_LENGTH + 32 + 1 + 8 + 1;
+
+// Name record PDA - maps the name of an nft to its mint. There is one per normalised name, so names are unique
+// Seeds for this will be [b"name".as_ref(), name_hash(&name).as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct NameRecord {
+    pub desc: String,        // "name"
+    pub name: String,        // name of the nft as it was registered, max 32 bytes
+    pub mint: Pubkey,        // the mint of the nft
+    pub nft_id: Option<u64>, // id that seeds the mint - None for nfts whose mint is seeded by their name
+    pub version: u8,
+}
+
+impl NameRecord {
+    pub fn register(&mut self, name: &str, mint: Pubkey, nft_id: Option<u64>) -> Result<()> {
+        if normalise_name(name).is_empty() {
+            return err!(InvalidNameError::EmptyName);
+        }
+        if name.len() > MAX_NFT_NAME_LENGTH {
+            return err!(InvalidNameError::NameTooLong);
+        }
+        self.desc = String::from("name");
+        self.name = name.to_string();
+        self.mint = mint;
+        self.nft_id = nft_id;
+        self.version = ACCOUNT_VERSION;
+        Ok(())
+    }
+}
+
+// Names are compared ignoring case and extra whitespace, so "Pink  Pigeon" and "pink pigeon" are the same name
+pub fn normalise_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+// Seed of the name record of a name - hashed because names can be longer than a 32 byte seed
+pub fn name_hash(name: &str) -> [u8; 32] {
+    keccak::hash(normalise_name(name).as_bytes()).to_bytes()
+}
+
+#[account]
+#[derive(Default, Debug)]
+pub struct Treasury {
+    pub desc: String, // "treasury"
+    pub version: u8,
+    // Running totals - appended after version so treasuries created before the ledger read them as 0
+    pub sales: u64,          // lamports from fixed price sales
+    pub auctions: u64,       // lamports from auction sales
+    pub presales: u64,       // lamports from presale purchases
+    pub donations: u64,      // lamports donated directly to the treasury
+    pub escrow_refunds: u64, // lamports of missed milestones refunded from escrows
+    pub other: u64,          // rent of closed accounts
+    pub withdrawn: u64,      // lamports paid out through withdrawals and grant streams
+    pub withdrawals: u64,    // number of withdrawals
+    pub grant_refunds: u64,  // unvested lamports returned by cancelled grant streams
+}
+
+impl Treasury {
+    pub fn record_inflow(&mut self, source: TreasuryInflow, amount: u64) {
+        let total = match source {
+            TreasuryInflow::Sale => &mut self.sales,
+            TreasuryInflow::Auction => &mut self.auctions,
+            TreasuryInflow::Presale => &mut self.presales,
+            TreasuryInflow::Donation => &mut self.donations,
+            TreasuryInflow::EscrowRefund => &mut self.escrow_refunds,
+            TreasuryInflow::Other => &mut self.other,
+            TreasuryInflow::GrantRefund => &mut self.grant_refunds,
+        };
+        *total += amount;
+    }
+}
+
+// Election PDA
+// Seeds for this will  be [b"election".as_ref(), date_of_election.to_string().as_bytes().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct Election {
+    pub desc: String, // "election"
+    pub date: i64,    // 26/02/2023
+    pub organisations: Organisations,
+    pub finalized: bool,   // set once the results have been archived
+    pub finalized_at: i64, // unix timestamp at which the election was finalized
+    pub version: u8,
+}
+
+// Layout of elections created before they could be finalized and were versioned
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ElectionV0 {
+    pub desc: String,
+    pub date: i64,
+    pub organisations: Organisations,
+}
+
+// Reads an election of any layout version. v0 elections that don't have room for the fields
+// appended since are read as not finalized, with version 0
+pub fn read_election(election: &AccountInfo) -> Result<Election> {
+    let data = election.try_borrow_data()?;
+    if data.len() < 8 || data[..8] != Election::discriminator() {
+        return err!(ErrorCode::AccountDiscriminatorMismatch);
+    }
+    if let Ok(election) = Election::try_deserialize(&mut &data[..]) {
+        return Ok(election);
+    }
+    let legacy = ElectionV0::deserialize(&mut &data[8..])?;
+    Ok(Election {
+        desc: legacy.desc,
+        date: legacy.date,
+        organisations: legacy.organisations,
+        finalized: false,
+        finalized_at: 0,
+        version: 0,
+    })
+}
+
+impl Election {
+    // Adds a voter's votes to the running totals of each organisation, while the election is active
+    pub fn add_votes(&mut self, votes: &Votes, timestamp: i64) -> Result<()> {
+        msg!("Validating that the election is still active");
+        if self.date < timestamp {
+            return err!(InvalidVoteError::ElectionClosed);
+        };
+        msg!("Validated that the election is still active");
+
+        self.organisations.mauritius_wildlife_protection.votes +=
+            votes.mauritius_wildlife_protection.amount as u64;
+        self.organisations.mauritius_nature_protection_society.votes +=
+            votes.mauritius_nature_protection_society.amount as u64;
+        self.organisations.mauritius_marine_life_protection.votes +=
+            votes.mauritius_marine_life_protection.amount as u64;
+        Ok(())
+    }
+}
+
+// Vote PDA
+// Seeds for this will  be [mint.key().as_ref(), election.key().as_bytes().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct Vote {
+    pub desc: String,  // "vote" 32 + 4
+    pub mint: Pubkey,  // the mint address of the nft that qualifies the voter to vote 32 + 4
+    pub voter: Pubkey, // the address of the person voting 32 + 4
+    pub ata: Pubkey, // the ATA of the voter that contains the nft that qualifies the voter to vote 32 + 4
+    pub date: i64,
+    pub vote: Votes,
+    pub version: u8,
+}
+
+pub const VOTE_RECEIPT_CONFIG_SPACE: usize = 8 + 4 + 12 + 32 + 4 + 32 + 4 + 10 + 4 + 200 + 8 + 1;
+
+// Vote receipt config PDA - the "I voted" nft voters of an election can claim
+// Seeds for this will be [b"voteReceiptConfig".as_ref(), election.key().as_ref()], current_program.key()
+// Receipt mints are seeded by [b"voteReceipt".as_ref(), vote.key().as_ref()]
+#[account]
+#[derive(Default, Debug)]
+pub struct VoteReceiptConfig {
+    pub desc: String,     // "vote receipt"
+    pub election: Pubkey, // the election pda the receipt commemorates
+    pub name: String,     // metadata name of the receipt, max 32 bytes
+    pub symbol: String,   // metadata symbol of the receipt, max 10 bytes
+    pub uri: String,      // metadata uri of the receipt, max 200 bytes
+    pub claimed: u64,     // number of receipts claimed
+    pub version: u8,
+}
+
+// Auction PDA
+// Seeds for this will be [b"auction".as_ref(), mint.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct Auction {
+    pub desc: String,     // "auction"
+    pub mint: Pubkey,     // the mint of the nft being sold
+    pub start_price: u64, // price in lamports at start_date
+    pub floor_price: u64, // price in lamports once duration has passed
+    pub start_date: i64,  // unix timestamp at which the price starts falling
+    pub duration: i64,    // seconds it takes for the price to fall to floor_price
+    pub buyer: Pubkey,    // set once the nft is sold
+    pub sale_price: u64,  // set once the nft is sold
+    pub version: u8,
+}
+
+impl Auction {
+    // Price falls linearly from start_price to floor_price over duration
+    pub fn current_price(&self, timestamp: i64) -> u64 {
+        if timestamp <= self.start_date {
+            return self.start_price;
+        }
+        let elapsed = timestamp - self.start_date;
+        if elapsed >= self.duration {
+            return self.floor_price;
+        }
+        let price_drop =
+            (self.start_price - self.floor_price) as u128 * elapsed as u128 / self.duration as u128;
+        self.start_price - price_drop as u64
+    }
+}
+
+// Payment mint PDA
+// Seeds for this will be [b"paymentMint".as_ref(), payment_mint.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct PaymentMint {
+    pub desc: String,  // "payment mint"
+    pub mint: Pubkey,  // the whitelisted SPL mint
+    pub vault: Pubkey, // the treasury's token account for this mint
+    pub price: u64,    // price of an nft in the mint's base units
+    pub enabled: bool, // whether purchases with this mint are currently accepted
+    pub version: u8,
+    // Appended after version so payment mints created before the ledger read it as 0
+    pub donations: u64, // base units donated to the treasury vault
+    pub sales: u64,     // base units paid into the treasury vault by nft purchases
+}
+
+impl PaymentMint {
+    // Values an amount of the mint in lamports, at the rate set by the nft price in the mint
+    pub fn lamport_value(&self, amount: u64) -> u64 {
+        let value = amount as u128 * NFT_PRICE as u128 / self.price.max(1) as u128;
+        value.min(u64::MAX as u128) as u64
+    }
+}
+
+// Conservation project PDA
+// Seeds for this will be [b"project".as_ref(), project_wallet.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct ConservationProject {
+    pub desc: String,   // "project"
+    pub name: String,   // display name of the project, max 50 bytes
+    pub wallet: Pubkey, // wallet that receives the project's share of proceeds and donations
+    pub active: bool,   // whether the project can currently receive funds
+    pub version: u8,
+}
+
+// Donor PDA
+// Seeds for this will be [b"donor".as_ref(), wallet.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct Donor {
+    pub desc: String,         // "donor"
+    pub wallet: Pubkey,       // the wallet this record belongs to
+    pub lamports_given: u64, // lifetime lamports given through purchases and donations, token donations at their lamport value
+    pub nfts_bought: u32,    // number of nfts bought
+    pub votes_cast: u32,     // number of votes cast
+    pub token_donations: u32, // number of donations made in whitelisted SPL tokens
+    pub donations: u32,      // total number of donations made
+    pub version: u8,
+}
+
+// 8 discriminator + (4 + 11) desc + 32 wallet + 32 mint + 8 amount_given + 4 donations + 1 version
+pub const TOKEN_DONOR_SPACE: usize = 8 + 4 + 11 + 32 + 32 + 8 + 4 + 1;
+
+// Token donor PDA
+// Seeds for this will be [b"tokenDonor".as_ref(), wallet.key().as_ref(), payment_mint.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct TokenDonor {
+    pub desc: String,      // "token donor"
+    pub wallet: Pubkey,    // the wallet this record belongs to
+    pub mint: Pubkey,      // the whitelisted SPL mint donated in
+    pub amount_given: u64, // lifetime base units of the mint donated
+    pub donations: u32,    // number of donations made in the mint
+    pub version: u8,
+}
+
+pub const LEADERBOARD_SIZE: usize = 10;
+// 8 discriminator + (4 + 11) desc + 4 vec length + LEADERBOARD_SIZE * (32 + 8) entries + 1 version
+pub const LEADERBOARD_SPACE: usize = 8 + 4 + 11 + 4 + LEADERBOARD_SIZE * (32 + 8) + 1;
+
+// Leaderboard PDA - top donors by lifetime lamports given, highest first
+// Seeds for this will be [b"leaderboard".as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct Leaderboard {
+    pub desc: String, // "leaderboard"
+    pub entries: Vec<LeaderboardEntry>,
+    pub version: u8,
+}
+
+impl Leaderboard {
+    // Updates the wallet's total, keeping only the top LEADERBOARD_SIZE donors
+    pub fn record(&mut self, wallet: Pubkey, lamports_given: u64) {
+        self.desc = String::from("leaderboard");
+        self.version = ACCOUNT_VERSION;
+        if let Some(entry) = self.entries.iter_mut().find(|entry| entry.wallet == wallet) {
+            entry.lamports_given = lamports_given;
+        } else if self.entries.len() < LEADERBOARD_SIZE {
+            self.entries.push(LeaderboardEntry {
+                wallet,
+                lamports_given,
+            });
+        } else if let Some(lowest) = self.entries.last_mut() {
+            if lowest.lamports_given >= lamports_given {
+                return;
+            }
+            *lowest = LeaderboardEntry {
+                wallet,
+                lamports_given,
+            };
+        }
+        self.entries
+            .sort_by(|a, b| b.lamports_given.cmp(&a.lamports_given));
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
+pub struct LeaderboardEntry {
+    pub wallet: Pubkey,
+    pub lamports_given: u64,
+}
+
+// Badge tier PDA
+// Seeds for this will be [b"badgeTier".as_ref(), &[tier]], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct BadgeTier {
+    pub desc: String,   // "badge tier"
+    pub tier: u8,       // index of the tier
+    pub threshold: u64, // lifetime lamports a donor must have given to claim the badge
+    pub name: String,   // metadata name of the badge, max 32 bytes
+    pub symbol: String, // metadata symbol of the badge, max 10 bytes
+    pub uri: String,    // metadata uri of the badge, max 200 bytes
+    pub version: u8,
+}
+
+// Sale phase PDA
+// Seeds for this will be [b"salePhase".as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct SalePhase {
+    pub desc: String,               // "sale phase"
+    pub presale_start: i64,         // unix timestamp at which the presale opens
+    pub presale_end: i64,           // unix timestamp at which sales open to everyone
+    pub merkle_root: [u8; 32],      // root of the keccak merkle tree of allowlisted wallets
+    pub max_presale_purchases: u32, // max number of nfts a wallet can buy during the presale
+    pub version: u8,
+}
+
+impl SalePhase {
+    pub fn is_presale(&self, timestamp: i64) -> bool {
+        timestamp >= self.presale_start && timestamp < self.presale_end
+    }
+}
+
+// Presale purchases PDA - one per wallet per presale, so every new presale starts from zero
+// Seeds for this will be [b"presale".as_ref(), wallet.key().as_ref(), presale_start.to_le_bytes().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct PresalePurchases {
+    pub desc: String,       // "presale purchases"
+    pub wallet: Pubkey,     // the wallet this record belongs to
+    pub presale_start: i64, // start of the presale these purchases were made in
+    pub purchases: u32,     // number of nfts bought during the presale
+    pub version: u8,
+}
+
+// Program config PDA
+// Seeds for this will be [b"config".as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct ProgramConfig {
+    pub desc: String,                  // "config"
+    pub max_nfts_per_wallet: u32,      // max number of nfts a wallet can buy, 0 for no limit
+    pub max_purchases_per_window: u32, // max number of purchases a wallet can make per slot_window, 0 for no limit
+    pub slot_window: u64,              // length of the rate limiting window in slots
+    pub reservation_seconds: i64,      // how long a reservation holds an nft for a buyer
+    pub reservation_deposit: u64,      // lamports a buyer deposits to reserve an nft
+    pub guardian: Pubkey,              // can pause and unpause the program alongside the admin
+    pub paused: u8,                    // bitmask of PAUSE_* flags for the paused instruction groups
+    pub version: u8,
+}
+
+pub const PAUSE_MINTING: u8 = 1 << 0;
+pub const PAUSE_SALES: u8 = 1 << 1;
+pub const PAUSE_VOTING: u8 = 1 << 2;
+pub const PAUSE_TREASURY: u8 = 1 << 3; // withdrawals and payouts out of the treasury and escrows
+pub const PAUSE_DONATIONS: u8 = 1 << 4;
+
+impl ProgramConfig {
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.paused & flag != 0
+    }
+}
+
+// Purchase counter PDA
+// Seeds for this will be [b"purchaseCounter".as_ref(), wallet.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct PurchaseCounter {
+    pub desc: String,           // "purchase counter"
+    pub wallet: Pubkey,         // the wallet this record belongs to
+    pub total_purchases: u32,   // number of nfts bought by the wallet
+    pub window_start_slot: u64, // slot at which the current rate limiting window started
+    pub window_purchases: u32,  // number of nfts bought in the current rate limiting window
+    pub version: u8,
+}
+
+impl PurchaseCounter {
+    // Counts a purchase, failing if it would take the wallet over the configured limits
+    pub fn record_purchase(&mut self, config: &ProgramConfig, slot: u64) -> Result<()> {
+        if config.max_nfts_per_wallet > 0 && self.total_purchases >= config.max_nfts_per_wallet {
+            return err!(InvalidPurchaseError::WalletLimitReached);
+        }
+        if slot >= self.window_start_slot.saturating_add(config.slot_window) {
+            self.window_start_slot = slot;
+            self.window_purchases = 0;
+        }
+        if config.max_purchases_per_window > 0
+            && self.window_purchases >= config.max_purchases_per_window
+        {
+            return err!(InvalidPurchaseError::RateLimited);
+        }
+        self.total_purchases += 1;
+        self.window_purchases += 1;
+        Ok(())
+    }
+}
+
+// Reservation PDA
+// Seeds for this will be [b"reservation".as_ref(), mint.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct Reservation {
+    pub desc: String,    // "reservation"
+    pub mint: Pubkey,    // the mint of the reserved nft
+    pub buyer: Pubkey,   // the only wallet that can buy the nft while the hold lasts
+    pub expires_at: i64, // unix timestamp at which the hold ends
+    pub deposit: u64, // lamports held on top of rent, refunded to the buyer when the reservation closes
+    pub version: u8,
+}
+
+// 8 discriminator + (4 + 20) desc + 32 mint + 32 buyer + 8 reservable_at + 1 version
+pub const RESERVATION_COOLDOWN_SPACE: usize = 8 + 4 + 20 + 32 + 32 + 8 + 1;
+
+// Reservation cooldown PDA - outlives the reservation, so releasing a hold early doesn't let the
+// buyer take a new one. A buyer can hold the nft again one reservation length after their hold ends
+// Seeds for this will be [b"reservationCooldown".as_ref(), mint.key().as_ref(), buyer.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct ReservationCooldown {
+    pub desc: String,       // "reservation cooldown"
+    pub mint: Pubkey,       // the mint of the reserved nft
+    pub buyer: Pubkey,      // the wallet that reserved it
+    pub reservable_at: i64, // unix timestamp from which the buyer can reserve the nft again
+    pub version: u8,
+}
+
+#[event]
+pub struct NftBurned {
+    mint: Pubkey,   // the mint of the burned nft
+    reason: String, // why the nft was burned, max 100 bytes
+    timestamp: i64,
+}
+
+// Seconds after an election is finalized before its election and vote pdas can be closed - 30 days
+pub const ELECTION_GRACE_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+// Election archive PDA
+// Seeds for this will be [b"archive".as_ref(), election.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct ElectionArchive {
+    pub desc: String,                 // "archive"
+    pub election: Pubkey,             // the election pda these results came from
+    pub date: i64,                    // the date of the election
+    pub finalized_at: i64,            // unix timestamp at which the election was finalized
+    pub winner: Pubkey,               // address of the organisation with the most votes
+    pub results: Vec<ArchivedResult>, // votes for each organisation
+    pub version: u8,
+    pub paid_out: u64, // lamports granted to the winner from the treasury
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
+pub struct ArchivedResult {
+    pub address: Pubkey,
+    pub votes: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
+pub struct EcologicalData {
+    pub asset_kind: AssetKind,
+    pub species_id: u64, // GBIF taxon key of the species, 0 for habitats and reefs
+    pub iucn_status: IucnStatus,
+    pub region_code: String, // ISO 3166-2 code of the region, e.g. "MU-PL", max 6 bytes
+    pub geohash: String,     // location of the animal or centre of the habitat, max 12 bytes
+    pub area_m2: u64,        // area covered in square metres, 0 for single animals
+}
+
+impl EcologicalData {
+    pub fn validate(&self) -> Result<()> {
+        if self.region_code.len() > 6 {
+            return err!(InvalidEcologyError::InvalidRegionCode);
+        }
+        if self.geohash.len() > 12
+            || !self
+                .geohash
+                .bytes()
+                .all(|c| b"0123456789bcdefghjkmnpqrstuvwxyz".contains(&c))
+        {
+            return err!(InvalidEcologyError::InvalidGeohash);
+        }
+        if self.species_id == 0 && self.asset_kind == AssetKind::Animal {
+            return err!(InvalidEcologyError::MissingSpecies);
+        }
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Default, Clone, Copy)]
+pub enum AssetKind {
+    #[default]
+    Unspecified,
+    Animal,
+    AnimalFamily,
+    Habitat,
+    Reef,
+}
+
+// IUCN Red List categories
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Default, Clone, Copy)]
+pub enum IucnStatus {
+    #[default]
+    NotEvaluated,
+    DataDeficient,
+    LeastConcern,
+    NearThreatened,
+    Vulnerable,
+    Endangered,
+    CriticallyEndangered,
+    ExtinctInTheWild,
+    Extinct,
+}
+
+// Field reporter PDA
+// Seeds for this will be [b"reporter".as_ref(), project_wallet.key().as_ref(), reporter.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct FieldReporter {
+    pub desc: String,     // "field reporter"
+    pub project: Pubkey,  // wallet of the project the reporter is accredited for
+    pub reporter: Pubkey, // key that signs status updates
+    pub active: bool,     // whether the reporter can currently post updates
+    pub version: u8,
+}
+
+// 8 discriminator + (4 + 10) desc + 32 mint + 4 count + 1 version
+pub const STATUS_LOG_SPACE: usize = 8 + 4 + 10 + 32 + 4 + 1;
+pub const MAX_EVIDENCE_URI_LENGTH: usize = 200;
+// 1 health + 8 timestamp + 32 reporter + 32 evidence hash + (4 + 200) evidence uri
+pub const STATUS_UPDATE_SPACE: usize = 1 + 8 + 32 + 32 + 4 + MAX_EVIDENCE_URI_LENGTH;
+
+// Status log PDA - grows by one StatusUpdate every time a field reporter posts.
+// The account is this header followed by `count` updates, each in a STATUS_UPDATE_SPACE slot, so
+// posting never has to deserialize the updates already in the log
+// Seeds for this will be [b"statusLog".as_ref(), mint.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct StatusLog {
+    pub desc: String, // "status log"
+    pub mint: Pubkey, // the mint of the nft the updates are about
+    pub count: u32,   // number of updates in the log
+    pub version: u8,
+}
+
+impl StatusLog {
+    // Offset of the update at `index` in the status log's data
+    pub fn entry_offset(index: u32) -> usize {
+        STATUS_LOG_SPACE + index as usize * STATUS_UPDATE_SPACE
+    }
+
+    // Decodes the update at `index` from the status log's data
+    pub fn entry(data: &[u8], index: u32) -> Result<StatusUpdate> {
+        let offset = Self::entry_offset(index);
+        let slot = data
+            .get(offset..offset + STATUS_UPDATE_SPACE)
+            .ok_or_else(|| error!(InvalidStatusError::UpdateNotFound))?;
+        StatusUpdate::deserialize(&mut &slot[..])
+            .map_err(|_| error!(ErrorCode::AccountDidNotDeserialize))
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
+pub struct StatusUpdate {
+    pub health: HealthStatus,
+    pub timestamp: i64,
+    pub reporter: Pubkey,
+    pub evidence_hash: [u8; 32], // sha256 of the evidence document
+    pub evidence_uri: String,    // where the evidence document is stored, max 200 bytes
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Default, Clone, Copy)]
+pub enum HealthStatus {
+    #[default]
+    Unknown,
+    Healthy,
+    Stable,
+    AtRisk,
+    Injured,
+    Recovering,
+    Degraded,
+    Restored,
+    Deceased,
+}
+
+pub const MAX_MILESTONES: usize = 10;
+// 2 basis points + 8 deadline + 1 status + 32 evidence hash + (4 + 100) evidence uri + 8 paid
+pub const MILESTONE_SPACE: usize = 2 + 8 + 1 + 32 + 4 + 100 + 8;
+// 8 discriminator + (4 + 6) desc + 32 mint + 32 project + 32 verifier + 8 deposited
+// + 8 settled + 4 vec length + milestones + 1 version
+pub const ESCROW_SPACE: usize =
+    8 + 4 + 6 + 32 + 32 + 32 + 8 + 8 + 4 + MAX_MILESTONES * MILESTONE_SPACE + 1;
+
+// Escrow PDA - holds the project's share of a habitat nft sale until milestones are met
+// Seeds for this will be [b"escrow".as_ref(), mint.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct Escrow {
+    pub desc: String,     // "escrow"
+    pub mint: Pubkey,     // the mint of the nft whose proceeds are escrowed
+    pub project: Pubkey, // wallet of the conservation project, posts evidence and receives releases
+    pub verifier: Pubkey, // can attest milestones alongside the admin
+    pub deposited: u64,  // lamports paid into the escrow by sales
+    pub settled: u64, // lamports released to the project or refunded to the treasury, across all milestones
+    pub milestones: Vec<Milestone>,
+    pub version: u8,
+}
+
+impl Escrow {
+    pub fn milestone_mut(&mut self, index: u8) -> Result<&mut Milestone> {
+        self.milestones
+            .get_mut(index as usize)
+            .ok_or_else(|| error!(InvalidEscrowError::InvalidMilestone))
+    }
+
+    // Share of everything deposited so far that a milestone is entitled to.
+    // The last milestone takes whatever rounding left over
+    pub fn entitlement(&self, index: usize) -> u64 {
+        let share = |milestone: &Milestone| {
+            (self.deposited as u128 * milestone.basis_points as u128 / 10_000) as u64
+        };
+        if index + 1 == self.milestones.len() {
+            self.deposited - self.milestones[..index].iter().map(share).sum::<u64>()
+        } else {
+            share(&self.milestones[index])
+        }
+    }
+
+    // Marks the part of a milestone's entitlement it hasn't been paid yet as paid and returns it
+    fn take_owed(&mut self, index: usize) -> u64 {
+        let owed = self
+            .entitlement(index)
+            .saturating_sub(self.milestones[index].paid);
+        self.milestones[index].paid += owed;
+        self.settled += owed;
+        owed
+    }
+
+    // Marks a milestone as released or refunded and returns what it is owed of the deposits so far.
+    // Sales after it settled are paid out by deposit
+    pub fn settle(&mut self, index: u8, status: MilestoneStatus) -> Result<u64> {
+        let milestone = self.milestone_mut(index)?;
+        if milestone.status == MilestoneStatus::Released
+            || milestone.status == MilestoneStatus::Refunded
+        {
+            return err!(InvalidEscrowError::MilestoneSettled);
+        }
+        milestone.status = status;
+        Ok(self.take_owed(index as usize))
+    }
+
+    // Records a sale paid into the escrow and returns the lamports owed from it to milestones that
+    // are already released and refunded, which the caller passes on straight away
+    pub fn deposit(&mut self, amount: u64) -> (u64, u64) {
+        self.deposited += amount;
+        let mut released = 0;
+        let mut refunded = 0;
+        for index in 0..self.milestones.len() {
+            match self.milestones[index].status {
+                MilestoneStatus::Released => released += self.take_owed(index),
+                MilestoneStatus::Refunded => refunded += self.take_owed(index),
+                _ => {}
+            }
+        }
+        (released, refunded)
     }
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
+pub struct MilestoneTerms {
+    pub basis_points: u16, // share of the escrow released by this milestone, all must add up to 10000
+    pub deadline: i64, // unix timestamp after which the milestone can be refunded to the treasury
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
+pub struct Milestone {
+    pub basis_points: u16,
+    pub deadline: i64,
+    pub status: MilestoneStatus,
+    pub evidence_hash: [u8; 32], // sha256 of the evidence document posted by the project
+    pub evidence_uri: String,    // where the evidence document is stored, max 100 bytes
+    pub paid: u64,               // lamports released or refunded for this milestone so far
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Default, Clone, Copy)]
+pub enum MilestoneStatus {
+    #[default]
+    Pending,
+    EvidencePosted,
+    Released,
+    Refunded,
+}
+
+// 8 discriminator + (4 + 17) desc + 32 recipient + 8 withdrawn + 8 withdrawals + 8 last withdrawal + 1 version
+pub const TREASURY_RECIPIENT_SPACE: usize = 8 + 4 + 17 + 32 + 8 + 8 + 8 + 1;
+
+// Treasury recipient PDA - running total of what the treasury has paid to one wallet
+// Seeds for this will be [b"treasuryRecipient".as_ref(), recipient.key().as_ref()], current_program.key()
 #[account]
-#[derive(Default)]
-pub struct NftPDA {
-    mint: Pubkey, // "mint"
-    conservation_address: String,
+#[derive(Default, Debug)]
+pub struct TreasuryRecipient {
+    pub desc: String,            // "treasuryRecipient"
+    pub recipient: Pubkey,       // wallet receiving the withdrawals
+    pub withdrawn: u64,          // lamports withdrawn to this wallet
+    pub withdrawals: u64,        // number of withdrawals to this wallet
+    pub last_withdrawal_at: i64, // unix timestamp of the latest withdrawal
+    pub version: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum TreasuryInflow {
+    Sale,
+    Auction,
+    Presale,
+    Donation,
+    EscrowRefund,
+    Other,
+    GrantRefund,
 }
 
+#[event]
+pub struct TreasuryWithdrawal {
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub reason: String,
+    pub election: Pubkey, // default pubkey when the withdrawal is not an election grant
+    pub balance: u64,     // lamports left in the treasury
+    pub timestamp: i64,
+}
+
+// Seconds over which a grant stream vests - 30 days
+pub const GRANT_STREAM_DURATION: i64 = 30 * 24 * 60 * 60;
+// 8 discriminator + (4 + 11) desc + 32 archive + 32 recipient + 8 total + 8 claimed + 8 start + 8 end
+// + 1 cancelled + 1 version + 32 cancelled_by
+pub const GRANT_STREAM_SPACE: usize = 8 + 4 + 11 + 32 + 32 + 8 + 8 + 8 + 8 + 1 + 1 + 32;
+
+// Grant stream PDA - treasury lamports vesting linearly to the winner of an election
+// Seeds for this will be [b"grantStream".as_ref(), archive.key().as_ref()], current_program.key()
 #[account]
-#[derive(Default)]
-pub struct MintAuthority {
-    desc: String, // "mint authority"
+#[derive(Default, Debug)]
+pub struct GrantStream {
+    pub desc: String,      // "grantStream"
+    pub archive: Pubkey,   // archive of the election that was won
+    pub recipient: Pubkey, // winning organisation
+    pub total: u64, // lamports granted, reduced to what had vested if the stream is cancelled
+    pub claimed: u64, // lamports claimed so far
+    pub start: i64, // unix timestamp at which vesting starts
+    pub end: i64,   // unix timestamp at which everything has vested
+    pub cancelled: bool, // set when a later election votes to cancel the stream
+    pub version: u8,
+    pub cancelled_by: Pubkey, // archive of the election that voted to cancel the stream
 }
 
+impl GrantStream {
+    pub fn vested(&self, timestamp: i64) -> u64 {
+        if timestamp >= self.end {
+            return self.total;
+        }
+        if timestamp <= self.start {
+            return 0;
+        }
+        let elapsed = (timestamp - self.start) as u128;
+        let duration = (self.end - self.start) as u128;
+        (self.total as u128 * elapsed / duration) as u64
+    }
+
+    // Stops vesting at `timestamp`, keeping what has vested claimable, and returns the unvested remainder
+    pub fn cancel(&mut self, timestamp: i64, cancelled_by: Pubkey) -> u64 {
+        let vested = self.vested(timestamp);
+        let remainder = self.total - vested;
+        self.cancelled = true;
+        self.cancelled_by = cancelled_by;
+        self.total = vested;
+        self.end = timestamp;
+        remainder
+    }
+}
+
+// 8 discriminator + (4 + 19) desc + 32 grant stream + 32 election + 8 cancel votes + 8 keep votes + 1 version
+pub const STREAM_CANCELLATION_SPACE: usize = 8 + 4 + 19 + 32 + 32 + 8 + 8 + 1;
+
+// Stream cancellation PDA - a proposal to cancel a grant stream, voted on by the voters of a later election
+// Seeds for this will be [b"streamCancellation".as_ref(), grant_stream.key().as_ref(), election.key().as_ref()], current_program.key()
 #[account]
-#[derive(Default)]
-pub struct Treasury {
-    desc: String, // "treasury"
+#[derive(Default, Debug)]
+pub struct StreamCancellation {
+    pub desc: String,         // "stream cancellation"
+    pub grant_stream: Pubkey, // the grant stream that would be cancelled
+    pub election: Pubkey,     // the election pda whose voters decide
+    pub cancel_votes: u64,    // ballots for cancelling the stream
+    pub keep_votes: u64,      // ballots for keeping it
+    pub version: u8,
 }
 
-// Election PDA
-// Seeds for this will  be [b"election".as_ref(), date_of_election.to_string().as_bytes().as_ref()], current_program.key()
+impl StreamCancellation {
+    // The stream is only cancelled if more ballots were cast for cancelling than for keeping it
+    pub fn passed(&self) -> bool {
+        self.cancel_votes > self.keep_votes
+    }
+}
+
+// 8 discriminator + (4 + 6) desc + 32 cancellation + 32 vote + 1 cancel + 1 version
+pub const CANCELLATION_BALLOT_SPACE: usize = 8 + 4 + 6 + 32 + 32 + 1 + 1;
+
+// Cancellation ballot PDA - one per vote pda, so each nft that voted in the election has one ballot
+// Seeds for this will be [b"ballot".as_ref(), cancellation.key().as_ref(), vote.key().as_ref()], current_program.key()
 #[account]
-#[derive(Default)]
-pub struct Election {
-    desc: String, // "election"
-    date: i64,    // 26/02/2023
-    organisations: Organisations,
+#[derive(Default, Debug)]
+pub struct CancellationBallot {
+    pub desc: String,         // "ballot"
+    pub cancellation: Pubkey, // the stream cancellation voted on
+    pub vote: Pubkey,         // the vote pda that cast the ballot
+    pub cancel: bool,         // true for cancelling the stream, false for keeping it
+    pub version: u8,
 }
 
-// Vote PDA
-// Seeds for this will  be [mint.key().as_ref(), election.key().as_bytes().as_ref()], current_program.key()
+// 8 discriminator + (4 + 8) desc + 32 mint + 8 max supply + 8 price + 8 minted + 1 version
+pub const PRINT_RUN_SPACE: usize = 8 + 4 + 8 + 32 + 8 + 8 + 8 + 1;
+
+// Print run PDA - limited run of numbered prints of a master edition
+// Seeds for this will be [b"printRun".as_ref(), mint.key().as_ref()], current_program.key()
 #[account]
-#[derive(Default)]
-pub struct Vote {
-    desc: String,  // "vote" 32 + 4
-    mint: Pubkey,  // the mint address of the nft that qualifies the voter to vote 32 + 4
-    voter: Pubkey, // the address of the person voting 32 + 4
-    ata: Pubkey, // the ATA of the voter that contains the nft that qualifies the voter to vote 32 + 4
-    date: i64,
-    vote: Votes,
+#[derive(Default, Debug)]
+pub struct PrintRun {
+    pub desc: String,    // "printRun"
+    pub mint: Pubkey,    // mint of the master edition
+    pub max_supply: u64, // number of prints that can be minted, also set on the master edition
+    pub price: u64, // lamports per print, split between the conservation project and the treasury
+    pub minted: u64, // number of prints minted so far - the next print is edition minted + 1
+    pub version: u8,
+}
+
+// Token-2022 mint layout - the 82 byte mint is padded to the 165 byte token account length,
+// followed by 1 account type byte and the extensions as (2 type + 2 length + data) entries
+pub const MINT_2022_BASE_SPACE: usize = 165 + 1;
+// 32 authority + 32 metadata address
+pub const METADATA_POINTER_SPACE: usize = 2 + 2 + 32 + 32;
+// 32 authority + 32 hook program id
+pub const TRANSFER_HOOK_SPACE: usize = 2 + 2 + 32 + 32;
+
+// Token-2022 instruction tags for the extensions spl-token-2022 0.5 has no builders for
+pub const TRANSFER_HOOK_EXTENSION: u8 = 36;
+pub const METADATA_POINTER_EXTENSION: u8 = 39;
+// spl-token-metadata-interface Initialize - first 8 bytes of
+// sha256("spl_token_metadata_interface:initialize_account")
+pub const TOKEN_METADATA_INITIALIZE: [u8; 8] = [210, 225, 30, 162, 88, 184, 77, 141];
+
+// Programs used for compressed nfts
+// BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY
+pub const BUBBLEGUM_ID: Pubkey = Pubkey::new_from_array([
+    152, 139, 128, 235, 121, 53, 40, 105, 178, 36, 116, 95, 89, 221, 191, 138, 38, 88, 202, 19,
+    220, 104, 129, 33, 38, 53, 28, 174, 7, 193, 165, 165,
+]);
+// cmtkDGRgzfahEAPJ5YdoTgG3iRJbXkKZG6o39xqiZjZ
+pub const COMPRESSION_ID: Pubkey = Pubkey::new_from_array([
+    9, 42, 22, 137, 198, 201, 59, 188, 180, 211, 232, 142, 13, 224, 20, 52, 225, 5, 160, 215, 70,
+    233, 76, 16, 67, 155, 191, 69, 44, 20, 169, 236,
+]);
+// noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV
+pub const NOOP_ID: Pubkey = Pubkey::new_from_array([
+    11, 188, 15, 192, 187, 71, 202, 47, 116, 196, 17, 46, 148, 171, 19, 207, 163, 198, 52, 229,
+    220, 23, 234, 203, 3, 205, 26, 35, 205, 126, 120, 124,
+]);
+
+// Anchor instruction discriminators - first 8 bytes of sha256("global:<instruction name>")
+pub const BUBBLEGUM_CREATE_TREE: [u8; 8] = [165, 83, 136, 142, 89, 202, 47, 220];
+pub const BUBBLEGUM_MINT_V1: [u8; 8] = [145, 98, 192, 118, 184, 147, 118, 104];
+pub const BUBBLEGUM_TRANSFER: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+pub const COMPRESSION_VERIFY_LEAF: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+// 8 discriminator + (4 + 14) desc + 32 merkle tree + (4 + 32) family + 32 project + 8 price
+// + 8 minted + 8 sold + 1 version
+pub const COMPRESSED_TREE_SPACE: usize = 8 + 4 + 14 + 32 + 4 + 32 + 32 + 8 + 8 + 8 + 1;
+
+// Compressed tree PDA - a bubblegum merkle tree holding the compressed nfts of one animal family
+// Seeds for this will be [b"compressedTree".as_ref(), merkle_tree.key().as_ref()], current_program.key()
+#[account]
+#[derive(Default, Debug)]
+pub struct CompressedTree {
+    pub desc: String,        // "compressedTree"
+    pub merkle_tree: Pubkey, // the bubblegum merkle tree
+    pub family: String,      // animal family the tree is for, e.g. "pink pigeon", max 32 bytes
+    pub project: Pubkey,     // wallet of the conservation project, receives its share of sales
+    pub price: u64,          // lamports per compressed nft
+    pub minted: u64,         // number of nfts minted - also the nonce of the next leaf
+    pub sold: u64,           // number of nfts sold
+    pub version: u8,
+}
+
+// Leaf of a compressed nft and the root it was read against - what bubblegum needs to verify
+// the leaf, with the proof nodes passed as remaining accounts
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
+pub struct CompressedLeaf {
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub nonce: u64,
+    pub index: u32,
+}
+
+impl CompressedLeaf {
+    // Hash of a bubblegum LeafSchema::V1 leaf
+    pub fn hash(&self, asset_id: &Pubkey, owner: &Pubkey, delegate: &Pubkey) -> [u8; 32] {
+        keccak::hashv(&[
+            &[1], // LeafSchema version V1
+            asset_id.as_ref(),
+            owner.as_ref(),
+            delegate.as_ref(),
+            self.nonce.to_le_bytes().as_ref(),
+            self.data_hash.as_ref(),
+            self.creator_hash.as_ref(),
+        ])
+        .to_bytes()
+    }
+}
+
+// Asset id bubblegum derives for the leaf with this nonce
+pub fn compressed_asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"asset", merkle_tree.as_ref(), nonce.to_le_bytes().as_ref()],
+        &BUBBLEGUM_ID,
+    )
+    .0
+}
+
+// Bubblegum's MetadataArgs - enums are written as their variant index
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
+pub struct BubblegumMetadataArgs {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<u8>,
+    pub collection: Option<(bool, Pubkey)>, // (verified, key)
+    pub uses: Option<(u8, u64, u64)>,       // (use method, remaining, total)
+    pub token_program_version: u8,
+    pub creators: Vec<BubblegumCreator>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
+pub struct BubblegumCreator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub share: u8,
+}
+
+#[event]
+pub struct CompressedNftMinted {
+    pub merkle_tree: Pubkey,
+    pub asset_id: Pubkey,
+    pub nonce: u64,
+    pub name: String,
+    pub uri: String,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
 pub struct Organisations {
-    mauritius_wildlife_protection: Organisation,
-    mauritius_nature_protection_society: Organisation,
-    mauritius_marine_life_protection: Organisation,
+    pub mauritius_wildlife_protection: Organisation,
+    pub mauritius_nature_protection_society: Organisation,
+    pub mauritius_marine_life_protection: Organisation,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
 pub struct Organisation {
-    name: String,
-    address: Pubkey,
-    votes: u64,
+    pub name: String,
+    pub address: Pubkey,
+    pub votes: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
 pub struct Votes {
-    mauritius_wildlife_protection: UserVote,
-    mauritius_nature_protection_society: UserVote,
-    mauritius_marine_life_protection: UserVote,
+    pub mauritius_wildlife_protection: UserVote,
+    pub mauritius_nature_protection_society: UserVote,
+    pub mauritius_marine_life_protection: UserVote,
+}
+
+impl Votes {
+    // Builds the votes of a voter from (amount, organisation address) pairs - at most 100 in total
+    pub fn new(
+        mauritius_wildlife_protection: (u32, Pubkey),
+        mauritius_nature_protection_society: (u32, Pubkey),
+        mauritius_marine_life_protection: (u32, Pubkey),
+    ) -> Result<Votes> {
+        msg!("Validating that the amount of votes passed in is <= 100");
+        if mauritius_wildlife_protection.0 as u64
+            + mauritius_nature_protection_society.0 as u64
+            + mauritius_marine_life_protection.0 as u64
+            > 100
+        {
+            return err!(InvalidVoteError::VoteTooLarge);
+        }
+        msg!("Validated the number of votes passed in");
+
+        Ok(Votes {
+            mauritius_wildlife_protection: UserVote {
+                amount: mauritius_wildlife_protection.0,
+                organisation_name: String::from("mauritius_wildlife_protection"),
+                organisation_address: mauritius_wildlife_protection.1,
+            },
+            mauritius_nature_protection_society: UserVote {
+                amount: mauritius_nature_protection_society.0,
+                organisation_name: String::from("mauritius_nature_protection_society"),
+                organisation_address: mauritius_nature_protection_society.1,
+            },
+            mauritius_marine_life_protection: UserVote {
+                amount: mauritius_marine_life_protection.0,
+                organisation_name: String::from("mauritius_marine_life_protection"),
+                organisation_address: mauritius_marine_life_protection.1,
+            },
+        })
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Debug, Default, Clone)]
 pub struct UserVote {
-    amount: u32,                  // amount of votes allocated 4
-    organisation_name: String,    // name of the organisation being voted for
-    organisation_address: Pubkey, // address of the organisation being voted for 4 + 32
+    pub amount: u32,                  // amount of votes allocated 4
+    pub organisation_name: String,    // name of the organisation being voted for
+    pub organisation_address: Pubkey, // address of the organisation being voted for 4 + 32
 }
 
 #[error_code]
@@ -852,4 +6913,630 @@ pub enum InvalidVoteError {
 pub enum InvalidElectionError {
     #[msg("Passed in date is in the past")]
     InvalidDate,
+    #[msg("Election is still open")]
+    ElectionNotOver,
+    #[msg("Election has already been finalized")]
+    AlreadyFinalized,
+    #[msg("Election has not been finalized")]
+    NotFinalized,
+    #[msg("Grace period after the election was finalized has not passed yet")]
+    GracePeriodNotOver,
+}
+
+#[error_code]
+pub enum InvalidAuctionError {
+    #[msg("Floor price must not be higher than the start price")]
+    InvalidPrice,
+    #[msg("Auction duration must be greater than zero")]
+    InvalidDuration,
+    #[msg("Passed in start date is in the past")]
+    InvalidDate,
+    #[msg("Auction has not started yet")]
+    AuctionNotStarted,
+    #[msg("NFT is on auction and can only be bought through the auction")]
+    NftOnAuction,
+    #[msg("NFT is not on auction")]
+    NftNotOnAuction,
+}
+
+#[error_code]
+pub enum InvalidPaymentError {
+    #[msg("Price must be greater than zero")]
+    InvalidPrice,
+    #[msg("Payments with this mint are currently disabled")]
+    PaymentMintDisabled,
+}
+
+#[error_code]
+pub enum InvalidProjectError {
+    #[msg("Project name is too long - max 50 bytes")]
+    NameTooLong,
+    #[msg("Project is not active")]
+    ProjectInactive,
+    #[msg("Project account is not the conservation project of the nft")]
+    ProjectMismatch,
+}
+
+#[error_code]
+pub enum InvalidDonationError {
+    #[msg("Donation amount must be greater than zero")]
+    EmptyDonation,
+    #[msg("Memo is too long - max 100 bytes")]
+    MemoTooLong,
+}
+
+#[error_code]
+pub enum InvalidBadgeError {
+    #[msg("Badge name, symbol or uri is too long")]
+    MetadataTooLong,
+    #[msg("Badge threshold must be greater than zero")]
+    InvalidThreshold,
+    #[msg("Donor has not given enough to claim this badge")]
+    ThresholdNotReached,
+}
+
+#[error_code]
+pub enum InvalidPresaleError {
+    #[msg("Presale end must not be before presale start")]
+    InvalidWindow,
+    #[msg("Presale is not running")]
+    PresaleNotActive,
+    #[msg("Presale is running - purchases need an allowlist proof")]
+    PresaleActive,
+    #[msg("Wallet is not on the presale allowlist")]
+    InvalidProof,
+    #[msg("Wallet has reached its presale purchase limit")]
+    PresaleLimitReached,
+}
+
+#[error_code]
+pub enum InvalidPurchaseError {
+    #[msg("Slot window must be greater than zero when a per window limit is set")]
+    InvalidSlotWindow,
+    #[msg("Wallet has reached the maximum number of nfts it can buy")]
+    WalletLimitReached,
+    #[msg("Too many purchases from this wallet - try again in a few slots")]
+    RateLimited,
+}
+
+#[error_code]
+pub enum InvalidReservationError {
+    #[msg("Reservation duration must be greater than zero")]
+    InvalidDuration,
+    #[msg("Reservations have not been enabled")]
+    ReservationsDisabled,
+    #[msg("NFT has already been sold")]
+    NftSold,
+    #[msg("NFT is reserved for another buyer")]
+    NftReserved,
+    #[msg("Reservation deposit must be greater than zero")]
+    InvalidDeposit,
+    #[msg("Wallet reserved this NFT recently - wait for the cooldown to end")]
+    CooldownActive,
+}
+
+#[error_code]
+pub enum InvalidBurnError {
+    #[msg("Burn reason is too long - max 100 bytes")]
+    ReasonTooLong,
+    #[msg("Reservation buyer does not match the nft's reservation")]
+    InvalidReservationBuyer,
+    #[msg("Name record does not belong to the nft")]
+    InvalidNameRecord,
+}
+
+#[error_code]
+pub enum InvalidConfigError {
+    #[msg("This instruction is paused")]
+    ProgramPaused,
+    #[msg("Only the admin or the guardian can do this")]
+    Unauthorized,
+}
+
+#[error_code]
+pub enum InvalidMigrationError {
+    #[msg("Account is already on the current version")]
+    AlreadyMigrated,
+    #[msg("Conservation address stored on the nft pda is not a valid pubkey")]
+    InvalidConservationAddress,
+}
+
+#[error_code]
+pub enum InvalidEcologyError {
+    #[msg("Region code must be an ISO 3166-2 code of at most 6 bytes")]
+    InvalidRegionCode,
+    #[msg("Geohash must be at most 12 base32 characters")]
+    InvalidGeohash,
+    #[msg("Animal nfts must have a species id")]
+    MissingSpecies,
+}
+
+#[error_code]
+pub enum InvalidStatusError {
+    #[msg("Evidence uri is too long - max 200 bytes")]
+    UriTooLong,
+    #[msg("Field reporter is not active")]
+    ReporterInactive,
+    #[msg("Status log has no update at this index")]
+    UpdateNotFound,
+}
+
+#[error_code]
+pub enum InvalidEscrowError {
+    #[msg("An escrow needs between 1 and 10 milestones")]
+    InvalidMilestoneCount,
+    #[msg("Milestone basis points must add up to 10000")]
+    InvalidBasisPoints,
+    #[msg("Milestone deadline is in the past")]
+    InvalidDeadline,
+    #[msg("Evidence uri is too long - max 100 bytes")]
+    UriTooLong,
+    #[msg("Milestone does not exist")]
+    InvalidMilestone,
+    #[msg("Milestone has already been released or refunded")]
+    MilestoneSettled,
+    #[msg("Milestone deadline has passed")]
+    DeadlinePassed,
+    #[msg("Milestone deadline has not passed yet")]
+    DeadlineNotPassed,
+    #[msg("No evidence has been posted for this milestone")]
+    EvidenceNotPosted,
+    #[msg("Escrowed nfts can only be bought with sol")]
+    EscrowRequiresSol,
+}
+
+#[error_code]
+pub enum InvalidTreasuryError {
+    #[msg("Withdrawal amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Withdrawal reason is too long - max 100 bytes")]
+    ReasonTooLong,
+    #[msg("Withdrawal would leave the treasury below rent exemption")]
+    BelowRentExemption,
+}
+
+#[error_code]
+pub enum InvalidGrantError {
+    #[msg("Nothing has vested since the last claim")]
+    NothingVested,
+    #[msg("The cancelling election must be later than the one the grant was opened for")]
+    ElectionNotLater,
+    #[msg("The grant stream has already ended or been cancelled")]
+    StreamEnded,
+    #[msg("The later election did not vote to cancel the stream")]
+    CancellationNotPassed,
+    #[msg("Only the wallet that cast the vote can cast its ballot")]
+    NotVoter,
+}
+
+#[error_code]
+pub enum InvalidEditionError {
+    #[msg("Edition number must be the next one in the print run")]
+    InvalidEditionNumber,
+    #[msg("All prints of this master edition have been minted")]
+    SoldOut,
+    #[msg("Prints can only be minted while the master edition is held by the program")]
+    MasterEditionSold,
+    #[msg("Print price must be greater than zero")]
+    InvalidPrice,
+}
+
+#[error_code]
+pub enum InvalidTokenInterfaceError {
+    #[msg("Token program must be SPL Token or Token-2022")]
+    InvalidTokenProgram,
+    #[msg("Account is not owned by the token program passed in")]
+    InvalidOwner,
+    #[msg("Mint is not an nft - it must have 0 decimals and a supply of 1")]
+    NotAnNft,
+    #[msg("Token account does not hold the nft")]
+    NftNotHeld,
+}
+
+#[error_code]
+pub enum InvalidVoteReceiptError {
+    #[msg("Receipt name, symbol or uri is too long")]
+    MetadataTooLong,
+    #[msg("Only the wallet that cast the vote can claim its receipt")]
+    NotVoter,
+    #[msg("The receipt can't be changed once voters have claimed it")]
+    ReceiptsClaimed,
+}
+
+#[error_code]
+pub enum InvalidNameError {
+    #[msg("Nft name must not be empty")]
+    EmptyName,
+    #[msg("Nft name is too long - max 32 bytes")]
+    NameTooLong,
+}
+
+#[error_code]
+pub enum InvalidCompressedError {
+    #[msg("Animal family is too long - max 32 bytes")]
+    FamilyTooLong,
+    #[msg("Price of a compressed nft must be greater than zero")]
+    InvalidPrice,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalise_name_ignores_case_and_extra_whitespace() {
+        assert_eq!(normalise_name("Pink Pigeon"), "pink pigeon");
+        assert_eq!(normalise_name("  PINK \t pigeon\n"), "pink pigeon");
+        assert_eq!(normalise_name("Écho Parakeet"), "écho parakeet");
+        assert_eq!(normalise_name(" \t\n"), "");
+    }
+
+    #[test]
+    fn name_hash_is_the_keccak_of_the_normalised_name() {
+        assert_eq!(
+            name_hash("  Pink   PIGEON "),
+            keccak::hash(b"pink pigeon").to_bytes()
+        );
+        assert_eq!(name_hash("Pink Pigeon"), name_hash("pink pigeon"));
+        assert_ne!(name_hash("Pink Pigeon"), name_hash("PinkPigeon"));
+    }
+
+    #[test]
+    fn names_are_registered_up_to_32_bytes() {
+        let mint = Pubkey::new_unique();
+        let mut record = NameRecord::default();
+        record
+            .register(&"a".repeat(MAX_NFT_NAME_LENGTH), mint, Some(1))
+            .unwrap();
+        assert_eq!(record.mint, mint);
+        assert_eq!(record.nft_id, Some(1));
+
+        assert!(NameRecord::default()
+            .register(&"a".repeat(MAX_NFT_NAME_LENGTH + 1), mint, Some(1))
+            .is_err());
+        assert!(NameRecord::default().register("   ", mint, None).is_err());
+    }
+
+    fn escrow(basis_points: &[u16]) -> Escrow {
+        Escrow {
+            milestones: basis_points
+                .iter()
+                .map(|&basis_points| Milestone {
+                    basis_points,
+                    ..Milestone::default()
+                })
+                .collect(),
+            ..Escrow::default()
+        }
+    }
+
+    #[test]
+    fn deposits_after_a_release_are_paid_out_straight_away() {
+        let mut escrow = escrow(&[3000, 7000]);
+        assert_eq!(escrow.deposit(1000), (0, 0));
+        assert_eq!(escrow.settle(0, MilestoneStatus::Released).unwrap(), 300);
+
+        assert_eq!(escrow.deposit(500), (150, 0));
+        assert_eq!(escrow.deposit(100), (30, 0));
+        assert_eq!(escrow.milestones[0].paid, 480);
+        assert_eq!(escrow.entitlement(0), 480);
+        assert_eq!(escrow.milestones[1].paid, 0);
+        assert_eq!(escrow.settled, 480);
+        assert_eq!(escrow.deposited, 1600);
+
+        assert_eq!(escrow.settle(1, MilestoneStatus::Released).unwrap(), 1120);
+        assert_eq!(escrow.settled, escrow.deposited);
+        assert!(escrow.settle(1, MilestoneStatus::Refunded).is_err());
+    }
+
+    #[test]
+    fn the_last_milestone_absorbs_the_rounding() {
+        let mut escrow = escrow(&[3333, 3333, 3334]);
+        escrow.deposit(10);
+        assert_eq!(escrow.entitlement(0), 3);
+        assert_eq!(escrow.entitlement(1), 3);
+        assert_eq!(escrow.entitlement(2), 4);
+
+        let paid: u64 = (0..3)
+            .map(|index| escrow.settle(index, MilestoneStatus::Released).unwrap())
+            .sum();
+        assert_eq!(paid, 10);
+        assert_eq!(escrow.settled, escrow.deposited);
+    }
+
+    #[test]
+    fn deposits_after_a_refund_go_to_the_treasury() {
+        let mut escrow = escrow(&[5000, 5000]);
+        escrow.deposit(1000);
+        assert_eq!(escrow.settle(1, MilestoneStatus::Refunded).unwrap(), 500);
+
+        assert_eq!(escrow.deposit(200), (0, 100));
+        assert_eq!(escrow.milestones[1].paid, 600);
+        assert_eq!(escrow.settle(0, MilestoneStatus::Released).unwrap(), 600);
+        assert_eq!(escrow.deposit(10), (5, 5));
+    }
+
+    #[test]
+    fn an_escrow_never_settles_more_than_was_deposited() {
+        let mut escrow = escrow(&[1, 2499, 3333, 4167]);
+        let statuses = [
+            MilestoneStatus::Released,
+            MilestoneStatus::Refunded,
+            MilestoneStatus::Released,
+            MilestoneStatus::Refunded,
+        ];
+        for (index, status) in statuses.into_iter().enumerate() {
+            for amount in [7, 1, 999_999_937] {
+                escrow.deposit(amount);
+                assert!(escrow.settled <= escrow.deposited);
+            }
+            escrow.settle(index as u8, status).unwrap();
+            assert!(escrow.settled <= escrow.deposited);
+        }
+        assert_eq!(escrow.settled, escrow.deposited);
+        assert_eq!(
+            escrow
+                .milestones
+                .iter()
+                .map(|milestone| milestone.paid)
+                .sum::<u64>(),
+            escrow.settled
+        );
+    }
+
+    fn auction(start_price: u64, floor_price: u64) -> Auction {
+        Auction {
+            start_price,
+            floor_price,
+            start_date: 1_000,
+            duration: 100,
+            ..Auction::default()
+        }
+    }
+
+    #[test]
+    fn auction_price_holds_at_the_start_price_until_the_start() {
+        let auction = auction(1_000, 100);
+        assert_eq!(auction.current_price(0), 1_000);
+        assert_eq!(auction.current_price(999), 1_000);
+        assert_eq!(auction.current_price(1_000), 1_000);
+    }
+
+    #[test]
+    fn auction_price_falls_linearly_over_the_duration() {
+        let auction = auction(1_000, 100);
+        assert_eq!(auction.current_price(1_001), 991);
+        assert_eq!(auction.current_price(1_050), 550);
+        assert_eq!(auction.current_price(1_099), 109);
+    }
+
+    #[test]
+    fn auction_price_stays_at_the_floor_from_the_end_of_the_duration() {
+        let auction = auction(1_000, 100);
+        assert_eq!(auction.current_price(1_100), 100);
+        assert_eq!(auction.current_price(1_101), 100);
+        assert_eq!(auction.current_price(i64::MAX), 100);
+    }
+
+    #[test]
+    fn auction_price_is_flat_when_the_start_price_is_the_floor() {
+        let auction = auction(500, 500);
+        for timestamp in [0, 1_000, 1_050, 1_100, 2_000] {
+            assert_eq!(auction.current_price(timestamp), 500);
+        }
+    }
+
+    fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            keccak::hashv(&[&a, &b]).0
+        } else {
+            keccak::hashv(&[&b, &a]).0
+        }
+    }
+
+    // Allowlist leaves and the root of the merkle tree over them
+    fn allowlist() -> ([[u8; 32]; 4], [u8; 32]) {
+        let leaves = [(); 4].map(|_| keccak::hash(Pubkey::new_unique().as_ref()).0);
+        let root = hash_pair(
+            hash_pair(leaves[0], leaves[1]),
+            hash_pair(leaves[2], leaves[3]),
+        );
+        (leaves, root)
+    }
+
+    #[test]
+    fn merkle_proofs_of_allowlisted_wallets_verify() {
+        let (leaves, root) = allowlist();
+        let proof = [leaves[1], hash_pair(leaves[2], leaves[3])];
+        assert!(verify_merkle_proof(&proof, root, leaves[0]));
+        let proof = [leaves[2], hash_pair(leaves[0], leaves[1])];
+        assert!(verify_merkle_proof(&proof, root, leaves[3]));
+    }
+
+    #[test]
+    fn merkle_proofs_fail_for_another_leaf() {
+        let (leaves, root) = allowlist();
+        let proof = [leaves[1], hash_pair(leaves[2], leaves[3])];
+        assert!(!verify_merkle_proof(&proof, root, leaves[2]));
+        let outsider = keccak::hash(Pubkey::new_unique().as_ref()).0;
+        assert!(!verify_merkle_proof(&proof, root, outsider));
+    }
+
+    #[test]
+    fn an_empty_merkle_proof_only_verifies_the_root_itself() {
+        let (leaves, root) = allowlist();
+        assert!(verify_merkle_proof(&[], root, root));
+        assert!(!verify_merkle_proof(&[], root, leaves[0]));
+    }
+
+    #[test]
+    fn merkle_pairs_are_hashed_in_sorted_order() {
+        let (leaves, _) = allowlist();
+        let (low, high) = if leaves[0] <= leaves[1] {
+            (leaves[0], leaves[1])
+        } else {
+            (leaves[1], leaves[0])
+        };
+        let root = keccak::hashv(&[&low, &high]).0;
+        assert!(verify_merkle_proof(&[high], root, low));
+        assert!(verify_merkle_proof(&[low], root, high));
+
+        let unsorted_root = keccak::hashv(&[&high, &low]).0;
+        assert!(!verify_merkle_proof(&[high], unsorted_root, low));
+        assert!(!verify_merkle_proof(&[low], unsorted_root, high));
+    }
+
+    #[test]
+    fn purchases_stop_at_the_wallet_limit() {
+        let config = ProgramConfig {
+            max_nfts_per_wallet: 2,
+            ..ProgramConfig::default()
+        };
+        let mut counter = PurchaseCounter::default();
+        counter.record_purchase(&config, 1).unwrap();
+        counter.record_purchase(&config, 2).unwrap();
+        assert_eq!(
+            counter.record_purchase(&config, 3).unwrap_err(),
+            error!(InvalidPurchaseError::WalletLimitReached)
+        );
+        assert_eq!(counter.total_purchases, 2);
+    }
+
+    #[test]
+    fn the_purchase_window_resets_at_its_last_slot() {
+        let config = ProgramConfig {
+            max_purchases_per_window: 1,
+            slot_window: 10,
+            ..ProgramConfig::default()
+        };
+        let mut counter = PurchaseCounter::default();
+        counter.record_purchase(&config, 100).unwrap();
+        assert_eq!(counter.window_start_slot, 100);
+        assert_eq!(
+            counter.record_purchase(&config, 109).unwrap_err(),
+            error!(InvalidPurchaseError::RateLimited)
+        );
+        counter.record_purchase(&config, 110).unwrap();
+        assert_eq!(counter.window_start_slot, 110);
+        assert_eq!(counter.window_purchases, 1);
+        assert_eq!(counter.total_purchases, 2);
+    }
+
+    #[test]
+    fn a_saturated_purchase_window_never_resets() {
+        let config = ProgramConfig {
+            max_purchases_per_window: 1,
+            slot_window: u64::MAX,
+            ..ProgramConfig::default()
+        };
+        let mut counter = PurchaseCounter {
+            window_start_slot: 5,
+            ..PurchaseCounter::default()
+        };
+        counter.record_purchase(&config, 5).unwrap();
+        assert_eq!(
+            counter.record_purchase(&config, u64::MAX - 1).unwrap_err(),
+            error!(InvalidPurchaseError::RateLimited)
+        );
+        assert_eq!(counter.window_start_slot, 5);
+    }
+
+    // Leaderboard of LEADERBOARD_SIZE donors who gave 100, 200, ... lamports
+    fn full_leaderboard() -> Leaderboard {
+        let mut leaderboard = Leaderboard::default();
+        for rank in 1..=LEADERBOARD_SIZE as u64 {
+            leaderboard.record(Pubkey::new_unique(), rank * 100);
+        }
+        leaderboard
+    }
+
+    fn totals(leaderboard: &Leaderboard) -> Vec<u64> {
+        leaderboard
+            .entries
+            .iter()
+            .map(|entry| entry.lamports_given)
+            .collect()
+    }
+
+    #[test]
+    fn the_leaderboard_updates_a_donor_already_on_it() {
+        let donor = Pubkey::new_unique();
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.record(donor, 100);
+        leaderboard.record(Pubkey::new_unique(), 200);
+        leaderboard.record(donor, 300);
+
+        assert_eq!(leaderboard.entries.len(), 2);
+        assert_eq!(leaderboard.entries[0].wallet, donor);
+        assert_eq!(totals(&leaderboard), [300, 200]);
+    }
+
+    #[test]
+    fn a_donor_joining_a_full_leaderboard_replaces_the_lowest() {
+        let mut leaderboard = full_leaderboard();
+        let donor = Pubkey::new_unique();
+        leaderboard.record(donor, 550);
+
+        assert_eq!(leaderboard.entries.len(), LEADERBOARD_SIZE);
+        assert_eq!(leaderboard.entries[5].wallet, donor);
+        assert_eq!(
+            totals(&leaderboard),
+            [1000, 900, 800, 700, 600, 550, 500, 400, 300, 200]
+        );
+    }
+
+    #[test]
+    fn a_full_leaderboard_ignores_donors_not_above_the_lowest() {
+        let mut leaderboard = full_leaderboard();
+        let before = leaderboard.entries.clone();
+        leaderboard.record(Pubkey::new_unique(), 50);
+        leaderboard.record(Pubkey::new_unique(), 100);
+        assert_eq!(leaderboard.entries, before);
+    }
+
+    #[test]
+    fn the_leaderboard_is_sorted_highest_first() {
+        let mut leaderboard = Leaderboard::default();
+        for lamports_given in [300, 100, 700, 200, 700, 50] {
+            leaderboard.record(Pubkey::new_unique(), lamports_given);
+        }
+        assert_eq!(totals(&leaderboard), [700, 700, 300, 200, 100, 50]);
+        assert_eq!(leaderboard.desc, "leaderboard");
+    }
+
+    fn grant_stream() -> GrantStream {
+        GrantStream {
+            total: 1_000_000,
+            start: 1_000,
+            end: 1_000 + GRANT_STREAM_DURATION,
+            ..GrantStream::default()
+        }
+    }
+
+    #[test]
+    fn grant_streams_vest_linearly_from_start_to_end() {
+        let stream = grant_stream();
+        assert_eq!(stream.vested(0), 0);
+        assert_eq!(stream.vested(stream.start), 0);
+        assert_eq!(
+            stream.vested(stream.start + GRANT_STREAM_DURATION / 2),
+            500_000
+        );
+        assert_eq!(stream.vested(stream.end), 1_000_000);
+        assert_eq!(stream.vested(i64::MAX), 1_000_000);
+    }
+
+    #[test]
+    fn a_cancelled_grant_stream_stops_vesting() {
+        let mut stream = grant_stream();
+        let later_archive = Pubkey::new_unique();
+        let cancelled_at = stream.start + GRANT_STREAM_DURATION / 4;
+        assert_eq!(stream.cancel(cancelled_at, later_archive), 750_000);
+
+        assert!(stream.cancelled);
+        assert_eq!(stream.cancelled_by, later_archive);
+        assert_eq!(stream.vested(cancelled_at), 250_000);
+        assert_eq!(stream.vested(cancelled_at + 1), 250_000);
+        assert_eq!(stream.vested(stream.start + GRANT_STREAM_DURATION), 250_000);
+    }
 }
@@ -0,0 +1,305 @@
+import * as anchor from "@project-serum/anchor";
+import { Program } from "@project-serum/anchor";
+import {
+  computeCompressedNFTHash,
+  computeCreatorHash,
+  computeDataHash,
+  getLeafAssetId,
+  MetadataArgs,
+  TokenProgramVersion,
+  TokenStandard,
+} from "@metaplex-foundation/mpl-bubblegum";
+import {
+  ConcurrentMerkleTreeAccount,
+  createAllocTreeIx,
+  MerkleTree,
+} from "@solana/spl-account-compression";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web3.js";
+import { assert } from "chai";
+import { AnimalKingdom } from "../target/types/animal_kingdom";
+
+// Runs against a local validator with bubblegum, account compression and noop loaded from
+// tests/fixtures (see dump-programs.sh). The provider wallet must be the program's admin.
+const BUBBLEGUM_ID = new PublicKey("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+const COMPRESSION_ID = new PublicKey("cmtkDGRgzfahEAPJ5YdoTgG3iRJbXkKZG6o39xqiZjZ");
+const NOOP_ID = new PublicKey("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+const ORGANISATION = new PublicKey("EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ");
+
+const MAX_DEPTH = 5;
+const MAX_BUFFER_SIZE = 8;
+
+describe("compressed nfts", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const connection = provider.connection;
+  const program = anchor.workspace.AnimalKingdom as Program<AnimalKingdom>;
+  const admin = provider.wallet.publicKey;
+
+  const merkleTree = Keypair.generate();
+  const project = Keypair.generate();
+  const buyer = Keypair.generate();
+
+  const pda = (seeds: (Buffer | Uint8Array)[], programId = program.programId) =>
+    PublicKey.findProgramAddressSync(seeds, programId)[0];
+
+  const compressedTree = pda([Buffer.from("compressedTree"), merkleTree.publicKey.toBuffer()]);
+  const treeAuthority = pda([merkleTree.publicKey.toBuffer()], BUBBLEGUM_ID);
+  const config = pda([Buffer.from("config")]);
+  const treasury = pda([Buffer.from("treasury")]);
+  const leaderboard = pda([Buffer.from("leaderboard")]);
+
+  const metadata = (index: number): MetadataArgs => ({
+    name: `Pink Pigeon #${index}`,
+    symbol: "AK",
+    uri: `https://animal-kingdom.example/pink-pigeon/${index}.json`,
+    sellerFeeBasisPoints: 500,
+    primarySaleHappened: false,
+    isMutable: true,
+    editionNonce: null,
+    tokenStandard: TokenStandard.NonFungible,
+    collection: null,
+    uses: null,
+    tokenProgramVersion: TokenProgramVersion.Original,
+    creators: [{ address: project.publicKey, verified: false, share: 100 }],
+  });
+
+  // Rebuilds the tree off-chain from the current owner of each leaf to get a proof for one of them
+  const proofFor = async (owners: PublicKey[], index: number) => {
+    const leaves: Buffer[] = [];
+    for (let nonce = 0; nonce < 2 ** MAX_DEPTH; nonce++) {
+      if (nonce >= owners.length) {
+        leaves.push(Buffer.alloc(32));
+        continue;
+      }
+      const assetId = await getLeafAssetId(merkleTree.publicKey, new anchor.BN(nonce));
+      leaves.push(
+        computeCompressedNFTHash(
+          assetId,
+          owners[nonce],
+          owners[nonce],
+          new anchor.BN(nonce),
+          metadata(nonce)
+        )
+      );
+    }
+    const tree = new MerkleTree(leaves);
+    const { proof } = tree.getProof(index);
+    const account = await ConcurrentMerkleTreeAccount.fromAccountAddress(
+      connection,
+      merkleTree.publicKey
+    );
+    assert.ok(account.getCurrentRoot().equals(tree.root), "off-chain tree matches the chain");
+
+    return {
+      leaf: {
+        root: Array.from(tree.root),
+        dataHash: Array.from(computeDataHash(metadata(index))),
+        creatorHash: Array.from(computeCreatorHash(metadata(index).creators)),
+        nonce: new anchor.BN(index),
+        index,
+      },
+      proof: proof.map((node) => ({
+        pubkey: new PublicKey(node),
+        isSigner: false,
+        isWritable: false,
+      })),
+    };
+  };
+
+  before(async () => {
+    await connection.confirmTransaction(
+      await connection.requestAirdrop(buyer.publicKey, 10 * LAMPORTS_PER_SOL)
+    );
+
+    await program.methods
+      .setPurchaseLimits(10, 10, new anchor.BN(1))
+      .accounts({ config, systemProgram: SystemProgram.programId, payer: admin })
+      .rpc();
+    await program.methods
+      .registerProject("Pink pigeon recovery")
+      .accounts({
+        projectPda: pda([Buffer.from("project"), project.publicKey.toBuffer()]),
+        projectAccount: project.publicKey,
+        systemProgram: SystemProgram.programId,
+        payer: admin,
+      })
+      .rpc();
+  });
+
+  it("creates a tree and mints compressed nfts into it", async () => {
+    const allocTree = await createAllocTreeIx(
+      connection,
+      merkleTree.publicKey,
+      admin,
+      { maxDepth: MAX_DEPTH, maxBufferSize: MAX_BUFFER_SIZE },
+      0
+    );
+    await program.methods
+      .createCompressedTree("pink pigeon", MAX_DEPTH, MAX_BUFFER_SIZE, new anchor.BN(LAMPORTS_PER_SOL))
+      .accounts({
+        compressedTree,
+        merkleTree: merkleTree.publicKey,
+        treeAuthority,
+        projectPda: pda([Buffer.from("project"), project.publicKey.toBuffer()]),
+        projectAccount: project.publicKey,
+        bubblegumProgram: BUBBLEGUM_ID,
+        compressionProgram: COMPRESSION_ID,
+        logWrapper: NOOP_ID,
+        config,
+        systemProgram: SystemProgram.programId,
+        payer: admin,
+      })
+      .preInstructions([allocTree])
+      .signers([merkleTree])
+      .rpc();
+
+    for (let index = 0; index < 2; index++) {
+      const { name, symbol, uri, sellerFeeBasisPoints } = metadata(index);
+      await program.methods
+        .mintCompressedNft(name, symbol, uri, sellerFeeBasisPoints)
+        .accounts({
+          compressedTree,
+          merkleTree: merkleTree.publicKey,
+          treeAuthority,
+          bubblegumProgram: BUBBLEGUM_ID,
+          compressionProgram: COMPRESSION_ID,
+          logWrapper: NOOP_ID,
+          config,
+          systemProgram: SystemProgram.programId,
+          payer: admin,
+        })
+        .rpc();
+    }
+
+    const tree = await program.account.compressedTree.fetch(compressedTree);
+    assert.equal(tree.minted.toNumber(), 2);
+  });
+
+  it("sells a compressed nft with a proof", async () => {
+    const { leaf, proof } = await proofFor([compressedTree, compressedTree], 0);
+    const projectBalance = await connection.getBalance(project.publicKey);
+
+    await program.methods
+      .buyCompressedNft(leaf)
+      .accounts({
+        compressedTree,
+        merkleTree: merkleTree.publicKey,
+        treeAuthority,
+        projectAccount: project.publicKey,
+        treasuryAccount: treasury,
+        donorPda: pda([Buffer.from("donor"), buyer.publicKey.toBuffer()]),
+        leaderboard,
+        salePhase: pda([Buffer.from("salePhase")]),
+        config,
+        purchaseCounter: pda([Buffer.from("purchaseCounter"), buyer.publicKey.toBuffer()]),
+        bubblegumProgram: BUBBLEGUM_ID,
+        compressionProgram: COMPRESSION_ID,
+        logWrapper: NOOP_ID,
+        systemProgram: SystemProgram.programId,
+        payer: buyer.publicKey,
+      })
+      .remainingAccounts(proof)
+      .signers([buyer])
+      .rpc();
+
+    const tree = await program.account.compressedTree.fetch(compressedTree);
+    assert.equal(tree.sold.toNumber(), 1);
+    assert.equal(
+      await connection.getBalance(project.publicKey),
+      projectBalance + LAMPORTS_PER_SOL / 2
+    );
+    // The leaf now belongs to the buyer - the proof only checks out with the new owner
+    await proofFor([buyer.publicKey, compressedTree], 0);
+  });
+
+  it("lets the owner of a compressed nft vote with a proof", async () => {
+    const date = Math.floor(Date.now() / 1000) + 3600;
+    const electionPda = pda([Buffer.from("election"), Buffer.from(date.toString())]);
+    await program.methods
+      .createElection(new anchor.BN(date))
+      .accounts({
+        electionPda,
+        mauritiusWildlifeProtection: ORGANISATION,
+        mauritiusNatureProtectionSociety: ORGANISATION,
+        mauritiusMarineLifeProtection: ORGANISATION,
+        config,
+        systemProgram: SystemProgram.programId,
+        payer: admin,
+      })
+      .rpc();
+
+    const { leaf, proof } = await proofFor([buyer.publicKey, compressedTree], 0);
+    const asset = await getLeafAssetId(merkleTree.publicKey, new anchor.BN(0));
+    await program.methods
+      .createCompressedVote(new anchor.BN(date), leaf, 60, 30, 10)
+      .accounts({
+        votePda: pda([asset.toBuffer(), electionPda.toBuffer()]),
+        electionPda,
+        asset,
+        compressedTree,
+        merkleTree: merkleTree.publicKey,
+        leafDelegate: buyer.publicKey,
+        mauritiusWildlifeProtection: ORGANISATION,
+        mauritiusNatureProtectionSociety: ORGANISATION,
+        mauritiusMarineLifeProtection: ORGANISATION,
+        donorPda: pda([Buffer.from("donor"), buyer.publicKey.toBuffer()]),
+        config,
+        compressionProgram: COMPRESSION_ID,
+        systemProgram: SystemProgram.programId,
+        payer: buyer.publicKey,
+      })
+      .remainingAccounts(proof)
+      .signers([buyer])
+      .rpc();
+
+    const election = await program.account.election.fetch(electionPda);
+    assert.equal(election.organisations.mauritiusWildlifeProtection.votes.toNumber(), 60);
+  });
+
+  it("rejects a vote from a wallet that does not own the compressed nft", async () => {
+    const date = Math.floor(Date.now() / 1000) + 7200;
+    const electionPda = pda([Buffer.from("election"), Buffer.from(date.toString())]);
+    await program.methods
+      .createElection(new anchor.BN(date))
+      .accounts({
+        electionPda,
+        mauritiusWildlifeProtection: ORGANISATION,
+        mauritiusNatureProtectionSociety: ORGANISATION,
+        mauritiusMarineLifeProtection: ORGANISATION,
+        config,
+        systemProgram: SystemProgram.programId,
+        payer: admin,
+      })
+      .rpc();
+
+    // Leaf 1 is still owned by the compressed tree pda
+    const { leaf, proof } = await proofFor([buyer.publicKey, compressedTree], 1);
+    const asset = await getLeafAssetId(merkleTree.publicKey, new anchor.BN(1));
+    try {
+      await program.methods
+        .createCompressedVote(new anchor.BN(date), leaf, 100, 0, 0)
+        .accounts({
+          votePda: pda([asset.toBuffer(), electionPda.toBuffer()]),
+          electionPda,
+          asset,
+          compressedTree,
+          merkleTree: merkleTree.publicKey,
+          leafDelegate: buyer.publicKey,
+          mauritiusWildlifeProtection: ORGANISATION,
+          mauritiusNatureProtectionSociety: ORGANISATION,
+          mauritiusMarineLifeProtection: ORGANISATION,
+          donorPda: pda([Buffer.from("donor"), buyer.publicKey.toBuffer()]),
+          config,
+          compressionProgram: COMPRESSION_ID,
+          systemProgram: SystemProgram.programId,
+          payer: buyer.publicKey,
+        })
+        .remainingAccounts(proof)
+        .signers([buyer])
+        .rpc();
+      assert.fail("vote should have been rejected");
+    } catch (error) {
+      assert.notEqual(error.message, "vote should have been rejected");
+    }
+  });
+});
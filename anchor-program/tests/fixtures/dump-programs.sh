@@ -0,0 +1,17 @@
+#!/usr/bin/env bash
+# Dumps the programs the compressed nft tests run against from mainnet into tests/fixtures.
+# The .so files are gitignored - `yarn test` runs this before `anchor test --provider.cluster localnet`,
+# which loads them into the local validator (see [[test.genesis]] in Anchor.toml).
+# Programs that have already been dumped are kept, so only the first run needs mainnet.
+set -euo pipefail
+cd "$(dirname "$0")"
+
+dump() {
+  if [ ! -s "$2" ]; then
+    solana program dump -u m "$1" "$2"
+  fi
+}
+
+dump BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY mpl_bubblegum.so
+dump cmtkDGRgzfahEAPJ5YdoTgG3iRJbXkKZG6o39xqiZjZ spl_account_compression.so
+dump noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV spl_noop.so
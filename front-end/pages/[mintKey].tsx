@@ -11,6 +11,7 @@ import {
   getAssociatedTokenAddress,
 } from "@solana/spl-token";
 import { PROGRAM_ID } from "@/utils/constants";
+import { findNftMint } from "@/utils/nftMint";
 import Loading from "@/components/Loading";
 
 export default function NftPage() {
@@ -42,10 +43,6 @@ export default function NftPage() {
   const { connection } = useConnection();
   const walletAdapter = useWallet();
 
-  const TOKEN_METADATA_PROGRAM_ID = new web3.PublicKey(
-    "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
-  );
-
   useEffect(() => {
     if (!mintKey || !workspace.nfts || workspace.nfts.length === 0) return;
 
@@ -81,65 +78,69 @@ export default function NftPage() {
       return;
     }
 
-    const [treasuryKey, treasuryKeyBump] =
-      web3.PublicKey.findProgramAddressSync(
-        [Buffer.from(`treasury`)],
-        new PublicKey(PROGRAM_ID)
-      );
+    const programId = new PublicKey(PROGRAM_ID);
+    const findPda = (seeds: Buffer[]) =>
+      web3.PublicKey.findProgramAddressSync(seeds, programId)[0];
 
-    const projectKey = new PublicKey(
-      "EoXeTQoYCaskdP4UrkMx93A43NaUuUQtYDBeXj2HEtLQ"
-    );
+    // Nfts are found through the name registry, falling back to the name seeded mint of nfts minted before it
+    const mintKey = await findNftMint(workspace.program, nft.json.name);
+    const nftPda = findPda([Buffer.from(`nftPDA`), mintKey.toBuffer()]);
 
-    const [mintKey, mintKeyBump] = web3.PublicKey.findProgramAddressSync(
-      [Buffer.from(nft.json.name), new PublicKey(PROGRAM_ID).toBuffer()],
-      new PublicKey(PROGRAM_ID)
-    );
+    // The proceeds go to the conservation project recorded on the nft pda
+    const nftPdaAccount = await workspace.program.account.nftPda.fetch(nftPda);
+    const projectKey =
+      nftPdaAccount.version >= 1
+        ? nftPdaAccount.project
+        : new PublicKey(nftPdaAccount.conservationAddress);
 
-    const [nftPda, nftPdaBump] = web3.PublicKey.findProgramAddressSync(
-      [Buffer.from(`nftPDA`), mintKey.toBuffer()],
-      new PublicKey(PROGRAM_ID)
-    );
+    // Nfts can be minted with either the SPL Token or the Token-2022 program
+    const mintAccount = await connection.getAccountInfo(mintKey);
+    const tokenProgram = mintAccount ? mintAccount.owner : TOKEN_PROGRAM_ID;
 
     const userATA = await getAssociatedTokenAddress(
       mintKey, // Token mint account
       walletAdapter.publicKey, // Owner of the account
-      false // Allow the owner account to be a PDA (Program Derived Address)
+      false, // Allow the owner account to be a PDA (Program Derived Address)
+      tokenProgram
     );
 
     const programATA = await getAssociatedTokenAddress(
       mintKey, // Token mint account
       nftPda, // Owner of the account
-      true // Allow the owner account to be a PDA (Program Derived Address)
+      true, // Allow the owner account to be a PDA (Program Derived Address)
+      tokenProgram
     );
 
-    const masterEdition = web3.PublicKey.findProgramAddressSync(
-      [
-        Buffer.from("metadata"),
-        TOKEN_METADATA_PROGRAM_ID.toBuffer(),
-        mintKey.toBuffer(),
-        Buffer.from("edition"),
-      ],
-      TOKEN_METADATA_PROGRAM_ID
-    )[0];
-
     const accounts = {
       projectAccount: projectKey,
-      treasuryAccount: treasuryKey,
+      treasuryAccount: findPda([Buffer.from(`treasury`)]),
       fromAccount: programATA,
       toAccount: userATA,
       authorityAccount: nftPda,
       mint: mintKey,
-      mintAuthority: masterEdition,
-      currentProgram: new PublicKey(PROGRAM_ID),
-      tokenProgram: TOKEN_PROGRAM_ID,
+      mintAuthority: findPda([Buffer.from(`mint`)]),
+      currentProgram: programId,
+      tokenProgram: tokenProgram,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      donorPda: findPda([
+        Buffer.from(`donor`),
+        walletAdapter.publicKey.toBuffer(),
+      ]),
+      leaderboard: findPda([Buffer.from(`leaderboard`)]),
+      salePhase: findPda([Buffer.from(`salePhase`)]),
+      config: findPda([Buffer.from(`config`)]),
+      purchaseCounter: findPda([
+        Buffer.from(`purchaseCounter`),
+        walletAdapter.publicKey.toBuffer(),
+      ]),
+      reservation: findPda([Buffer.from(`reservation`), mintKey.toBuffer()]),
+      escrow: findPda([Buffer.from(`escrow`), mintKey.toBuffer()]),
       systemProgram: SystemProgram.programId,
       payer: walletAdapter.publicKey,
     };
 
     const transferInstruction = await workspace.program.methods
-      .transferNft(nft.json.name)
+      .transferNft()
       .accounts(accounts)
       .instruction();
 
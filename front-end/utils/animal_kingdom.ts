@@ -31,6 +31,11 @@ export type AnimalKingdom = {
     {
       name: "createMint";
       accounts: [
+        {
+          name: "nftCounter";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "mint";
           isMut: true;
@@ -85,12 +90,28 @@ export type AnimalKingdom = {
           name: "organisationWallet";
           isMut: true;
           isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nameRecord";
+          isMut: true;
+          isSigner: false;
         }
       ];
       args: [
         {
           name: "name";
           type: "string";
+        },
+        {
+          name: "ecology";
+          type: {
+            defined: "EcologicalData";
+          };
         }
       ];
     },
@@ -136,6 +157,16 @@ export type AnimalKingdom = {
           name: "currentProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nameRecord";
+          isMut: false;
+          isSigner: false;
         }
       ];
       args: [
@@ -170,6 +201,11 @@ export type AnimalKingdom = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "nftPda";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "tokenProgram";
           isMut: false;
@@ -204,12 +240,26 @@ export type AnimalKingdom = {
           name: "currentProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "printRun";
+          isMut: true;
+          isSigner: false;
         }
       ];
       args: [
         {
-          name: "name";
-          type: "string";
+          name: "maxSupply";
+          type: "u64";
+        },
+        {
+          name: "printPrice";
+          type: "u64";
         }
       ];
     },
@@ -260,11 +310,66 @@ export type AnimalKingdom = {
           name: "currentProgram";
           isMut: false;
           isSigner: false;
+        },
+        {
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "auctionPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reservation";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reservationBuyer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "printRun";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "statusLog";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nameRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         }
       ];
       args: [
         {
-          name: "name";
+          name: "reason";
           type: "string";
         }
       ];
@@ -276,9 +381,6 @@ export type AnimalKingdom = {
           name: "projectAccount";
           isMut: true;
           isSigner: false;
-          docs: [
-            "CHECK - this is dangerous - need to implement PDAs to hold information about verified conservation project addresses"
-          ];
         },
         {
           name: "treasuryAccount";
@@ -325,6 +427,41 @@ export type AnimalKingdom = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "salePhase";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "purchaseCounter";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reservation";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
+        },
         {
           name: "systemProgram";
           isMut: false;
@@ -336,12 +473,7 @@ export type AnimalKingdom = {
           isSigner: true;
         }
       ];
-      args: [
-        {
-          name: "name";
-          type: "string";
-        }
-      ];
+      args: [];
     },
     {
       name: "createElection";
@@ -366,6 +498,11 @@ export type AnimalKingdom = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "systemProgram";
           isMut: false;
@@ -402,6 +539,11 @@ export type AnimalKingdom = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "nftPda";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "mintAuthority";
           isMut: true;
@@ -412,6 +554,11 @@ export type AnimalKingdom = {
           isMut: true;
           isSigner: false;
         },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "mauritiusWildlifeProtection";
           isMut: false;
@@ -427,6 +574,16 @@ export type AnimalKingdom = {
           isMut: false;
           isSigner: false;
         },
+        {
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
         {
           name: "systemProgram";
           isMut: false;
@@ -444,10 +601,6 @@ export type AnimalKingdom = {
         }
       ];
       args: [
-        {
-          name: "nameOfNft";
-          type: "string";
-        },
         {
           name: "dateOfElection";
           type: "i64";
@@ -465,902 +618,9667 @@ export type AnimalKingdom = {
           type: "u32";
         }
       ];
-    }
-  ];
-  accounts: [
-    {
-      name: "nftPda";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "mint";
-            type: "publicKey";
-          },
-          {
-            name: "conservationAddress";
-            type: "string";
-          }
-        ];
-      };
-    },
-    {
-      name: "mintAuthority";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "desc";
-            type: "string";
-          }
-        ];
-      };
-    },
-    {
-      name: "treasury";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "desc";
-            type: "string";
-          }
-        ];
-      };
     },
     {
-      name: "election";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "desc";
-            type: "string";
-          },
-          {
-            name: "date";
-            type: "i64";
-          },
-          {
-            name: "organisations";
-            type: {
-              defined: "Organisations";
-            };
-          }
-        ];
-      };
+      name: "setVoteReceipt";
+      accounts: [
+        {
+          name: "receiptConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "electionPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "dateOfElection";
+          type: "i64";
+        },
+        {
+          name: "name";
+          type: "string";
+        },
+        {
+          name: "symbol";
+          type: "string";
+        },
+        {
+          name: "uri";
+          type: "string";
+        }
+      ];
     },
     {
-      name: "vote";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "desc";
-            type: "string";
-          },
-          {
-            name: "mint";
-            type: "publicKey";
-          },
-          {
-            name: "voter";
-            type: "publicKey";
-          },
-          {
-            name: "ata";
-            type: "publicKey";
-          },
-          {
-            name: "date";
-            type: "i64";
-          },
-          {
-            name: "vote";
-            type: {
-              defined: "Votes";
-            };
-          }
-        ];
-      };
-    }
-  ];
-  types: [
-    {
-      name: "Organisations";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "mauritiusWildlifeProtection";
-            type: {
-              defined: "Organisation";
-            };
-          },
-          {
-            name: "mauritiusNatureProtectionSociety";
-            type: {
-              defined: "Organisation";
-            };
-          },
-          {
-            name: "mauritiusMarineLifeProtection";
-            type: {
-              defined: "Organisation";
-            };
-          }
-        ];
-      };
-    },
-    {
-      name: "Organisation";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "address";
-            type: "publicKey";
-          },
-          {
-            name: "votes";
-            type: "u64";
-          }
-        ];
-      };
-    },
-    {
-      name: "Votes";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "mauritiusWildlifeProtection";
-            type: {
-              defined: "UserVote";
-            };
-          },
-          {
-            name: "mauritiusNatureProtectionSociety";
-            type: {
-              defined: "UserVote";
-            };
-          },
-          {
-            name: "mauritiusMarineLifeProtection";
-            type: {
-              defined: "UserVote";
-            };
-          }
-        ];
-      };
-    },
-    {
-      name: "UserVote";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "amount";
-            type: "u32";
-          },
-          {
-            name: "organisationName";
-            type: "string";
-          },
-          {
-            name: "organisationAddress";
-            type: "publicKey";
-          }
-        ];
-      };
-    },
-    {
-      name: "InvalidElectionError";
-      type: {
-        kind: "enum";
-        variants: [
-          {
-            name: "InvalidDate";
-          }
-        ];
-      };
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "VoteTooLarge";
-      msg: "Number number of votes cast is too high";
-    },
-    {
-      code: 6001;
-      name: "VoteNegative";
-      msg: "Number number of votes cast should not be negative";
-    },
-    {
-      code: 6002;
-      name: "ElectionClosed";
-      msg: "Election is closed - its date is in the past";
-    }
-  ];
-};
-
-export const IDL: AnimalKingdom = {
-  version: "0.1.0",
-  name: "animal_kingdom",
-  instructions: [
-    {
-      name: "initializeMintAuthority",
+      name: "claimVoteReceipt";
       accounts: [
         {
-          name: "mintAuthority",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "receiptConfig";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "votePda";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "electionPda";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-      args: [],
-    },
-    {
-      name: "createMint",
-      accounts: [
         {
-          name: "mint",
-          isMut: true,
-          isSigner: false,
+          name: "receiptMint";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mintAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "tokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "mintAuthority";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "nftPda",
-          isMut: true,
-          isSigner: false,
+          name: "metadata";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "masterEdition";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "config";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "currentProgram",
-          isMut: false,
-          isSigner: false,
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "organisationWallet",
-          isMut: true,
-          isSigner: false,
-        },
-      ],
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
       args: [
         {
-          name: "name",
-          type: "string",
-        },
-      ],
+          name: "dateOfElection";
+          type: "i64";
+        }
+      ];
     },
     {
-      name: "createMetadata",
+      name: "createAuction";
       accounts: [
         {
-          name: "mint",
-          isMut: false,
-          isSigner: false,
+          name: "auctionPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mintAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "nftPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "tokenAccount";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "metadata",
-          isMut: true,
-          isSigner: false,
+          name: "mint";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "reservation";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "currentProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "config";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "currentProgram",
-          isMut: false,
-          isSigner: false,
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         },
-      ],
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
       args: [
         {
-          name: "name",
-          type: "string",
+          name: "startPrice";
+          type: "u64";
         },
         {
-          name: "uri",
-          type: "string",
+          name: "floorPrice";
+          type: "u64";
         },
         {
-          name: "sellerFeeBasisPoints",
-          type: "u16",
+          name: "startDate";
+          type: "i64";
         },
         {
-          name: "symbol",
-          type: "string",
-        },
-      ],
+          name: "duration";
+          type: "i64";
+        }
+      ];
     },
     {
-      name: "createMasterEdition",
+      name: "buyAuctionNft";
       accounts: [
         {
-          name: "mint",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "mintAuthority",
-          isMut: false,
-          isSigner: false,
+          name: "auctionPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "salePhase";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "metadata",
-          isMut: true,
-          isSigner: false,
+          name: "reservation";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "projectAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "fromAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "masterEdition",
-          isMut: true,
-          isSigner: false,
+          name: "toAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "currentProgram",
-          isMut: false,
-          isSigner: false,
+          name: "authorityAccount";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "name",
-          type: "string",
+          name: "mint";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-    },
-    {
-      name: "deleteNft",
-      accounts: [
         {
-          name: "metadata",
-          isMut: true,
-          isSigner: false,
+          name: "currentProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "nftPda",
-          isMut: true,
-          isSigner: false,
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mint",
-          isMut: true,
-          isSigner: false,
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenAccount",
-          isMut: true,
-          isSigner: false,
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "masterEdition",
-          isMut: true,
-          isSigner: false,
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "config";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "tokenMetadataProgram",
-          isMut: false,
-          isSigner: false,
+          name: "purchaseCounter";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mintAuthority",
-          isMut: true,
-          isSigner: false,
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "currentProgram",
-          isMut: false,
-          isSigner: false,
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "name",
-          type: "string",
-        },
-      ],
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
     },
     {
-      name: "transferNft",
+      name: "setPaymentMint";
       accounts: [
         {
-          name: "projectAccount",
-          isMut: true,
-          isSigner: false,
-          docs: [
-            "CHECK - this is dangerous - need to implement PDAs to hold information about verified conservation project addresses",
-          ],
+          name: "paymentMintPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "treasuryAccount",
-          isMut: true,
-          isSigner: false,
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "fromAccount",
-          isMut: true,
-          isSigner: false,
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "toAccount",
-          isMut: true,
-          isSigner: false,
+          name: "treasuryVault";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "authorityAccount",
-          isMut: true,
-          isSigner: false,
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mint",
-          isMut: true,
-          isSigner: false,
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mintAuthority",
-          isMut: true,
-          isSigner: false,
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "currentProgram",
-          isMut: false,
-          isSigner: false,
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "price";
+          type: "u64";
         },
         {
-          name: "tokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "enabled";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "transferNftWithToken";
+      accounts: [
+        {
+          name: "paymentMintPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "associatedTokenProgram",
-          isMut: false,
-          isSigner: false,
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "payerTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "projectAccount";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "name",
-          type: "string",
+          name: "projectTokenAccount";
+          isMut: true;
+          isSigner: false;
         },
-      ],
-    },
-    {
-      name: "createElection",
-      accounts: [
         {
-          name: "electionPda",
-          isMut: true,
-          isSigner: false,
+          name: "treasuryAccount";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mauritiusWildlifeProtection",
-          isMut: false,
-          isSigner: false,
+          name: "treasuryVault";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mauritiusNatureProtectionSociety",
-          isMut: false,
-          isSigner: false,
+          name: "fromAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mauritiusMarineLifeProtection",
-          isMut: false,
-          isSigner: false,
+          name: "toAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "authorityAccount";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "mint";
+          isMut: false;
+          isSigner: false;
         },
-      ],
-      args: [
         {
-          name: "dateOfElection",
-          type: "i64",
+          name: "currentProgram";
+          isMut: false;
+          isSigner: false;
         },
-      ],
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "salePhase";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "purchaseCounter";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reservation";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
     },
     {
-      name: "createVote",
+      name: "registerProject";
       accounts: [
         {
-          name: "votePda",
-          isMut: true,
-          isSigner: false,
+          name: "projectPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "electionPda",
-          isMut: false,
-          isSigner: false,
+          name: "projectAccount";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mint",
-          isMut: true,
-          isSigner: false,
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mintAuthority",
-          isMut: true,
-          isSigner: false,
-        },
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
         {
-          name: "ataAccount",
-          isMut: true,
-          isSigner: false,
+          name: "name";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "donate";
+      accounts: [
+        {
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mauritiusWildlifeProtection",
-          isMut: false,
-          isSigner: false,
+          name: "projectPda";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "mauritiusNatureProtectionSociety",
-          isMut: false,
-          isSigner: false,
+          name: "projectAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mauritiusMarineLifeProtection",
-          isMut: false,
-          isSigner: false,
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "currentProgram",
-          isMut: false,
-          isSigner: false,
+          name: "config";
+          isMut: false;
+          isSigner: false;
         },
         {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
         },
-      ],
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
       args: [
         {
-          name: "nameOfNft",
-          type: "string",
+          name: "projectAmount";
+          type: "u64";
         },
         {
-          name: "dateOfElection",
-          type: "i64",
+          name: "treasuryAmount";
+          type: "u64";
         },
         {
-          name: "mauritiusWildlifeProtectionVote",
-          type: "u32",
+          name: "memo";
+          type: {
+            option: "string";
+          };
+        }
+      ];
+    },
+    {
+      name: "donateToken";
+      accounts: [
+        {
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mauritiusNatureProtectionSocietyVote",
-          type: "u32",
+          name: "tokenDonorPda";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "mauritiusMarineLifeProtectionVote",
-          type: "u32",
+          name: "projectPda";
+          isMut: false;
+          isSigner: false;
         },
-      ],
+        {
+          name: "projectAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "paymentMintPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "paymentMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payerTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "projectTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasuryVault";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "projectAmount";
+          type: "u64";
+        },
+        {
+          name: "treasuryAmount";
+          type: "u64";
+        },
+        {
+          name: "memo";
+          type: {
+            option: "string";
+          };
+        }
+      ];
+    },
+    {
+      name: "setBadgeTier";
+      accounts: [
+        {
+          name: "badgeTierPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "tier";
+          type: "u8";
+        },
+        {
+          name: "threshold";
+          type: "u64";
+        },
+        {
+          name: "name";
+          type: "string";
+        },
+        {
+          name: "symbol";
+          type: "string";
+        },
+        {
+          name: "uri";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "claimBadge";
+      accounts: [
+        {
+          name: "badgeTierPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "donorPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "badgeMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "metadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "tier";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "setSalePhase";
+      accounts: [
+        {
+          name: "salePhase";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "presaleStart";
+          type: "i64";
+        },
+        {
+          name: "presaleEnd";
+          type: "i64";
+        },
+        {
+          name: "merkleRoot";
+          type: {
+            array: [
+              "u8",
+              32
+            ];
+          };
+        },
+        {
+          name: "maxPresalePurchases";
+          type: "u32";
+        }
+      ];
+    },
+    {
+      name: "presaleTransferNft";
+      accounts: [
+        {
+          name: "salePhase";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "presalePurchases";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "projectAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "fromAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "toAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authorityAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "currentProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "purchaseCounter";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "reservation";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "proof";
+          type: {
+            vec: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "setPurchaseLimits";
+      accounts: [
+        {
+          name: "config";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "maxNftsPerWallet";
+          type: "u32";
+        },
+        {
+          name: "maxPurchasesPerWindow";
+          type: "u32";
+        },
+        {
+          name: "slotWindow";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "setReservationTerms";
+      accounts: [
+        {
+          name: "config";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "reservationSeconds";
+          type: "i64";
+        },
+        {
+          name: "reservationDeposit";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "reserveNft";
+      accounts: [
+        {
+          name: "reservation";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "cooldown";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nftPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "currentProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "releaseReservation";
+      accounts: [
+        {
+          name: "reservation";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "buyer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "finalizeElection";
+      accounts: [
+        {
+          name: "electionPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "archive";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "dateOfElection";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "closeVote";
+      accounts: [
+        {
+          name: "votePda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "electionPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "archive";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "voter";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "dateOfElection";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "closeElection";
+      accounts: [
+        {
+          name: "electionPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "dateOfElection";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "setGuardian";
+      accounts: [
+        {
+          name: "config";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "guardian";
+          type: "publicKey";
+        }
+      ];
+    },
+    {
+      name: "setPaused";
+      accounts: [
+        {
+          name: "config";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "paused";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "migrateNftPda";
+      accounts: [
+        {
+          name: "nftPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "ecology";
+          type: {
+            defined: "EcologicalData";
+          };
+        }
+      ];
+    },
+    {
+      name: "migrateElection";
+      accounts: [
+        {
+          name: "electionPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "dateOfElection";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "migrateNftName";
+      accounts: [
+        {
+          name: "nameRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nftPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "name";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "renameNft";
+      accounts: [
+        {
+          name: "nameRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "newNameRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "metadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "newName";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "setFieldReporter";
+      accounts: [
+        {
+          name: "reporterPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "projectPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "reporter";
+          type: "publicKey";
+        },
+        {
+          name: "active";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "postStatusUpdate";
+      accounts: [
+        {
+          name: "statusLog";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reporterPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "reporter";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "health";
+          type: {
+            defined: "HealthStatus";
+          };
+        },
+        {
+          name: "evidenceHash";
+          type: {
+            array: [
+              "u8",
+              32
+            ];
+          };
+        },
+        {
+          name: "evidenceUri";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "createEscrow";
+      accounts: [
+        {
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "currentProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "verifier";
+          type: "publicKey";
+        },
+        {
+          name: "milestones";
+          type: {
+            vec: {
+              defined: "MilestoneTerms";
+            };
+          };
+        }
+      ];
+    },
+    {
+      name: "postMilestoneEvidence";
+      accounts: [
+        {
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "project";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8";
+        },
+        {
+          name: "evidenceHash";
+          type: {
+            array: [
+              "u8",
+              32
+            ];
+          };
+        },
+        {
+          name: "evidenceUri";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "attestMilestone";
+      accounts: [
+        {
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "projectAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "refundMilestone";
+      accounts: [
+        {
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "index";
+          type: "u8";
+        }
+      ];
+    },
+    {
+      name: "withdrawTreasury";
+      accounts: [
+        {
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipientLedger";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        },
+        {
+          name: "reason";
+          type: "string";
+        }
+      ];
+    },
+    {
+      name: "openGrantStream";
+      accounts: [
+        {
+          name: "grantStream";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "archive";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "amount";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "claimGrant";
+      accounts: [
+        {
+          name: "grantStream";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "archive";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "recipient";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "proposeStreamCancellation";
+      accounts: [
+        {
+          name: "cancellation";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "grantStream";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "archive";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "electionPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "dateOfElection";
+          type: "i64";
+        }
+      ];
+    },
+    {
+      name: "voteOnCancellation";
+      accounts: [
+        {
+          name: "cancellation";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "electionPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "votePda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "ballot";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "dateOfElection";
+          type: "i64";
+        },
+        {
+          name: "cancel";
+          type: "bool";
+        }
+      ];
+    },
+    {
+      name: "cancelGrantStream";
+      accounts: [
+        {
+          name: "grantStream";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "archive";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "laterArchive";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "cancellation";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "mintEdition";
+      accounts: [
+        {
+          name: "printRun";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "nftPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "masterTokenAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "metadata";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "masterEdition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "editionMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "editionMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "edition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "editionMarker";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "toAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "escrow";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "salePhase";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "purchaseCounter";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "currentProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "editionNumber";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "createMint2022";
+      accounts: [
+        {
+          name: "nftCounter";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nftPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "tokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "organisationWallet";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "nameRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "currentProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "associatedTokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "name";
+          type: "string";
+        },
+        {
+          name: "symbol";
+          type: "string";
+        },
+        {
+          name: "uri";
+          type: "string";
+        },
+        {
+          name: "ecology";
+          type: {
+            defined: "EcologicalData";
+          };
+        },
+        {
+          name: "transferHookProgram";
+          type: {
+            option: "publicKey";
+          };
+        }
+      ];
+    },
+    {
+      name: "createCompressedTree";
+      accounts: [
+        {
+          name: "compressedTree";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "merkleTree";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treeAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "projectPda";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAccount";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "bubblegumProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "compressionProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "logWrapper";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "family";
+          type: "string";
+        },
+        {
+          name: "maxDepth";
+          type: "u32";
+        },
+        {
+          name: "maxBufferSize";
+          type: "u32";
+        },
+        {
+          name: "price";
+          type: "u64";
+        }
+      ];
+    },
+    {
+      name: "mintCompressedNft";
+      accounts: [
+        {
+          name: "compressedTree";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "merkleTree";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treeAuthority";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bubblegumProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "compressionProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "logWrapper";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "name";
+          type: "string";
+        },
+        {
+          name: "symbol";
+          type: "string";
+        },
+        {
+          name: "uri";
+          type: "string";
+        },
+        {
+          name: "sellerFeeBasisPoints";
+          type: "u16";
+        }
+      ];
+    },
+    {
+      name: "buyCompressedNft";
+      accounts: [
+        {
+          name: "compressedTree";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "merkleTree";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treeAuthority";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "projectAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "treasuryAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "leaderboard";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "salePhase";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "purchaseCounter";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "bubblegumProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "compressionProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "logWrapper";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "leaf";
+          type: {
+            defined: "CompressedLeaf";
+          };
+        }
+      ];
+    },
+    {
+      name: "createCompressedVote";
+      accounts: [
+        {
+          name: "votePda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "electionPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "asset";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "compressedTree";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "merkleTree";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "leafDelegate";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mauritiusWildlifeProtection";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mauritiusNatureProtectionSociety";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "mauritiusMarineLifeProtection";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "donorPda";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "config";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "compressionProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [
+        {
+          name: "dateOfElection";
+          type: "i64";
+        },
+        {
+          name: "leaf";
+          type: {
+            defined: "CompressedLeaf";
+          };
+        },
+        {
+          name: "mauritiusWildlifeProtectionVote";
+          type: "u32";
+        },
+        {
+          name: "mauritiusNatureProtectionSocietyVote";
+          type: "u32";
+        },
+        {
+          name: "mauritiusMarineLifeProtectionVote";
+          type: "u32";
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "nftPda";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "conservationAddress";
+            type: "string";
+          },
+          {
+            name: "onAuction";
+            type: "bool";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "ecology";
+            type: {
+              defined: "EcologicalData";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "mintAuthority";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "nftCounter";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "nextId";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "nameRecord";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "nftId";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "treasury";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "sales";
+            type: "u64";
+          },
+          {
+            name: "auctions";
+            type: "u64";
+          },
+          {
+            name: "presales";
+            type: "u64";
+          },
+          {
+            name: "donations";
+            type: "u64";
+          },
+          {
+            name: "escrowRefunds";
+            type: "u64";
+          },
+          {
+            name: "other";
+            type: "u64";
+          },
+          {
+            name: "withdrawn";
+            type: "u64";
+          },
+          {
+            name: "withdrawals";
+            type: "u64";
+          },
+          {
+            name: "grantRefunds";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "election";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "date";
+            type: "i64";
+          },
+          {
+            name: "organisations";
+            type: {
+              defined: "Organisations";
+            };
+          },
+          {
+            name: "finalized";
+            type: "bool";
+          },
+          {
+            name: "finalizedAt";
+            type: "i64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "vote";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "voter";
+            type: "publicKey";
+          },
+          {
+            name: "ata";
+            type: "publicKey";
+          },
+          {
+            name: "date";
+            type: "i64";
+          },
+          {
+            name: "vote";
+            type: {
+              defined: "Votes";
+            };
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "voteReceiptConfig";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "election";
+            type: "publicKey";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "claimed";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "auction";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "startPrice";
+            type: "u64";
+          },
+          {
+            name: "floorPrice";
+            type: "u64";
+          },
+          {
+            name: "startDate";
+            type: "i64";
+          },
+          {
+            name: "duration";
+            type: "i64";
+          },
+          {
+            name: "buyer";
+            type: "publicKey";
+          },
+          {
+            name: "salePrice";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "paymentMint";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "vault";
+            type: "publicKey";
+          },
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "enabled";
+            type: "bool";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "donations";
+            type: "u64";
+          },
+          {
+            name: "sales";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "conservationProject";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "active";
+            type: "bool";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "donor";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "lamportsGiven";
+            type: "u64";
+          },
+          {
+            name: "nftsBought";
+            type: "u32";
+          },
+          {
+            name: "votesCast";
+            type: "u32";
+          },
+          {
+            name: "tokenDonations";
+            type: "u32";
+          },
+          {
+            name: "donations";
+            type: "u32";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "tokenDonor";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "amountGiven";
+            type: "u64";
+          },
+          {
+            name: "donations";
+            type: "u32";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "leaderboard";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "entries";
+            type: {
+              vec: {
+                defined: "LeaderboardEntry";
+              };
+            };
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "badgeTier";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "tier";
+            type: "u8";
+          },
+          {
+            name: "threshold";
+            type: "u64";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "salePhase";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "presaleStart";
+            type: "i64";
+          },
+          {
+            name: "presaleEnd";
+            type: "i64";
+          },
+          {
+            name: "merkleRoot";
+            type: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          },
+          {
+            name: "maxPresalePurchases";
+            type: "u32";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "presalePurchases";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "presaleStart";
+            type: "i64";
+          },
+          {
+            name: "purchases";
+            type: "u32";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "programConfig";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "maxNftsPerWallet";
+            type: "u32";
+          },
+          {
+            name: "maxPurchasesPerWindow";
+            type: "u32";
+          },
+          {
+            name: "slotWindow";
+            type: "u64";
+          },
+          {
+            name: "reservationSeconds";
+            type: "i64";
+          },
+          {
+            name: "reservationDeposit";
+            type: "u64";
+          },
+          {
+            name: "guardian";
+            type: "publicKey";
+          },
+          {
+            name: "paused";
+            type: "u8";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "purchaseCounter";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "totalPurchases";
+            type: "u32";
+          },
+          {
+            name: "windowStartSlot";
+            type: "u64";
+          },
+          {
+            name: "windowPurchases";
+            type: "u32";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "reservation";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "buyer";
+            type: "publicKey";
+          },
+          {
+            name: "expiresAt";
+            type: "i64";
+          },
+          {
+            name: "deposit";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "reservationCooldown";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "buyer";
+            type: "publicKey";
+          },
+          {
+            name: "reservableAt";
+            type: "i64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "electionArchive";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "election";
+            type: "publicKey";
+          },
+          {
+            name: "date";
+            type: "i64";
+          },
+          {
+            name: "finalizedAt";
+            type: "i64";
+          },
+          {
+            name: "winner";
+            type: "publicKey";
+          },
+          {
+            name: "results";
+            type: {
+              vec: {
+                defined: "ArchivedResult";
+              };
+            };
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "paidOut";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "fieldReporter";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "reporter";
+            type: "publicKey";
+          },
+          {
+            name: "active";
+            type: "bool";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "statusLog";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "count";
+            type: "u32";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "escrow";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "verifier";
+            type: "publicKey";
+          },
+          {
+            name: "deposited";
+            type: "u64";
+          },
+          {
+            name: "settled";
+            type: "u64";
+          },
+          {
+            name: "milestones";
+            type: {
+              vec: {
+                defined: "Milestone";
+              };
+            };
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "treasuryRecipient";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "recipient";
+            type: "publicKey";
+          },
+          {
+            name: "withdrawn";
+            type: "u64";
+          },
+          {
+            name: "withdrawals";
+            type: "u64";
+          },
+          {
+            name: "lastWithdrawalAt";
+            type: "i64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "grantStream";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "archive";
+            type: "publicKey";
+          },
+          {
+            name: "recipient";
+            type: "publicKey";
+          },
+          {
+            name: "total";
+            type: "u64";
+          },
+          {
+            name: "claimed";
+            type: "u64";
+          },
+          {
+            name: "start";
+            type: "i64";
+          },
+          {
+            name: "end";
+            type: "i64";
+          },
+          {
+            name: "cancelled";
+            type: "bool";
+          },
+          {
+            name: "version";
+            type: "u8";
+          },
+          {
+            name: "cancelledBy";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "streamCancellation";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "grantStream";
+            type: "publicKey";
+          },
+          {
+            name: "election";
+            type: "publicKey";
+          },
+          {
+            name: "cancelVotes";
+            type: "u64";
+          },
+          {
+            name: "keepVotes";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "cancellationBallot";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "cancellation";
+            type: "publicKey";
+          },
+          {
+            name: "vote";
+            type: "publicKey";
+          },
+          {
+            name: "cancel";
+            type: "bool";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "printRun";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "maxSupply";
+            type: "u64";
+          },
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "minted";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "compressedTree";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "merkleTree";
+            type: "publicKey";
+          },
+          {
+            name: "family";
+            type: "string";
+          },
+          {
+            name: "project";
+            type: "publicKey";
+          },
+          {
+            name: "price";
+            type: "u64";
+          },
+          {
+            name: "minted";
+            type: "u64";
+          },
+          {
+            name: "sold";
+            type: "u64";
+          },
+          {
+            name: "version";
+            type: "u8";
+          }
+        ];
+      };
+    }
+  ];
+  types: [
+    {
+      name: "ElectionV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "desc";
+            type: "string";
+          },
+          {
+            name: "date";
+            type: "i64";
+          },
+          {
+            name: "organisations";
+            type: {
+              defined: "Organisations";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "LeaderboardEntry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "wallet";
+            type: "publicKey";
+          },
+          {
+            name: "lamportsGiven";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "ArchivedResult";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "address";
+            type: "publicKey";
+          },
+          {
+            name: "votes";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "EcologicalData";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "assetKind";
+            type: {
+              defined: "AssetKind";
+            };
+          },
+          {
+            name: "speciesId";
+            type: "u64";
+          },
+          {
+            name: "iucnStatus";
+            type: {
+              defined: "IucnStatus";
+            };
+          },
+          {
+            name: "regionCode";
+            type: "string";
+          },
+          {
+            name: "geohash";
+            type: "string";
+          },
+          {
+            name: "areaM2";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "StatusUpdate";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "health";
+            type: {
+              defined: "HealthStatus";
+            };
+          },
+          {
+            name: "timestamp";
+            type: "i64";
+          },
+          {
+            name: "reporter";
+            type: "publicKey";
+          },
+          {
+            name: "evidenceHash";
+            type: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          },
+          {
+            name: "evidenceUri";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "MilestoneTerms";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "basisPoints";
+            type: "u16";
+          },
+          {
+            name: "deadline";
+            type: "i64";
+          }
+        ];
+      };
+    },
+    {
+      name: "Milestone";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "basisPoints";
+            type: "u16";
+          },
+          {
+            name: "deadline";
+            type: "i64";
+          },
+          {
+            name: "status";
+            type: {
+              defined: "MilestoneStatus";
+            };
+          },
+          {
+            name: "evidenceHash";
+            type: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          },
+          {
+            name: "evidenceUri";
+            type: "string";
+          },
+          {
+            name: "paid";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "CompressedLeaf";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "root";
+            type: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          },
+          {
+            name: "dataHash";
+            type: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          },
+          {
+            name: "creatorHash";
+            type: {
+              array: [
+                "u8",
+                32
+              ];
+            };
+          },
+          {
+            name: "nonce";
+            type: "u64";
+          },
+          {
+            name: "index";
+            type: "u32";
+          }
+        ];
+      };
+    },
+    {
+      name: "BubblegumMetadataArgs";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "sellerFeeBasisPoints";
+            type: "u16";
+          },
+          {
+            name: "primarySaleHappened";
+            type: "bool";
+          },
+          {
+            name: "isMutable";
+            type: "bool";
+          },
+          {
+            name: "editionNonce";
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "tokenStandard";
+            type: {
+              option: "u8";
+            };
+          },
+          {
+            name: "collection";
+            type: {
+              option: {
+                defined: "(bool,Pubkey)";
+              };
+            };
+          },
+          {
+            name: "uses";
+            type: {
+              option: {
+                defined: "(u8,u64,u64)";
+              };
+            };
+          },
+          {
+            name: "tokenProgramVersion";
+            type: "u8";
+          },
+          {
+            name: "creators";
+            type: {
+              vec: {
+                defined: "BubblegumCreator";
+              };
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "BubblegumCreator";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "address";
+            type: "publicKey";
+          },
+          {
+            name: "verified";
+            type: "bool";
+          },
+          {
+            name: "share";
+            type: "u8";
+          }
+        ];
+      };
+    },
+    {
+      name: "Organisations";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mauritiusWildlifeProtection";
+            type: {
+              defined: "Organisation";
+            };
+          },
+          {
+            name: "mauritiusNatureProtectionSociety";
+            type: {
+              defined: "Organisation";
+            };
+          },
+          {
+            name: "mauritiusMarineLifeProtection";
+            type: {
+              defined: "Organisation";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "Organisation";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "address";
+            type: "publicKey";
+          },
+          {
+            name: "votes";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "Votes";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mauritiusWildlifeProtection";
+            type: {
+              defined: "UserVote";
+            };
+          },
+          {
+            name: "mauritiusNatureProtectionSociety";
+            type: {
+              defined: "UserVote";
+            };
+          },
+          {
+            name: "mauritiusMarineLifeProtection";
+            type: {
+              defined: "UserVote";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "UserVote";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "amount";
+            type: "u32";
+          },
+          {
+            name: "organisationName";
+            type: "string";
+          },
+          {
+            name: "organisationAddress";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "AssetKind";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Unspecified";
+          },
+          {
+            name: "Animal";
+          },
+          {
+            name: "AnimalFamily";
+          },
+          {
+            name: "Habitat";
+          },
+          {
+            name: "Reef";
+          }
+        ];
+      };
+    },
+    {
+      name: "IucnStatus";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "NotEvaluated";
+          },
+          {
+            name: "DataDeficient";
+          },
+          {
+            name: "LeastConcern";
+          },
+          {
+            name: "NearThreatened";
+          },
+          {
+            name: "Vulnerable";
+          },
+          {
+            name: "Endangered";
+          },
+          {
+            name: "CriticallyEndangered";
+          },
+          {
+            name: "ExtinctInTheWild";
+          },
+          {
+            name: "Extinct";
+          }
+        ];
+      };
+    },
+    {
+      name: "HealthStatus";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Unknown";
+          },
+          {
+            name: "Healthy";
+          },
+          {
+            name: "Stable";
+          },
+          {
+            name: "AtRisk";
+          },
+          {
+            name: "Injured";
+          },
+          {
+            name: "Recovering";
+          },
+          {
+            name: "Degraded";
+          },
+          {
+            name: "Restored";
+          },
+          {
+            name: "Deceased";
+          }
+        ];
+      };
+    },
+    {
+      name: "MilestoneStatus";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Pending";
+          },
+          {
+            name: "EvidencePosted";
+          },
+          {
+            name: "Released";
+          },
+          {
+            name: "Refunded";
+          }
+        ];
+      };
+    },
+    {
+      name: "TreasuryInflow";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Sale";
+          },
+          {
+            name: "Auction";
+          },
+          {
+            name: "Presale";
+          },
+          {
+            name: "Donation";
+          },
+          {
+            name: "EscrowRefund";
+          },
+          {
+            name: "Other";
+          },
+          {
+            name: "GrantRefund";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidElectionError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidDate";
+          },
+          {
+            name: "ElectionNotOver";
+          },
+          {
+            name: "AlreadyFinalized";
+          },
+          {
+            name: "NotFinalized";
+          },
+          {
+            name: "GracePeriodNotOver";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidAuctionError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidPrice";
+          },
+          {
+            name: "InvalidDuration";
+          },
+          {
+            name: "InvalidDate";
+          },
+          {
+            name: "AuctionNotStarted";
+          },
+          {
+            name: "NftOnAuction";
+          },
+          {
+            name: "NftNotOnAuction";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidPaymentError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidPrice";
+          },
+          {
+            name: "PaymentMintDisabled";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidProjectError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "NameTooLong";
+          },
+          {
+            name: "ProjectInactive";
+          },
+          {
+            name: "ProjectMismatch";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidDonationError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "EmptyDonation";
+          },
+          {
+            name: "MemoTooLong";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidBadgeError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "MetadataTooLong";
+          },
+          {
+            name: "InvalidThreshold";
+          },
+          {
+            name: "ThresholdNotReached";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidPresaleError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidWindow";
+          },
+          {
+            name: "PresaleNotActive";
+          },
+          {
+            name: "PresaleActive";
+          },
+          {
+            name: "InvalidProof";
+          },
+          {
+            name: "PresaleLimitReached";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidPurchaseError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidSlotWindow";
+          },
+          {
+            name: "WalletLimitReached";
+          },
+          {
+            name: "RateLimited";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidReservationError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidDuration";
+          },
+          {
+            name: "ReservationsDisabled";
+          },
+          {
+            name: "NftSold";
+          },
+          {
+            name: "NftReserved";
+          },
+          {
+            name: "InvalidDeposit";
+          },
+          {
+            name: "CooldownActive";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidBurnError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "ReasonTooLong";
+          },
+          {
+            name: "InvalidReservationBuyer";
+          },
+          {
+            name: "InvalidNameRecord";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidConfigError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "ProgramPaused";
+          },
+          {
+            name: "Unauthorized";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidMigrationError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "AlreadyMigrated";
+          },
+          {
+            name: "InvalidConservationAddress";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidEcologyError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidRegionCode";
+          },
+          {
+            name: "InvalidGeohash";
+          },
+          {
+            name: "MissingSpecies";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidStatusError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "UriTooLong";
+          },
+          {
+            name: "ReporterInactive";
+          },
+          {
+            name: "UpdateNotFound";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidEscrowError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidMilestoneCount";
+          },
+          {
+            name: "InvalidBasisPoints";
+          },
+          {
+            name: "InvalidDeadline";
+          },
+          {
+            name: "UriTooLong";
+          },
+          {
+            name: "InvalidMilestone";
+          },
+          {
+            name: "MilestoneSettled";
+          },
+          {
+            name: "DeadlinePassed";
+          },
+          {
+            name: "DeadlineNotPassed";
+          },
+          {
+            name: "EvidenceNotPosted";
+          },
+          {
+            name: "EscrowRequiresSol";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidTreasuryError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidAmount";
+          },
+          {
+            name: "ReasonTooLong";
+          },
+          {
+            name: "BelowRentExemption";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidGrantError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "NothingVested";
+          },
+          {
+            name: "ElectionNotLater";
+          },
+          {
+            name: "StreamEnded";
+          },
+          {
+            name: "CancellationNotPassed";
+          },
+          {
+            name: "NotVoter";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidEditionError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidEditionNumber";
+          },
+          {
+            name: "SoldOut";
+          },
+          {
+            name: "MasterEditionSold";
+          },
+          {
+            name: "InvalidPrice";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidTokenInterfaceError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "InvalidTokenProgram";
+          },
+          {
+            name: "InvalidOwner";
+          },
+          {
+            name: "NotAnNft";
+          },
+          {
+            name: "NftNotHeld";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidVoteReceiptError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "MetadataTooLong";
+          },
+          {
+            name: "NotVoter";
+          },
+          {
+            name: "ReceiptsClaimed";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidNameError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "EmptyName";
+          },
+          {
+            name: "NameTooLong";
+          }
+        ];
+      };
+    },
+    {
+      name: "InvalidCompressedError";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "FamilyTooLong";
+          },
+          {
+            name: "InvalidPrice";
+          }
+        ];
+      };
+    }
+  ];
+  events: [
+    {
+      name: "NftBurned";
+      fields: [
+        {
+          name: "mint";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "reason";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "TreasuryWithdrawal";
+      fields: [
+        {
+          name: "recipient";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "amount";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "reason";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "election";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "balance";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "timestamp";
+          type: "i64";
+          index: false;
+        }
+      ];
+    },
+    {
+      name: "CompressedNftMinted";
+      fields: [
+        {
+          name: "merkleTree";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "assetId";
+          type: "publicKey";
+          index: false;
+        },
+        {
+          name: "nonce";
+          type: "u64";
+          index: false;
+        },
+        {
+          name: "name";
+          type: "string";
+          index: false;
+        },
+        {
+          name: "uri";
+          type: "string";
+          index: false;
+        }
+      ];
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "VoteTooLarge";
+      msg: "Number number of votes cast is too high";
+    },
+    {
+      code: 6001;
+      name: "VoteNegative";
+      msg: "Number number of votes cast should not be negative";
+    },
+    {
+      code: 6002;
+      name: "ElectionClosed";
+      msg: "Election is closed - its date is in the past";
+    }
+  ];
+};
+
+export const IDL: AnimalKingdom = {
+  version: "0.1.0",
+  name: "animal_kingdom",
+  instructions: [
+    {
+      name: "initializeMintAuthority",
+      accounts: [
+        {
+          name: "mintAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createMint",
+      accounts: [
+        {
+          name: "nftCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "organisationWallet",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameRecord",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "name",
+          type: "string",
+        },
+        {
+          name: "ecology",
+          type: {
+            defined: "EcologicalData",
+          },
+        },
+      ],
+    },
+    {
+      name: "createMetadata",
+      accounts: [
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameRecord",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "name",
+          type: "string",
+        },
+        {
+          name: "uri",
+          type: "string",
+        },
+        {
+          name: "sellerFeeBasisPoints",
+          type: "u16",
+        },
+        {
+          name: "symbol",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "createMasterEdition",
+      accounts: [
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "masterEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "printRun",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "maxSupply",
+          type: "u64",
+        },
+        {
+          name: "printPrice",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "deleteNft",
+      accounts: [
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "masterEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "auctionPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reservation",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reservationBuyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "printRun",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "statusLog",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nameRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "reason",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "transferNft",
+      accounts: [
+        {
+          name: "projectAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "fromAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "toAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authorityAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "salePhase",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchaseCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reservation",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "createElection",
+      accounts: [
+        {
+          name: "electionPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mauritiusWildlifeProtection",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mauritiusNatureProtectionSociety",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mauritiusMarineLifeProtection",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "createVote",
+      accounts: [
+        {
+          name: "votePda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "electionPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "ataAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mauritiusWildlifeProtection",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mauritiusNatureProtectionSociety",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mauritiusMarineLifeProtection",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+        {
+          name: "mauritiusWildlifeProtectionVote",
+          type: "u32",
+        },
+        {
+          name: "mauritiusNatureProtectionSocietyVote",
+          type: "u32",
+        },
+        {
+          name: "mauritiusMarineLifeProtectionVote",
+          type: "u32",
+        },
+      ],
+    },
+    {
+      name: "setVoteReceipt",
+      accounts: [
+        {
+          name: "receiptConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "electionPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+        {
+          name: "name",
+          type: "string",
+        },
+        {
+          name: "symbol",
+          type: "string",
+        },
+        {
+          name: "uri",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "claimVoteReceipt",
+      accounts: [
+        {
+          name: "receiptConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "votePda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "electionPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "receiptMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "masterEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "createAuction",
+      accounts: [
+        {
+          name: "auctionPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reservation",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "startPrice",
+          type: "u64",
+        },
+        {
+          name: "floorPrice",
+          type: "u64",
+        },
+        {
+          name: "startDate",
+          type: "i64",
+        },
+        {
+          name: "duration",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "buyAuctionNft",
+      accounts: [
+        {
+          name: "auctionPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "salePhase",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reservation",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "fromAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "toAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authorityAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchaseCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setPaymentMint",
+      accounts: [
+        {
+          name: "paymentMintPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "price",
+          type: "u64",
+        },
+        {
+          name: "enabled",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "transferNftWithToken",
+      accounts: [
+        {
+          name: "paymentMintPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasuryVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "fromAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "toAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authorityAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "salePhase",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchaseCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reservation",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "registerProject",
+      accounts: [
+        {
+          name: "projectPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "name",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "donate",
+      accounts: [
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "projectAmount",
+          type: "u64",
+        },
+        {
+          name: "treasuryAmount",
+          type: "u64",
+        },
+        {
+          name: "memo",
+          type: {
+            option: "string",
+          },
+        },
+      ],
+    },
+    {
+      name: "donateToken",
+      accounts: [
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenDonorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "paymentMintPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "paymentMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payerTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasuryVault",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "projectAmount",
+          type: "u64",
+        },
+        {
+          name: "treasuryAmount",
+          type: "u64",
+        },
+        {
+          name: "memo",
+          type: {
+            option: "string",
+          },
+        },
+      ],
+    },
+    {
+      name: "setBadgeTier",
+      accounts: [
+        {
+          name: "badgeTierPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "tier",
+          type: "u8",
+        },
+        {
+          name: "threshold",
+          type: "u64",
+        },
+        {
+          name: "name",
+          type: "string",
+        },
+        {
+          name: "symbol",
+          type: "string",
+        },
+        {
+          name: "uri",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "claimBadge",
+      accounts: [
+        {
+          name: "badgeTierPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "donorPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "badgeMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "tier",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "setSalePhase",
+      accounts: [
+        {
+          name: "salePhase",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "presaleStart",
+          type: "i64",
+        },
+        {
+          name: "presaleEnd",
+          type: "i64",
+        },
+        {
+          name: "merkleRoot",
+          type: {
+            array: [
+              "u8",
+              32,
+            ],
+          },
+        },
+        {
+          name: "maxPresalePurchases",
+          type: "u32",
+        },
+      ],
+    },
+    {
+      name: "presaleTransferNft",
+      accounts: [
+        {
+          name: "salePhase",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "presalePurchases",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "fromAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "toAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authorityAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchaseCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "reservation",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "proof",
+          type: {
+            vec: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "setPurchaseLimits",
+      accounts: [
+        {
+          name: "config",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "maxNftsPerWallet",
+          type: "u32",
+        },
+        {
+          name: "maxPurchasesPerWindow",
+          type: "u32",
+        },
+        {
+          name: "slotWindow",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "setReservationTerms",
+      accounts: [
+        {
+          name: "config",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "reservationSeconds",
+          type: "i64",
+        },
+        {
+          name: "reservationDeposit",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "reserveNft",
+      accounts: [
+        {
+          name: "reservation",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "cooldown",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "releaseReservation",
+      accounts: [
+        {
+          name: "reservation",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "buyer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "finalizeElection",
+      accounts: [
+        {
+          name: "electionPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "archive",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "closeVote",
+      accounts: [
+        {
+          name: "votePda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "electionPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "archive",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "voter",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "closeElection",
+      accounts: [
+        {
+          name: "electionPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "setGuardian",
+      accounts: [
+        {
+          name: "config",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "guardian",
+          type: "publicKey",
+        },
+      ],
+    },
+    {
+      name: "setPaused",
+      accounts: [
+        {
+          name: "config",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "paused",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "migrateNftPda",
+      accounts: [
+        {
+          name: "nftPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "ecology",
+          type: {
+            defined: "EcologicalData",
+          },
+        },
+      ],
+    },
+    {
+      name: "migrateElection",
+      accounts: [
+        {
+          name: "electionPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "migrateNftName",
+      accounts: [
+        {
+          name: "nameRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "name",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "renameNft",
+      accounts: [
+        {
+          name: "nameRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "newNameRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "newName",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "setFieldReporter",
+      accounts: [
+        {
+          name: "reporterPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "reporter",
+          type: "publicKey",
+        },
+        {
+          name: "active",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "postStatusUpdate",
+      accounts: [
+        {
+          name: "statusLog",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reporterPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "reporter",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "health",
+          type: {
+            defined: "HealthStatus",
+          },
+        },
+        {
+          name: "evidenceHash",
+          type: {
+            array: [
+              "u8",
+              32,
+            ],
+          },
+        },
+        {
+          name: "evidenceUri",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "createEscrow",
+      accounts: [
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "verifier",
+          type: "publicKey",
+        },
+        {
+          name: "milestones",
+          type: {
+            vec: {
+              defined: "MilestoneTerms",
+            },
+          },
+        },
+      ],
+    },
+    {
+      name: "postMilestoneEvidence",
+      accounts: [
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "project",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "index",
+          type: "u8",
+        },
+        {
+          name: "evidenceHash",
+          type: {
+            array: [
+              "u8",
+              32,
+            ],
+          },
+        },
+        {
+          name: "evidenceUri",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "attestMilestone",
+      accounts: [
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "index",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "refundMilestone",
+      accounts: [
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "index",
+          type: "u8",
+        },
+      ],
+    },
+    {
+      name: "withdrawTreasury",
+      accounts: [
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipientLedger",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recipient",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+        {
+          name: "reason",
+          type: "string",
+        },
+      ],
+    },
+    {
+      name: "openGrantStream",
+      accounts: [
+        {
+          name: "grantStream",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "archive",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "amount",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "claimGrant",
+      accounts: [
+        {
+          name: "grantStream",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "archive",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "recipient",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "proposeStreamCancellation",
+      accounts: [
+        {
+          name: "cancellation",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "grantStream",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "archive",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "electionPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+      ],
+    },
+    {
+      name: "voteOnCancellation",
+      accounts: [
+        {
+          name: "cancellation",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "electionPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "votePda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "ballot",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+        {
+          name: "cancel",
+          type: "bool",
+        },
+      ],
+    },
+    {
+      name: "cancelGrantStream",
+      accounts: [
+        {
+          name: "grantStream",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "archive",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "laterArchive",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "cancellation",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "mintEdition",
+      accounts: [
+        {
+          name: "printRun",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "masterTokenAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "metadata",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "masterEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "editionMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "editionMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "edition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "editionMarker",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "toAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "escrow",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "salePhase",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchaseCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "editionNumber",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "createMint2022",
+      accounts: [
+        {
+          name: "nftCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nftPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "tokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "organisationWallet",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "nameRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "currentProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "associatedTokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "name",
+          type: "string",
+        },
+        {
+          name: "symbol",
+          type: "string",
+        },
+        {
+          name: "uri",
+          type: "string",
+        },
+        {
+          name: "ecology",
+          type: {
+            defined: "EcologicalData",
+          },
+        },
+        {
+          name: "transferHookProgram",
+          type: {
+            option: "publicKey",
+          },
+        },
+      ],
+    },
+    {
+      name: "createCompressedTree",
+      accounts: [
+        {
+          name: "compressedTree",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "merkleTree",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treeAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "projectPda",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "bubblegumProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "compressionProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "logWrapper",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "family",
+          type: "string",
+        },
+        {
+          name: "maxDepth",
+          type: "u32",
+        },
+        {
+          name: "maxBufferSize",
+          type: "u32",
+        },
+        {
+          name: "price",
+          type: "u64",
+        },
+      ],
+    },
+    {
+      name: "mintCompressedNft",
+      accounts: [
+        {
+          name: "compressedTree",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "merkleTree",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treeAuthority",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "bubblegumProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "compressionProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "logWrapper",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "name",
+          type: "string",
+        },
+        {
+          name: "symbol",
+          type: "string",
+        },
+        {
+          name: "uri",
+          type: "string",
+        },
+        {
+          name: "sellerFeeBasisPoints",
+          type: "u16",
+        },
+      ],
+    },
+    {
+      name: "buyCompressedNft",
+      accounts: [
+        {
+          name: "compressedTree",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "merkleTree",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treeAuthority",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "projectAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "treasuryAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "leaderboard",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "salePhase",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "purchaseCounter",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "bubblegumProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "compressionProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "logWrapper",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "leaf",
+          type: {
+            defined: "CompressedLeaf",
+          },
+        },
+      ],
+    },
+    {
+      name: "createCompressedVote",
+      accounts: [
+        {
+          name: "votePda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "electionPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "asset",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "compressedTree",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "merkleTree",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "leafDelegate",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mauritiusWildlifeProtection",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mauritiusNatureProtectionSociety",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "mauritiusMarineLifeProtection",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "donorPda",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "config",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "compressionProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [
+        {
+          name: "dateOfElection",
+          type: "i64",
+        },
+        {
+          name: "leaf",
+          type: {
+            defined: "CompressedLeaf",
+          },
+        },
+        {
+          name: "mauritiusWildlifeProtectionVote",
+          type: "u32",
+        },
+        {
+          name: "mauritiusNatureProtectionSocietyVote",
+          type: "u32",
+        },
+        {
+          name: "mauritiusMarineLifeProtectionVote",
+          type: "u32",
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: "nftPda",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "conservationAddress",
+            type: "string",
+          },
+          {
+            name: "onAuction",
+            type: "bool",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "ecology",
+            type: {
+              defined: "EcologicalData",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "mintAuthority",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "nftCounter",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "nextId",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "nameRecord",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "nftId",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "treasury",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "sales",
+            type: "u64",
+          },
+          {
+            name: "auctions",
+            type: "u64",
+          },
+          {
+            name: "presales",
+            type: "u64",
+          },
+          {
+            name: "donations",
+            type: "u64",
+          },
+          {
+            name: "escrowRefunds",
+            type: "u64",
+          },
+          {
+            name: "other",
+            type: "u64",
+          },
+          {
+            name: "withdrawn",
+            type: "u64",
+          },
+          {
+            name: "withdrawals",
+            type: "u64",
+          },
+          {
+            name: "grantRefunds",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "election",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "date",
+            type: "i64",
+          },
+          {
+            name: "organisations",
+            type: {
+              defined: "Organisations",
+            },
+          },
+          {
+            name: "finalized",
+            type: "bool",
+          },
+          {
+            name: "finalizedAt",
+            type: "i64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "vote",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "voter",
+            type: "publicKey",
+          },
+          {
+            name: "ata",
+            type: "publicKey",
+          },
+          {
+            name: "date",
+            type: "i64",
+          },
+          {
+            name: "vote",
+            type: {
+              defined: "Votes",
+            },
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "voteReceiptConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "election",
+            type: "publicKey",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "claimed",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "auction",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "startPrice",
+            type: "u64",
+          },
+          {
+            name: "floorPrice",
+            type: "u64",
+          },
+          {
+            name: "startDate",
+            type: "i64",
+          },
+          {
+            name: "duration",
+            type: "i64",
+          },
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "salePrice",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "paymentMint",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "vault",
+            type: "publicKey",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "enabled",
+            type: "bool",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "donations",
+            type: "u64",
+          },
+          {
+            name: "sales",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "conservationProject",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+          {
+            name: "active",
+            type: "bool",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "donor",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+          {
+            name: "lamportsGiven",
+            type: "u64",
+          },
+          {
+            name: "nftsBought",
+            type: "u32",
+          },
+          {
+            name: "votesCast",
+            type: "u32",
+          },
+          {
+            name: "tokenDonations",
+            type: "u32",
+          },
+          {
+            name: "donations",
+            type: "u32",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "tokenDonor",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "amountGiven",
+            type: "u64",
+          },
+          {
+            name: "donations",
+            type: "u32",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "leaderboard",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "entries",
+            type: {
+              vec: {
+                defined: "LeaderboardEntry",
+              },
+            },
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "badgeTier",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "tier",
+            type: "u8",
+          },
+          {
+            name: "threshold",
+            type: "u64",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "salePhase",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "presaleStart",
+            type: "i64",
+          },
+          {
+            name: "presaleEnd",
+            type: "i64",
+          },
+          {
+            name: "merkleRoot",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+          {
+            name: "maxPresalePurchases",
+            type: "u32",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "presalePurchases",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+          {
+            name: "presaleStart",
+            type: "i64",
+          },
+          {
+            name: "purchases",
+            type: "u32",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "programConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "maxNftsPerWallet",
+            type: "u32",
+          },
+          {
+            name: "maxPurchasesPerWindow",
+            type: "u32",
+          },
+          {
+            name: "slotWindow",
+            type: "u64",
+          },
+          {
+            name: "reservationSeconds",
+            type: "i64",
+          },
+          {
+            name: "reservationDeposit",
+            type: "u64",
+          },
+          {
+            name: "guardian",
+            type: "publicKey",
+          },
+          {
+            name: "paused",
+            type: "u8",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "purchaseCounter",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+          {
+            name: "totalPurchases",
+            type: "u32",
+          },
+          {
+            name: "windowStartSlot",
+            type: "u64",
+          },
+          {
+            name: "windowPurchases",
+            type: "u32",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "reservation",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "expiresAt",
+            type: "i64",
+          },
+          {
+            name: "deposit",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "reservationCooldown",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "buyer",
+            type: "publicKey",
+          },
+          {
+            name: "reservableAt",
+            type: "i64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "electionArchive",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "election",
+            type: "publicKey",
+          },
+          {
+            name: "date",
+            type: "i64",
+          },
+          {
+            name: "finalizedAt",
+            type: "i64",
+          },
+          {
+            name: "winner",
+            type: "publicKey",
+          },
+          {
+            name: "results",
+            type: {
+              vec: {
+                defined: "ArchivedResult",
+              },
+            },
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "paidOut",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "fieldReporter",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "reporter",
+            type: "publicKey",
+          },
+          {
+            name: "active",
+            type: "bool",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "statusLog",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "count",
+            type: "u32",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "escrow",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "verifier",
+            type: "publicKey",
+          },
+          {
+            name: "deposited",
+            type: "u64",
+          },
+          {
+            name: "settled",
+            type: "u64",
+          },
+          {
+            name: "milestones",
+            type: {
+              vec: {
+                defined: "Milestone",
+              },
+            },
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "treasuryRecipient",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "recipient",
+            type: "publicKey",
+          },
+          {
+            name: "withdrawn",
+            type: "u64",
+          },
+          {
+            name: "withdrawals",
+            type: "u64",
+          },
+          {
+            name: "lastWithdrawalAt",
+            type: "i64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "grantStream",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "archive",
+            type: "publicKey",
+          },
+          {
+            name: "recipient",
+            type: "publicKey",
+          },
+          {
+            name: "total",
+            type: "u64",
+          },
+          {
+            name: "claimed",
+            type: "u64",
+          },
+          {
+            name: "start",
+            type: "i64",
+          },
+          {
+            name: "end",
+            type: "i64",
+          },
+          {
+            name: "cancelled",
+            type: "bool",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+          {
+            name: "cancelledBy",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "streamCancellation",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "grantStream",
+            type: "publicKey",
+          },
+          {
+            name: "election",
+            type: "publicKey",
+          },
+          {
+            name: "cancelVotes",
+            type: "u64",
+          },
+          {
+            name: "keepVotes",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "cancellationBallot",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "cancellation",
+            type: "publicKey",
+          },
+          {
+            name: "vote",
+            type: "publicKey",
+          },
+          {
+            name: "cancel",
+            type: "bool",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "printRun",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "maxSupply",
+            type: "u64",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "minted",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "compressedTree",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "merkleTree",
+            type: "publicKey",
+          },
+          {
+            name: "family",
+            type: "string",
+          },
+          {
+            name: "project",
+            type: "publicKey",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+          {
+            name: "minted",
+            type: "u64",
+          },
+          {
+            name: "sold",
+            type: "u64",
+          },
+          {
+            name: "version",
+            type: "u8",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "ElectionV0",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "desc",
+            type: "string",
+          },
+          {
+            name: "date",
+            type: "i64",
+          },
+          {
+            name: "organisations",
+            type: {
+              defined: "Organisations",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "LeaderboardEntry",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "wallet",
+            type: "publicKey",
+          },
+          {
+            name: "lamportsGiven",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "ArchivedResult",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "address",
+            type: "publicKey",
+          },
+          {
+            name: "votes",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "EcologicalData",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "assetKind",
+            type: {
+              defined: "AssetKind",
+            },
+          },
+          {
+            name: "speciesId",
+            type: "u64",
+          },
+          {
+            name: "iucnStatus",
+            type: {
+              defined: "IucnStatus",
+            },
+          },
+          {
+            name: "regionCode",
+            type: "string",
+          },
+          {
+            name: "geohash",
+            type: "string",
+          },
+          {
+            name: "areaM2",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "StatusUpdate",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "health",
+            type: {
+              defined: "HealthStatus",
+            },
+          },
+          {
+            name: "timestamp",
+            type: "i64",
+          },
+          {
+            name: "reporter",
+            type: "publicKey",
+          },
+          {
+            name: "evidenceHash",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+          {
+            name: "evidenceUri",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "MilestoneTerms",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "basisPoints",
+            type: "u16",
+          },
+          {
+            name: "deadline",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "Milestone",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "basisPoints",
+            type: "u16",
+          },
+          {
+            name: "deadline",
+            type: "i64",
+          },
+          {
+            name: "status",
+            type: {
+              defined: "MilestoneStatus",
+            },
+          },
+          {
+            name: "evidenceHash",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+          {
+            name: "evidenceUri",
+            type: "string",
+          },
+          {
+            name: "paid",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "CompressedLeaf",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "root",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+          {
+            name: "dataHash",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+          {
+            name: "creatorHash",
+            type: {
+              array: [
+                "u8",
+                32,
+              ],
+            },
+          },
+          {
+            name: "nonce",
+            type: "u64",
+          },
+          {
+            name: "index",
+            type: "u32",
+          },
+        ],
+      },
+    },
+    {
+      name: "BubblegumMetadataArgs",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "sellerFeeBasisPoints",
+            type: "u16",
+          },
+          {
+            name: "primarySaleHappened",
+            type: "bool",
+          },
+          {
+            name: "isMutable",
+            type: "bool",
+          },
+          {
+            name: "editionNonce",
+            type: {
+              option: "u8",
+            },
+          },
+          {
+            name: "tokenStandard",
+            type: {
+              option: "u8",
+            },
+          },
+          {
+            name: "collection",
+            type: {
+              option: {
+                defined: "(bool,Pubkey)",
+              },
+            },
+          },
+          {
+            name: "uses",
+            type: {
+              option: {
+                defined: "(u8,u64,u64)",
+              },
+            },
+          },
+          {
+            name: "tokenProgramVersion",
+            type: "u8",
+          },
+          {
+            name: "creators",
+            type: {
+              vec: {
+                defined: "BubblegumCreator",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "BubblegumCreator",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "address",
+            type: "publicKey",
+          },
+          {
+            name: "verified",
+            type: "bool",
+          },
+          {
+            name: "share",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "Organisations",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mauritiusWildlifeProtection",
+            type: {
+              defined: "Organisation",
+            },
+          },
+          {
+            name: "mauritiusNatureProtectionSociety",
+            type: {
+              defined: "Organisation",
+            },
+          },
+          {
+            name: "mauritiusMarineLifeProtection",
+            type: {
+              defined: "Organisation",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "Organisation",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "address",
+            type: "publicKey",
+          },
+          {
+            name: "votes",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "Votes",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mauritiusWildlifeProtection",
+            type: {
+              defined: "UserVote",
+            },
+          },
+          {
+            name: "mauritiusNatureProtectionSociety",
+            type: {
+              defined: "UserVote",
+            },
+          },
+          {
+            name: "mauritiusMarineLifeProtection",
+            type: {
+              defined: "UserVote",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "UserVote",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "amount",
+            type: "u32",
+          },
+          {
+            name: "organisationName",
+            type: "string",
+          },
+          {
+            name: "organisationAddress",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "AssetKind",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Unspecified",
+          },
+          {
+            name: "Animal",
+          },
+          {
+            name: "AnimalFamily",
+          },
+          {
+            name: "Habitat",
+          },
+          {
+            name: "Reef",
+          },
+        ],
+      },
+    },
+    {
+      name: "IucnStatus",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "NotEvaluated",
+          },
+          {
+            name: "DataDeficient",
+          },
+          {
+            name: "LeastConcern",
+          },
+          {
+            name: "NearThreatened",
+          },
+          {
+            name: "Vulnerable",
+          },
+          {
+            name: "Endangered",
+          },
+          {
+            name: "CriticallyEndangered",
+          },
+          {
+            name: "ExtinctInTheWild",
+          },
+          {
+            name: "Extinct",
+          },
+        ],
+      },
+    },
+    {
+      name: "HealthStatus",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Unknown",
+          },
+          {
+            name: "Healthy",
+          },
+          {
+            name: "Stable",
+          },
+          {
+            name: "AtRisk",
+          },
+          {
+            name: "Injured",
+          },
+          {
+            name: "Recovering",
+          },
+          {
+            name: "Degraded",
+          },
+          {
+            name: "Restored",
+          },
+          {
+            name: "Deceased",
+          },
+        ],
+      },
+    },
+    {
+      name: "MilestoneStatus",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Pending",
+          },
+          {
+            name: "EvidencePosted",
+          },
+          {
+            name: "Released",
+          },
+          {
+            name: "Refunded",
+          },
+        ],
+      },
+    },
+    {
+      name: "TreasuryInflow",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Sale",
+          },
+          {
+            name: "Auction",
+          },
+          {
+            name: "Presale",
+          },
+          {
+            name: "Donation",
+          },
+          {
+            name: "EscrowRefund",
+          },
+          {
+            name: "Other",
+          },
+          {
+            name: "GrantRefund",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidElectionError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "InvalidDate",
+          },
+          {
+            name: "ElectionNotOver",
+          },
+          {
+            name: "AlreadyFinalized",
+          },
+          {
+            name: "NotFinalized",
+          },
+          {
+            name: "GracePeriodNotOver",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidAuctionError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "InvalidPrice",
+          },
+          {
+            name: "InvalidDuration",
+          },
+          {
+            name: "InvalidDate",
+          },
+          {
+            name: "AuctionNotStarted",
+          },
+          {
+            name: "NftOnAuction",
+          },
+          {
+            name: "NftNotOnAuction",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidPaymentError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "InvalidPrice",
+          },
+          {
+            name: "PaymentMintDisabled",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidProjectError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "NameTooLong",
+          },
+          {
+            name: "ProjectInactive",
+          },
+          {
+            name: "ProjectMismatch",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidDonationError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "EmptyDonation",
+          },
+          {
+            name: "MemoTooLong",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidBadgeError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "MetadataTooLong",
+          },
+          {
+            name: "InvalidThreshold",
+          },
+          {
+            name: "ThresholdNotReached",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidPresaleError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "InvalidWindow",
+          },
+          {
+            name: "PresaleNotActive",
+          },
+          {
+            name: "PresaleActive",
+          },
+          {
+            name: "InvalidProof",
+          },
+          {
+            name: "PresaleLimitReached",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidPurchaseError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "InvalidSlotWindow",
+          },
+          {
+            name: "WalletLimitReached",
+          },
+          {
+            name: "RateLimited",
+          },
+        ],
+      },
     },
-  ],
-  accounts: [
     {
-      name: "nftPda",
+      name: "InvalidReservationError",
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "mint",
-            type: "publicKey",
+            name: "InvalidDuration",
           },
           {
-            name: "conservationAddress",
-            type: "string",
+            name: "ReservationsDisabled",
+          },
+          {
+            name: "NftSold",
+          },
+          {
+            name: "NftReserved",
+          },
+          {
+            name: "InvalidDeposit",
+          },
+          {
+            name: "CooldownActive",
           },
         ],
       },
     },
     {
-      name: "mintAuthority",
+      name: "InvalidBurnError",
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "desc",
-            type: "string",
+            name: "ReasonTooLong",
+          },
+          {
+            name: "InvalidReservationBuyer",
+          },
+          {
+            name: "InvalidNameRecord",
           },
         ],
       },
     },
     {
-      name: "treasury",
+      name: "InvalidConfigError",
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "desc",
-            type: "string",
+            name: "ProgramPaused",
+          },
+          {
+            name: "Unauthorized",
           },
         ],
       },
     },
     {
-      name: "election",
+      name: "InvalidMigrationError",
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "desc",
-            type: "string",
+            name: "AlreadyMigrated",
           },
           {
-            name: "date",
-            type: "i64",
+            name: "InvalidConservationAddress",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidEcologyError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "InvalidRegionCode",
           },
           {
-            name: "organisations",
-            type: {
-              defined: "Organisations",
-            },
+            name: "InvalidGeohash",
+          },
+          {
+            name: "MissingSpecies",
           },
         ],
       },
     },
     {
-      name: "vote",
+      name: "InvalidStatusError",
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "desc",
-            type: "string",
+            name: "UriTooLong",
           },
           {
-            name: "mint",
-            type: "publicKey",
+            name: "ReporterInactive",
           },
           {
-            name: "voter",
-            type: "publicKey",
+            name: "UpdateNotFound",
           },
+        ],
+      },
+    },
+    {
+      name: "InvalidEscrowError",
+      type: {
+        kind: "enum",
+        variants: [
           {
-            name: "ata",
-            type: "publicKey",
+            name: "InvalidMilestoneCount",
           },
           {
-            name: "date",
-            type: "i64",
+            name: "InvalidBasisPoints",
           },
           {
-            name: "vote",
-            type: {
-              defined: "Votes",
-            },
+            name: "InvalidDeadline",
+          },
+          {
+            name: "UriTooLong",
+          },
+          {
+            name: "InvalidMilestone",
+          },
+          {
+            name: "MilestoneSettled",
+          },
+          {
+            name: "DeadlinePassed",
+          },
+          {
+            name: "DeadlineNotPassed",
+          },
+          {
+            name: "EvidenceNotPosted",
+          },
+          {
+            name: "EscrowRequiresSol",
           },
         ],
       },
     },
-  ],
-  types: [
     {
-      name: "Organisations",
+      name: "InvalidTreasuryError",
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "mauritiusWildlifeProtection",
-            type: {
-              defined: "Organisation",
-            },
+            name: "InvalidAmount",
           },
           {
-            name: "mauritiusNatureProtectionSociety",
-            type: {
-              defined: "Organisation",
-            },
+            name: "ReasonTooLong",
           },
           {
-            name: "mauritiusMarineLifeProtection",
-            type: {
-              defined: "Organisation",
-            },
+            name: "BelowRentExemption",
           },
         ],
       },
     },
     {
-      name: "Organisation",
+      name: "InvalidGrantError",
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "name",
-            type: "string",
+            name: "NothingVested",
           },
           {
-            name: "address",
-            type: "publicKey",
+            name: "ElectionNotLater",
           },
           {
-            name: "votes",
-            type: "u64",
+            name: "StreamEnded",
+          },
+          {
+            name: "CancellationNotPassed",
+          },
+          {
+            name: "NotVoter",
           },
         ],
       },
     },
     {
-      name: "Votes",
+      name: "InvalidEditionError",
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "mauritiusWildlifeProtection",
-            type: {
-              defined: "UserVote",
-            },
+            name: "InvalidEditionNumber",
           },
           {
-            name: "mauritiusNatureProtectionSociety",
-            type: {
-              defined: "UserVote",
-            },
+            name: "SoldOut",
           },
           {
-            name: "mauritiusMarineLifeProtection",
-            type: {
-              defined: "UserVote",
-            },
+            name: "MasterEditionSold",
+          },
+          {
+            name: "InvalidPrice",
           },
         ],
       },
     },
     {
-      name: "UserVote",
+      name: "InvalidTokenInterfaceError",
       type: {
-        kind: "struct",
-        fields: [
+        kind: "enum",
+        variants: [
           {
-            name: "amount",
-            type: "u32",
+            name: "InvalidTokenProgram",
           },
           {
-            name: "organisationName",
-            type: "string",
+            name: "InvalidOwner",
           },
           {
-            name: "organisationAddress",
-            type: "publicKey",
+            name: "NotAnNft",
+          },
+          {
+            name: "NftNotHeld",
           },
         ],
       },
     },
     {
-      name: "InvalidElectionError",
+      name: "InvalidVoteReceiptError",
       type: {
         kind: "enum",
         variants: [
           {
-            name: "InvalidDate",
+            name: "MetadataTooLong",
+          },
+          {
+            name: "NotVoter",
+          },
+          {
+            name: "ReceiptsClaimed",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidNameError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "EmptyName",
+          },
+          {
+            name: "NameTooLong",
+          },
+        ],
+      },
+    },
+    {
+      name: "InvalidCompressedError",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "FamilyTooLong",
+          },
+          {
+            name: "InvalidPrice",
           },
         ],
       },
     },
   ],
+  events: [
+    {
+      name: "NftBurned",
+      fields: [
+        {
+          name: "mint",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "reason",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "TreasuryWithdrawal",
+      fields: [
+        {
+          name: "recipient",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "amount",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "reason",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "election",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "balance",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "timestamp",
+          type: "i64",
+          index: false,
+        },
+      ],
+    },
+    {
+      name: "CompressedNftMinted",
+      fields: [
+        {
+          name: "merkleTree",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "assetId",
+          type: "publicKey",
+          index: false,
+        },
+        {
+          name: "nonce",
+          type: "u64",
+          index: false,
+        },
+        {
+          name: "name",
+          type: "string",
+          index: false,
+        },
+        {
+          name: "uri",
+          type: "string",
+          index: false,
+        },
+      ],
+    },
+  ],
   errors: [
     {
       code: 6000,
@@ -0,0 +1,34 @@
+import { Program } from "@project-serum/anchor";
+import { PublicKey } from "@solana/web3.js";
+import { keccak_256 } from "@noble/hashes/sha3";
+import { AnimalKingdom } from "@/utils/animal_kingdom";
+import { PROGRAM_ID } from "@/utils/constants";
+
+// Names are compared ignoring case and extra whitespace, so "Pink  Pigeon" and "pink pigeon" are the same name
+export const normaliseName = (name: string) =>
+  name.trim().split(/\s+/).join(" ").toLowerCase();
+
+// Name record PDA - maps the name of an nft to its mint
+export const findNameRecord = (name: string) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from("name"), Buffer.from(keccak_256(normaliseName(name)))],
+    new PublicKey(PROGRAM_ID)
+  )[0];
+
+// Mints of nfts minted before the name registry were derived from their name
+export const findLegacyNftMint = (name: string) =>
+  PublicKey.findProgramAddressSync(
+    [Buffer.from(name), new PublicKey(PROGRAM_ID).toBuffer()],
+    new PublicKey(PROGRAM_ID)
+  )[0];
+
+// Mint of the nft registered with this name, or its name seeded mint if it was minted before the registry
+export const findNftMint = async (
+  program: Program<AnimalKingdom>,
+  name: string
+) => {
+  const nameRecord = await program.account.nameRecord.fetchNullable(
+    findNameRecord(name)
+  );
+  return nameRecord ? nameRecord.mint : findLegacyNftMint(name);
+};